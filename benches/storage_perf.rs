@@ -476,6 +476,61 @@ fn bench_ready_query(c: &mut Criterion) {
     log_group_end(group_name);
 }
 
+/// Benchmark NDJSON ready output: full `ReadyIssue` rows vs. projected columns.
+///
+/// Mirrors `br ready --jsonl` with and without `--fields id,priority`.
+fn bench_ready_jsonl_projection(c: &mut Criterion) {
+    use beads_rust::format::ReadyIssue;
+
+    init_bench_logging();
+    let group_name = "storage/ready_jsonl";
+    log_group_start(group_name);
+    let mut group = c.benchmark_group(group_name);
+    configure_group(&mut group);
+
+    let (_dir, storage) = setup_db_with_deps(1000, 2000);
+    let fields = vec!["id".to_string(), "priority".to_string()];
+
+    group.bench_function("full", |b| {
+        let bench_start = log_bench_start("storage/ready_jsonl/full");
+        b.iter(|| {
+            let ready = storage
+                .get_ready_issues(&ReadyFilters::default(), ReadySortPolicy::default())
+                .unwrap();
+            let mut out = Vec::new();
+            for issue in &ready {
+                serde_json::to_writer(&mut out, &ReadyIssue::from(issue)).unwrap();
+                out.push(b'\n');
+            }
+            black_box(out)
+        });
+        log_bench_end("storage/ready_jsonl/full", bench_start);
+    });
+
+    group.bench_function("fields_id_priority", |b| {
+        let bench_start = log_bench_start("storage/ready_jsonl/fields_id_priority");
+        b.iter(|| {
+            let rows = storage
+                .get_ready_issue_columns(
+                    &ReadyFilters::default(),
+                    ReadySortPolicy::default(),
+                    &fields,
+                )
+                .unwrap();
+            let mut out = Vec::new();
+            for row in &rows {
+                serde_json::to_writer(&mut out, row).unwrap();
+                out.push(b'\n');
+            }
+            black_box(out)
+        });
+        log_bench_end("storage/ready_jsonl/fields_id_priority", bench_start);
+    });
+
+    group.finish();
+    log_group_end(group_name);
+}
+
 /// Benchmark blocked issues query.
 fn bench_blocked_query(c: &mut Criterion) {
     init_bench_logging();
@@ -1004,6 +1059,7 @@ criterion_group!(
     bench_list_issues,
     bench_list_issues_filtered,
     bench_ready_query,
+    bench_ready_jsonl_projection,
     bench_blocked_query,
     bench_add_dependency,
     bench_cycle_detection,
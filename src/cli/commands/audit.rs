@@ -3,6 +3,7 @@
 use crate::cli::{AuditCommands, AuditLabelArgs, AuditLogArgs, AuditRecordArgs, AuditSummaryArgs};
 use crate::config;
 use crate::error::{BeadsError, Result};
+use crate::format::{TimeFormat, format_timestamp_or};
 use crate::model::EventType;
 use crate::output::{OutputContext, Theme};
 use chrono::{DateTime, Utc};
@@ -132,7 +133,10 @@ pub fn execute(
     match command {
         AuditCommands::Record(args) => record_entry(args, &beads_dir, &actor, ctx),
         AuditCommands::Label(args) => label_entry(args, &beads_dir, &actor, ctx),
-        AuditCommands::Log(args) => execute_log(args, &beads_dir, cli, json, ctx),
+        AuditCommands::Log(args) => {
+            let time_format = config::time_format_from_layer(&layer)?;
+            execute_log(args, &beads_dir, cli, time_format, ctx)
+        }
         AuditCommands::Summary(args) => execute_summary(args, &beads_dir, cli, json, ctx),
     }
 }
@@ -141,7 +145,7 @@ fn execute_log(
     args: &AuditLogArgs,
     beads_dir: &Path,
     cli: &config::CliOverrides,
-    _json: bool,
+    time_format: Option<TimeFormat>,
     ctx: &OutputContext,
 ) -> Result<()> {
     let storage_ctx = config::open_storage_with_cli(beads_dir, cli)?;
@@ -158,9 +162,9 @@ fn execute_log(
     }
 
     if ctx.is_rich() {
-        render_audit_log_rich(issue_id, &events, ctx);
+        render_audit_log_rich(issue_id, &events, time_format, ctx);
     } else {
-        render_audit_log_plain(issue_id, &events);
+        render_audit_log_plain(issue_id, &events, time_format);
     }
 
     Ok(())
@@ -462,7 +466,12 @@ fn new_audit_id() -> String {
     )
 }
 
-fn render_audit_log_rich(issue_id: &str, events: &[crate::model::Event], ctx: &OutputContext) {
+fn render_audit_log_rich(
+    issue_id: &str,
+    events: &[crate::model::Event],
+    time_format: Option<TimeFormat>,
+    ctx: &OutputContext,
+) {
     let console = Console::default();
     let theme = ctx.theme();
     let width = ctx.width();
@@ -472,7 +481,7 @@ fn render_audit_log_rich(issue_id: &str, events: &[crate::model::Event], ctx: &O
 
     for event in events {
         // Timestamp + Actor
-        let time_str = event.created_at.format("%Y-%m-%d %H:%M").to_string();
+        let time_str = format_timestamp_or(&event.created_at, time_format, "%Y-%m-%d %H:%M");
         content.append_styled(&time_str, theme.dimmed.clone());
         content.append("  ");
         content.append_styled(&format!("@{:<10}", event.actor), theme.accent.clone());
@@ -517,14 +526,18 @@ fn render_audit_log_rich(issue_id: &str, events: &[crate::model::Event], ctx: &O
     console.print_renderable(&panel);
 }
 
-fn render_audit_log_plain(issue_id: &str, events: &[crate::model::Event]) {
+fn render_audit_log_plain(
+    issue_id: &str,
+    events: &[crate::model::Event],
+    time_format: Option<TimeFormat>,
+) {
     println!("Audit Log: {}", issue_id);
     println!("{}", "-".repeat(40));
 
     for event in events {
         println!(
             "{}  @{:<10}  {}",
-            event.created_at.format("%Y-%m-%d %H:%M"),
+            format_timestamp_or(&event.created_at, time_format, "%Y-%m-%d %H:%M"),
             event.actor,
             event.event_type.as_str()
         );
@@ -35,7 +35,9 @@ pub fn execute(
     let storage = &storage_ctx.storage;
 
     let config_layer = load_config(&beads_dir, Some(storage), overrides)?;
-    let external_db_paths = external_project_db_paths(&config_layer, &beads_dir);
+    let external_projects = external_project_db_paths(&config_layer, &beads_dir);
+    external_projects.report_unresolved();
+    let external_db_paths = external_projects.resolved;
     let use_color = should_use_color(&config_layer);
     let output_format = resolve_output_format_basic(args.format, outer_ctx.is_json(), args.robot);
     let quiet = overrides.quiet.unwrap_or(false);
@@ -175,7 +177,7 @@ pub fn execute(
                 .collect();
             ctx.toon_with_stats(&output, args.stats);
         }
-        OutputFormat::Text | OutputFormat::Csv => {
+        OutputFormat::Text | OutputFormat::Csv | OutputFormat::Tsv => {
             let max_width = if args.wrap { ctx.width() } else { 0 };
             if matches!(ctx.mode(), OutputMode::Rich) {
                 render_blocked_rich(&blocked_issues, args.detailed, storage, max_width);
@@ -3,10 +3,11 @@
 use crate::cli::{CommentAddArgs, CommentCommands, CommentListArgs, CommentsArgs};
 use crate::config;
 use crate::error::{BeadsError, Result};
+use crate::format::{TimeFormat, format_timestamp, format_timestamp_or};
 use crate::model::Comment;
 use crate::output::{OutputContext, OutputMode};
 use crate::storage::SqliteStorage;
-use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
+use crate::util::id::{IdResolver, find_matching_ids};
 use chrono::{DateTime, Utc};
 use rich_rust::prelude::*;
 use std::fs;
@@ -28,10 +29,10 @@ pub fn execute(
     let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
 
     let config_layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
-    let id_config = config::id_config_from_layer(&config_layer);
-    let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
+    let resolver = IdResolver::new(config::resolver_config_from_layer(&config_layer));
     let all_ids = storage_ctx.storage.get_all_ids()?;
     let actor = config::actor_from_layer(&config_layer);
+    let time_format = config::time_format_from_layer(&config_layer)?;
     let storage = &mut storage_ctx.storage;
 
     match &args.command {
@@ -49,7 +50,7 @@ pub fn execute(
             storage,
             &resolver,
             &all_ids,
-            json,
+            time_format,
             ctx,
             list_args.wrap,
         ),
@@ -58,7 +59,15 @@ pub fn execute(
                 .id
                 .as_deref()
                 .ok_or_else(|| BeadsError::validation("id", "missing issue id"))?;
-            list_comments_by_id(id, storage, &resolver, &all_ids, json, ctx, args.wrap)
+            list_comments_by_id(
+                id,
+                storage,
+                &resolver,
+                &all_ids,
+                time_format,
+                ctx,
+                args.wrap,
+            )
         }
     }?;
 
@@ -103,11 +112,11 @@ fn list_comments(
     storage: &SqliteStorage,
     resolver: &IdResolver,
     all_ids: &[String],
-    json: bool,
+    time_format: Option<TimeFormat>,
     ctx: &OutputContext,
     wrap: bool,
 ) -> Result<()> {
-    list_comments_by_id(&args.id, storage, resolver, all_ids, json, ctx, wrap)
+    list_comments_by_id(&args.id, storage, resolver, all_ids, time_format, ctx, wrap)
 }
 
 fn list_comments_by_id(
@@ -115,7 +124,7 @@ fn list_comments_by_id(
     storage: &SqliteStorage,
     resolver: &IdResolver,
     all_ids: &[String],
-    _json: bool,
+    time_format: Option<TimeFormat>,
     ctx: &OutputContext,
     wrap: bool,
 ) -> Result<()> {
@@ -128,7 +137,7 @@ fn list_comments_by_id(
     }
 
     if matches!(ctx.mode(), OutputMode::Rich) {
-        render_comments_list_rich(&issue_id, &comments, ctx, wrap);
+        render_comments_list_rich(&issue_id, &comments, time_format, ctx, wrap);
         return Ok(());
     }
 
@@ -139,7 +148,7 @@ fn list_comments_by_id(
 
     println!("Comments for {issue_id}:");
     for comment in comments {
-        let timestamp = comment.created_at.format("%Y-%m-%d %H:%M UTC");
+        let timestamp = format_timestamp_or(&comment.created_at, time_format, "%Y-%m-%d %H:%M UTC");
         println!("[{}] at {}", comment.author, timestamp);
         println!("{}", comment.body.trim_end_matches('\n'));
         println!();
@@ -152,6 +161,7 @@ fn list_comments_by_id(
 fn render_comments_list_rich(
     issue_id: &str,
     comments: &[Comment],
+    time_format: Option<TimeFormat>,
     ctx: &OutputContext,
     wrap: bool,
 ) {
@@ -186,10 +196,12 @@ fn render_comments_list_rich(
         // Author and timestamp
         content.append_styled(&format!("@{}", comment.author), theme.username.clone());
         content.append_styled(" \u{2022} ", theme.dimmed.clone());
-        content.append_styled(
-            &format_relative_time(comment.created_at, now),
-            theme.timestamp.clone(),
+        // Relative by default; --time-format overrides it.
+        let timestamp = time_format.map_or_else(
+            || format_relative_time(comment.created_at, now),
+            |fmt| format_timestamp(&comment.created_at, fmt, now),
         );
+        content.append_styled(&timestamp, theme.timestamp.clone());
         content.append("\n");
 
         // Comment body
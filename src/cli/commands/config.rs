@@ -6,14 +6,16 @@
 //! - List all available options
 //! - Open config in editor
 //! - Show config file paths
+//! - Export/import config for transfer between workspaces
+//! - Diff effective config against another workspace
 
 #![allow(clippy::default_trait_access)]
 
-use crate::cli::ConfigCommands;
+use crate::cli::{ConfigCommands, ConfigConflictPolicy};
 use crate::config::{
     self, CliOverrides, ConfigLayer, ConfigPaths, default_config_layer, discover_beads_dir,
     id_config_from_layer, load_legacy_user_config, load_project_config, load_user_config,
-    resolve_actor,
+    normalize_key, resolve_actor,
 };
 use crate::error::Result;
 use crate::output::OutputContext;
@@ -101,9 +103,37 @@ pub fn execute(
         }
         ConfigCommands::Set { args } => set_config_value(args, json_mode, ctx),
         ConfigCommands::Delete { key } => delete_config_value(key, json_mode, overrides, ctx),
-        ConfigCommands::Get { key } => {
+        ConfigCommands::Get {
+            key,
+            all: false,
+            default,
+        } => {
             let beads_dir = discover_beads_dir(None).ok();
-            get_config_value(key, beads_dir.as_ref(), overrides, json_mode, ctx)
+            get_config_value(
+                key,
+                default.as_deref(),
+                beads_dir.as_ref(),
+                overrides,
+                json_mode,
+                ctx,
+            )
+        }
+        ConfigCommands::Get { key, all: true, .. } => {
+            let beads_dir = discover_beads_dir(None).ok();
+            get_config_prefix(key, beads_dir.as_ref(), overrides, ctx)
+        }
+        ConfigCommands::Export { project } => {
+            let beads_dir = discover_beads_dir(None).ok();
+            export_config(beads_dir.as_ref(), overrides, *project, ctx)
+        }
+        ConfigCommands::Import {
+            file,
+            user,
+            on_conflict,
+        } => import_config(file, *user, *on_conflict, ctx),
+        ConfigCommands::Diff { other } => {
+            let beads_dir = discover_beads_dir(None)?;
+            diff_config(&beads_dir, other, overrides, ctx)
         }
     }
 }
@@ -347,6 +377,7 @@ fn edit_config() -> Result<()> {
 /// Get a specific config value.
 fn get_config_value(
     key: &str,
+    default: Option<&str>,
     beads_dir: Option<&PathBuf>,
     overrides: &CliOverrides,
     _json_mode: bool,
@@ -363,6 +394,20 @@ fn get_config_value(
         .or_else(|| layer.startup.get(key))
         .cloned();
 
+    if let (None, Some(fallback)) = (&value, default) {
+        debug!(key, "Config key unset; using --default");
+        if ctx.is_json() {
+            ctx.json_pretty(&json!({
+                "key": key,
+                "value": fallback,
+                "source": "default-flag",
+            }));
+        } else if !ctx.is_quiet() {
+            println!("{fallback}");
+        }
+        return Ok(());
+    }
+
     if ctx.is_json() {
         let output = json!({
             "key": key,
@@ -396,6 +441,66 @@ fn get_config_value(
     Ok(())
 }
 
+/// Print every effective key under `prefix` with its value and source.
+fn get_config_prefix(
+    prefix: &str,
+    beads_dir: Option<&PathBuf>,
+    overrides: &CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    debug!(prefix, "Reading config namespace");
+    let layers = build_layers(beads_dir, overrides)?;
+    let layer = merge_layers(&layers);
+    let matches = collect_prefixed_keys(&layer, prefix);
+
+    if ctx.is_json() {
+        ctx.json_pretty(&matches);
+    } else if ctx.is_quiet() {
+        // Nothing to output in quiet mode
+    } else if ctx.is_rich() {
+        let entries = matches
+            .iter()
+            .map(|(key, value)| ConfigEntry {
+                key: key.clone(),
+                value: format_config_value(value),
+                source: layers
+                    .iter()
+                    .rev()
+                    .find_map(|l| {
+                        l.layer
+                            .runtime
+                            .keys()
+                            .chain(l.layer.startup.keys())
+                            .find(|k| normalize_key(k) == *key)
+                            .map(|_| l.source)
+                    })
+                    .unwrap_or(ConfigSource::Default),
+            })
+            .collect::<Vec<_>>();
+        render_config_table(&format!("Config: {prefix}*"), &entries, ctx);
+    } else {
+        for (key, value) in &matches {
+            println!("{key}: {value}");
+        }
+    }
+
+    Ok(())
+}
+
+/// Collect effective keys whose normalized form starts with the normalized `prefix`.
+///
+/// Keys are returned normalized; runtime values win over startup values.
+fn collect_prefixed_keys(layer: &ConfigLayer, prefix: &str) -> BTreeMap<String, String> {
+    let wanted = normalize_key(prefix);
+    let mut matches = BTreeMap::new();
+    for (key, value) in layer.startup.iter().chain(layer.runtime.iter()) {
+        if normalize_key(key).starts_with(&wanted) {
+            matches.insert(normalize_key(key), value.clone());
+        }
+    }
+    matches
+}
+
 /// Set a config value in project config (if available) or user config.
 fn set_config_value(args: &[String], _json_mode: bool, ctx: &OutputContext) -> Result<()> {
     let (key, value) = match args.len() {
@@ -705,6 +810,374 @@ fn delete_nested(value: &mut serde_yaml::Value, path: &[&str]) -> bool {
     false
 }
 
+/// Export config as a flat YAML mapping of key to value.
+///
+/// Each key appears once, under the spelling of the layer that sets it.
+/// Project-only exports keep the file's YAML types (numbers, lists); merged
+/// exports restore booleans and numbers from the layered string values.
+fn export_config(
+    beads_dir: Option<&PathBuf>,
+    overrides: &CliOverrides,
+    project_only: bool,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let entries: BTreeMap<String, serde_yaml::Value> = if project_only {
+        let yaml = match beads_dir {
+            Some(dir) => read_yaml_config(&dir.join("config.yaml"))?,
+            None => serde_yaml::Value::Mapping(serde_yaml::Mapping::default()),
+        };
+        let mut flat = BTreeMap::new();
+        flatten_yaml(&yaml, "", &mut flat);
+        dedupe_spellings(flat).into_values().collect()
+    } else {
+        resolve_effective(&build_layers(beads_dir, overrides)?)
+            .into_values()
+            .map(|(key, value)| {
+                let typed = typed_yaml_value(&value);
+                (key, typed)
+            })
+            .collect()
+    };
+    debug!(count = entries.len(), project_only, "Exporting config");
+
+    if ctx.is_json() {
+        ctx.json_pretty(&entries);
+    } else if !entries.is_empty() {
+        print!("{}", serde_yaml::to_string(&entries)?);
+    }
+
+    Ok(())
+}
+
+/// Import config keys from a YAML file into the project (or user) config.
+///
+/// Keys are compared after normalization, so `issue_prefix` in the file
+/// updates an existing `issue-prefix` entry instead of adding a second spelling.
+/// Overwriting a key that the target already sets under several spellings
+/// collapses them into one. Values keep their YAML types.
+#[allow(clippy::too_many_lines)]
+fn import_config(
+    file: &Path,
+    to_user: bool,
+    on_conflict: ConfigConflictPolicy,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let incoming_yaml = read_yaml_config(file)?;
+    let mut flat = BTreeMap::new();
+    flatten_yaml(&incoming_yaml, "", &mut flat);
+    let mut seen: BTreeMap<String, (&String, &serde_yaml::Value)> = BTreeMap::new();
+    for (key, value) in &flat {
+        if let Some((other, other_value)) = seen.insert(normalize_key(key), (key, value)) {
+            if yaml_value_to_string(other_value) != yaml_value_to_string(value) {
+                return Err(crate::error::BeadsError::validation(
+                    "file",
+                    format!(
+                        "{} sets {other} and {key} to different values",
+                        file.display()
+                    ),
+                ));
+            }
+        }
+    }
+    let incoming = dedupe_spellings(flat);
+
+    let (config_path, scope) = if to_user {
+        let path = get_user_config_path().ok_or_else(|| {
+            crate::error::BeadsError::Config("HOME environment variable not set".to_string())
+        })?;
+        (path, "user")
+    } else {
+        (discover_beads_dir(None)?.join("config.yaml"), "project")
+    };
+
+    let mut config = read_yaml_config(&config_path)?;
+    let mut existing = BTreeMap::new();
+    flatten_yaml(&config, "", &mut existing);
+    let mut existing_by_normalized: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for key in existing.keys() {
+        existing_by_normalized
+            .entry(normalize_key(key))
+            .or_default()
+            .push(key.clone());
+    }
+
+    let mut imported = Vec::new();
+    let mut skipped = Vec::new();
+    let mut conflicts = Vec::new();
+    for (normalized, (key, value)) in incoming {
+        let spellings = existing_by_normalized
+            .get(&normalized)
+            .cloned()
+            .unwrap_or_default();
+        let target_key = if let Some(kept) = spellings
+            .iter()
+            .min_by(|a, b| spelling_rank(a, &normalized).cmp(&spelling_rank(b, &normalized)))
+            .cloned()
+        {
+            let wanted = yaml_value_to_string(&value);
+            let unchanged = spellings
+                .iter()
+                .all(|spelling| existing.get(spelling).and_then(yaml_value_to_string) == wanted);
+            if unchanged {
+                skipped.push(key);
+                continue;
+            }
+            match on_conflict {
+                ConfigConflictPolicy::Skip => {
+                    skipped.push(key);
+                    continue;
+                }
+                ConfigConflictPolicy::Error => {
+                    conflicts.push(key);
+                    continue;
+                }
+                ConfigConflictPolicy::Overwrite => {
+                    for spelling in spellings.iter().filter(|spelling| **spelling != kept) {
+                        delete_from_yaml(&mut config, spelling);
+                    }
+                    kept
+                }
+            }
+        } else {
+            key
+        };
+        let parts: Vec<&str> = target_key.split('.').collect();
+        set_yaml_value(&mut config, &parts, value);
+        imported.push(target_key);
+    }
+
+    if !conflicts.is_empty() {
+        return Err(crate::error::BeadsError::Validation {
+            field: "config".to_string(),
+            reason: format!(
+                "Keys already set in {scope} config: {} (use --on-conflict overwrite or skip)",
+                conflicts.join(", ")
+            ),
+        });
+    }
+
+    if !imported.is_empty() {
+        if let Some(parent) = config_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&config_path, serde_yaml::to_string(&config)?)?;
+    }
+    info!(
+        imported = imported.len(),
+        skipped = skipped.len(),
+        path = %config_path.display(),
+        "Config imported"
+    );
+
+    if ctx.is_json() {
+        let output = json!({
+            "imported": imported,
+            "skipped": skipped,
+            "path": config_path.display().to_string(),
+            "scope": scope,
+        });
+        ctx.json_pretty(&output);
+    } else if ctx.is_quiet() {
+        return Ok(());
+    } else {
+        println!(
+            "Imported {} key(s) into {} ({} skipped)",
+            imported.len(),
+            config_path.display(),
+            skipped.len()
+        );
+    }
+
+    Ok(())
+}
+
+/// A key whose effective value differs between two workspaces.
+///
+/// `None` means the key is unset on that side.
+#[derive(Debug, PartialEq, Eq)]
+struct ConfigDiff {
+    key: String,
+    here: Option<String>,
+    there: Option<String>,
+}
+
+/// Compare this workspace's effective config with another workspace's.
+fn diff_config(
+    beads_dir: &Path,
+    other: &Path,
+    overrides: &CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let other_dir = if other.join(".beads").is_dir() {
+        other.join(".beads")
+    } else if other.is_dir() {
+        other.to_path_buf()
+    } else {
+        return Err(crate::error::BeadsError::validation(
+            "other",
+            format!("{} is not a workspace or .beads directory", other.display()),
+        ));
+    };
+
+    let here = effective_entries(&build_layers(Some(&beads_dir.to_path_buf()), overrides)?);
+    // CLI overrides (--db, --actor, ...) describe this invocation, not the other workspace
+    let there = effective_entries(&build_layers(Some(&other_dir), &CliOverrides::default())?);
+    let diffs = diff_entries(&here, &there);
+    debug!(count = diffs.len(), other = %other_dir.display(), "Config diff computed");
+
+    if ctx.is_json() {
+        let output = json!({
+            "here": beads_dir.display().to_string(),
+            "there": other_dir.display().to_string(),
+            "differences": diffs
+                .iter()
+                .map(|diff| json!({ "key": diff.key, "here": diff.here, "there": diff.there }))
+                .collect::<Vec<_>>(),
+        });
+        ctx.json_pretty(&output);
+        return Ok(());
+    }
+    if ctx.is_quiet() {
+        return Ok(());
+    }
+    if diffs.is_empty() {
+        println!("No config differences with {}", other_dir.display());
+        return Ok(());
+    }
+    println!(
+        "Config differences ({} vs {}):",
+        beads_dir.display(),
+        other_dir.display()
+    );
+    for diff in &diffs {
+        match (&diff.here, &diff.there) {
+            (Some(here), Some(there)) => println!("  ~ {}: {here} -> {there}", diff.key),
+            (Some(here), None) => println!("  < {}: {here} (only here)", diff.key),
+            (None, Some(there)) => println!("  > {}: {there} (only there)", diff.key),
+            (None, None) => {}
+        }
+    }
+    Ok(())
+}
+
+/// Effective key/value pairs keyed by normalized name, so `issue_prefix` and
+/// `issue-prefix` compare as the same key. Values follow layer precedence.
+fn effective_entries(layers: &[LayerWithSource]) -> BTreeMap<String, String> {
+    resolve_effective(layers)
+        .into_iter()
+        .map(|(key, (_, value))| (key, value))
+        .collect()
+}
+
+fn diff_entries(
+    here: &BTreeMap<String, String>,
+    there: &BTreeMap<String, String>,
+) -> Vec<ConfigDiff> {
+    let mut keys: Vec<&String> = here.keys().chain(there.keys()).collect();
+    keys.sort();
+    keys.dedup();
+    keys.into_iter()
+        .filter(|key| here.get(*key) != there.get(*key))
+        .map(|key| ConfigDiff {
+            key: key.clone(),
+            here: here.get(key).cloned(),
+            there: there.get(key).cloned(),
+        })
+        .collect()
+}
+
+/// Flatten a nested YAML mapping into dotted keys, keeping leaf values
+/// (scalars and lists) as YAML.
+fn flatten_yaml(
+    value: &serde_yaml::Value,
+    prefix: &str,
+    out: &mut BTreeMap<String, serde_yaml::Value>,
+) {
+    if let serde_yaml::Value::Mapping(map) = value {
+        for (key, child) in map {
+            let Some(key) = yaml_value_to_string(key) else {
+                continue;
+            };
+            let full_key = if prefix.is_empty() {
+                key
+            } else {
+                format!("{prefix}.{key}")
+            };
+            flatten_yaml(child, &full_key, out);
+        }
+    } else if !prefix.is_empty() {
+        out.insert(prefix.to_string(), value.clone());
+    }
+}
+
+/// Read a YAML config file, treating a missing or empty file as an empty mapping.
+fn read_yaml_config(path: &Path) -> Result<serde_yaml::Value> {
+    if !path.exists() {
+        return Ok(serde_yaml::Value::Mapping(serde_yaml::Mapping::default()));
+    }
+    Ok(match serde_yaml::from_str(&fs::read_to_string(path)?)? {
+        serde_yaml::Value::Null => serde_yaml::Value::Mapping(serde_yaml::Mapping::default()),
+        value => value,
+    })
+}
+
+/// Sort key for choosing between spellings of one config key: the normalized
+/// spelling first, then lexicographic order.
+fn spelling_rank<'a>(key: &'a str, normalized: &str) -> (bool, &'a str) {
+    (key != normalized, key)
+}
+
+/// Keep one spelling per normalized key, as `normalized -> (spelling, value)`.
+///
+/// The choice follows [`spelling_rank`], so it never depends on the order of
+/// the input (e.g. `HashMap` iteration).
+fn dedupe_spellings<V>(
+    entries: impl IntoIterator<Item = (String, V)>,
+) -> BTreeMap<String, (String, V)> {
+    let mut deduped: BTreeMap<String, (String, V)> = BTreeMap::new();
+    for (key, value) in entries {
+        let normalized = normalize_key(&key);
+        let better = deduped.get(&normalized).is_none_or(|(current, _)| {
+            spelling_rank(&key, &normalized) < spelling_rank(current, &normalized)
+        });
+        if better {
+            deduped.insert(normalized, (key, value));
+        }
+    }
+    deduped
+}
+
+/// Effective config by normalized key, as `normalized -> (spelling, value)`.
+///
+/// Later layers override earlier ones whatever spelling they use, so a
+/// project `issue-prefix` replaces the builtin `issue_prefix`.
+fn resolve_effective(layers: &[LayerWithSource]) -> BTreeMap<String, (String, String)> {
+    let mut effective = BTreeMap::new();
+    for layer in layers {
+        let entries = layer
+            .layer
+            .startup
+            .iter()
+            .chain(layer.layer.runtime.iter())
+            .map(|(key, value)| (key.clone(), value.clone()));
+        effective.extend(dedupe_spellings(entries));
+    }
+    effective
+}
+
+/// Turn a layered string value back into a YAML boolean or number when it
+/// round-trips exactly (`"01"` stays a string).
+fn typed_yaml_value(value: &str) -> serde_yaml::Value {
+    match serde_yaml::from_str::<serde_yaml::Value>(value) {
+        Ok(typed @ (serde_yaml::Value::Bool(_) | serde_yaml::Value::Number(_)))
+            if yaml_value_to_string(&typed).as_deref() == Some(value) =>
+        {
+            typed
+        }
+        _ => serde_yaml::Value::String(value.to_string()),
+    }
+}
+
 /// Show merged configuration.
 #[allow(clippy::too_many_lines)]
 fn show_config(
@@ -979,6 +1452,74 @@ mod tests {
         assert_eq!(parts[1], "color");
     }
 
+    #[test]
+    fn test_flatten_yaml_nested_keys() {
+        let yaml: serde_yaml::Value =
+            serde_yaml::from_str("issue_prefix: bd\ndisplay:\n  color: blue\n").unwrap();
+        let mut out = BTreeMap::new();
+        flatten_yaml(&yaml, "", &mut out);
+        assert_eq!(out.get("issue_prefix").and_then(|v| v.as_str()), Some("bd"));
+        assert_eq!(
+            out.get("display.color").and_then(|v| v.as_str()),
+            Some("blue")
+        );
+    }
+
+    #[test]
+    fn test_dedupe_spellings_prefers_normalized_key() {
+        let entries = vec![
+            ("issue_prefix".to_string(), "a"),
+            ("issue-prefix".to_string(), "b"),
+            ("ISSUE_PREFIX".to_string(), "c"),
+            ("default_type".to_string(), "task"),
+        ];
+        let deduped = dedupe_spellings(entries);
+        assert_eq!(
+            deduped.get("issue-prefix"),
+            Some(&("issue-prefix".to_string(), "b"))
+        );
+        assert_eq!(
+            deduped.get("default-type"),
+            Some(&("default_type".to_string(), "task"))
+        );
+    }
+
+    #[test]
+    fn test_typed_yaml_value_round_trips_scalars() {
+        assert_eq!(typed_yaml_value("6"), serde_yaml::Value::Number(6.into()));
+        assert_eq!(typed_yaml_value("true"), serde_yaml::Value::Bool(true));
+        assert_eq!(
+            typed_yaml_value("01"),
+            serde_yaml::Value::String("01".to_string())
+        );
+        assert_eq!(
+            typed_yaml_value("bd"),
+            serde_yaml::Value::String("bd".to_string())
+        );
+    }
+
+    #[test]
+    fn test_collect_prefixed_keys_matches_namespace() {
+        let mut layer = ConfigLayer::default();
+        layer
+            .runtime
+            .insert("external_db.api".to_string(), "../api/.beads".to_string());
+        layer
+            .runtime
+            .insert("external-db.web".to_string(), "../web/.beads".to_string());
+        layer
+            .runtime
+            .insert("issue_prefix".to_string(), "bd".to_string());
+
+        let matches = collect_prefixed_keys(&layer, "external-db.");
+        assert_eq!(matches.len(), 2);
+        assert_eq!(
+            matches.get("external-db.api").map(String::as_str),
+            Some("../api/.beads")
+        );
+        assert!(collect_prefixed_keys(&layer, "nope").is_empty());
+    }
+
     #[test]
     fn test_set_yaml_value_overwrites_scalar_root() {
         let mut config = serde_yaml::Value::String("legacy".to_string());
@@ -1027,4 +1568,67 @@ mod tests {
             Some(&serde_yaml::Value::String("blue".to_string()))
         );
     }
+
+    #[test]
+    fn test_diff_entries_reports_changed_and_one_sided_keys() {
+        let layer = |source: ConfigSource, runtime: &[(&str, &str)], startup: &[(&str, &str)]| {
+            let to_map = |entries: &[(&str, &str)]| {
+                entries
+                    .iter()
+                    .map(|(key, value)| ((*key).to_string(), (*value).to_string()))
+                    .collect()
+            };
+            LayerWithSource {
+                source,
+                layer: ConfigLayer {
+                    startup: to_map(startup),
+                    runtime: to_map(runtime),
+                },
+            }
+        };
+        // The project's `issue-prefix` overrides the default `issue_prefix`
+        // on both sides, so only the project values are compared.
+        let here = vec![
+            layer(ConfigSource::Default, &[("issue_prefix", "bd")], &[]),
+            layer(
+                ConfigSource::Project,
+                &[("issue-prefix", "abc"), ("default-priority", "2")],
+                &[("lock-timeout", "30000")],
+            ),
+        ];
+        let there = vec![
+            layer(ConfigSource::Default, &[("issue_prefix", "bd")], &[]),
+            layer(
+                ConfigSource::Project,
+                &[
+                    ("issue-prefix", "xyz"),
+                    ("default_priority", "2"),
+                    ("external-cache-ttl", "60"),
+                ],
+                &[],
+            ),
+        ];
+
+        let diffs = diff_entries(&effective_entries(&here), &effective_entries(&there));
+        assert_eq!(
+            diffs,
+            vec![
+                ConfigDiff {
+                    key: "external-cache-ttl".to_string(),
+                    here: None,
+                    there: Some("60".to_string()),
+                },
+                ConfigDiff {
+                    key: "issue-prefix".to_string(),
+                    here: Some("abc".to_string()),
+                    there: Some("xyz".to_string()),
+                },
+                ConfigDiff {
+                    key: "lock-timeout".to_string(),
+                    here: Some("30000".to_string()),
+                    there: None,
+                },
+            ]
+        );
+    }
 }
@@ -164,6 +164,8 @@ fn render_count_grouped_rich(
         CountBy::Type => "Issue Counts by Type",
         CountBy::Assignee => "Issue Counts by Assignee",
         CountBy::Label => "Issue Counts by Label",
+        CountBy::Overdue => "Issue Counts by Overdue",
+        CountBy::Labeled => "Issue Counts by Labeled",
     };
 
     let panel = Panel::from_rich_text(&content, width)
@@ -194,6 +196,18 @@ fn parse_priorities(values: &[String]) -> Result<Vec<Priority>> {
         .collect::<Result<Vec<Priority>>>()
 }
 
+/// `overdue` when the due date has passed on a non-terminal issue, else `on-time`.
+pub(crate) fn overdue_group(
+    issue: &crate::model::Issue,
+    now: chrono::DateTime<chrono::Utc>,
+) -> &'static str {
+    if issue.due_at.is_some_and(|due| due < now) && !issue.status.is_terminal() {
+        "overdue"
+    } else {
+        "on-time"
+    }
+}
+
 fn group_counts(
     storage: &SqliteStorage,
     issues: &[crate::model::Issue],
@@ -244,6 +258,23 @@ fn group_counts(
                 }
             }
         }
+        CountBy::Overdue => {
+            let now = chrono::Utc::now();
+            for issue in issues {
+                *counts
+                    .entry(overdue_group(issue, now).to_string())
+                    .or_insert(0) += 1;
+            }
+        }
+        CountBy::Labeled => {
+            let issue_ids: Vec<String> = issues.iter().map(|i| i.id.clone()).collect();
+            let labels_map = storage.get_labels_for_issues(&issue_ids)?;
+            for issue in issues {
+                let labeled = labels_map.get(&issue.id).is_some_and(|l| !l.is_empty());
+                let key = if labeled { "labeled" } else { "unlabeled" };
+                *counts.entry(key.to_string()).or_insert(0) += 1;
+            }
+        }
     }
 
     Ok(counts
@@ -1,13 +1,13 @@
 //! Defer and Undefer command implementations.
 
-use crate::cli::{DeferArgs, UndeferArgs};
+use crate::cli::{DeferArgs, DeferFilterArgs, UndeferArgs};
 use crate::config;
 use crate::error::{BeadsError, Result};
 use crate::format::ReadyIssue;
-use crate::model::{Issue, Status};
+use crate::model::{Issue, IssueType, Priority, Status};
 use crate::output::{OutputContext, OutputMode};
-use crate::storage::IssueUpdate;
-use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
+use crate::storage::{ListFilters, SqliteStorage};
+use crate::util::id::{IdResolver, find_matching_ids};
 use crate::util::time::parse_flexible_timestamp;
 use rich_rust::prelude::*;
 use serde::Serialize;
@@ -31,6 +31,9 @@ pub struct SkippedIssue {
 
 /// Execute the defer command.
 ///
+/// Issues come from explicit IDs or, for bulk deferral, from `--label`/`--type`/
+/// `--priority`/`--assignee` filters. All changes are applied in one transaction.
+///
 /// # Errors
 ///
 /// Returns an error if database operations fail or IDs cannot be resolved.
@@ -42,20 +45,14 @@ pub fn execute_defer(
 ) -> Result<()> {
     tracing::info!("Executing defer command");
 
-    if args.ids.is_empty() {
-        return Err(BeadsError::validation(
-            "ids",
-            "at least one issue ID is required",
-        ));
-    }
+    validate_targets(&args.ids, &args.filters)?;
 
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
     let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
 
     let config_layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
     let actor = config::resolve_actor(&config_layer);
-    let id_config = config::id_config_from_layer(&config_layer);
-    let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
+    let resolver = IdResolver::new(config::resolver_config_from_layer(&config_layer));
     let all_ids = storage_ctx.storage.get_all_ids()?;
     let storage = &mut storage_ctx.storage;
 
@@ -66,35 +63,22 @@ pub fn execute_defer(
         .map(|s| parse_flexible_timestamp(s, "defer_until"))
         .transpose()?;
 
-    // Resolve all IDs
-    let resolved_ids = resolver.resolve_all(
+    let (candidates, mut skipped_issues) = select_issues(
+        storage,
+        &resolver,
+        &all_ids,
         &args.ids,
-        |id| all_ids.iter().any(|existing| existing == id),
-        |hash| find_matching_ids(&all_ids, hash),
+        &args.filters,
+        false,
     )?;
 
-    let mut deferred_issues: Vec<DeferredIssue> = Vec::new();
-    let mut deferred_full: Vec<Issue> = Vec::new();
-    let mut skipped_issues: Vec<SkippedIssue> = Vec::new();
-
-    for resolved in &resolved_ids {
-        let id = &resolved.id;
-        tracing::info!(id = %id, until = ?defer_until, "Deferring issue");
-
-        // Get current issue
-        let Some(issue) = storage.get_issue(id)? else {
-            skipped_issues.push(SkippedIssue {
-                id: id.clone(),
-                reason: "issue not found".to_string(),
-            });
-            continue;
-        };
-
+    let mut eligible: Vec<Issue> = Vec::new();
+    for issue in candidates {
         // Check if already closed/tombstone
         if issue.status.is_terminal() {
-            tracing::debug!(id = %id, status = ?issue.status, "Issue is terminal");
+            tracing::debug!(id = %issue.id, status = ?issue.status, "Issue is terminal");
             skipped_issues.push(SkippedIssue {
-                id: id.clone(),
+                id: issue.id.clone(),
                 reason: format!("cannot defer {} issue", issue.status.as_str()),
             });
             continue;
@@ -102,40 +86,38 @@ pub fn execute_defer(
 
         // Check if already deferred (with same time)
         if issue.status == Status::Deferred && issue.defer_until == defer_until {
-            tracing::debug!(id = %id, "Issue already deferred with same time");
+            tracing::debug!(id = %issue.id, "Issue already deferred with same time");
             skipped_issues.push(SkippedIssue {
-                id: id.clone(),
+                id: issue.id.clone(),
                 reason: "already deferred".to_string(),
             });
             continue;
         }
 
-        // Build update: set status=deferred, set defer_until
-        let update = IssueUpdate {
-            status: Some(Status::Deferred),
-            defer_until: Some(defer_until),
-            ..Default::default()
-        };
-
-        // Apply update
-        storage.update_issue(id, &update, &actor)?;
-        tracing::info!(id = %id, defer_until = ?defer_until, "Issue deferred");
-
-        // Update last touched
-        crate::util::set_last_touched_id(&beads_dir, id);
+        eligible.push(issue);
+    }
 
-        // Get updated issue for JSON output
-        if let Some(updated) = storage.get_issue(id)? {
-            deferred_full.push(updated);
+    let deferred_full = if args.dry_run || eligible.is_empty() {
+        eligible
+    } else {
+        let ids: Vec<String> = eligible.iter().map(|issue| issue.id.clone()).collect();
+        tracing::info!(count = ids.len(), defer_until = ?defer_until, "Deferring issues");
+        let updated = storage.defer_issues(&ids, defer_until, &actor)?;
+        for issue in &updated {
+            crate::util::set_last_touched_id(&beads_dir, &issue.id);
         }
+        updated
+    };
 
-        deferred_issues.push(DeferredIssue {
-            id: id.clone(),
+    let deferred_issues: Vec<DeferredIssue> = deferred_full
+        .iter()
+        .map(|issue| DeferredIssue {
+            id: issue.id.clone(),
             title: issue.title.clone(),
             status: "deferred".to_string(),
             defer_until: defer_until.map(|dt| dt.to_rfc3339()),
-        });
-    }
+        })
+        .collect();
 
     // Output
     let use_json = ctx.is_json() || args.robot;
@@ -143,6 +125,19 @@ pub fn execute_defer(
         // bd outputs a bare array of updated issues
         let json_output: Vec<ReadyIssue> = deferred_full.iter().map(ReadyIssue::from).collect();
         ctx.json_pretty(&json_output);
+    } else if args.dry_run {
+        println!("Dry run: would defer {} issue(s)", deferred_issues.len());
+        for deferred in &deferred_issues {
+            println!(
+                "  {}: {} (until {})",
+                deferred.id,
+                deferred.title,
+                deferred.defer_until.as_deref().unwrap_or("indefinitely")
+            );
+        }
+        for skipped in &skipped_issues {
+            println!("\u{2298} Skipped {}: {}", skipped.id, skipped.reason);
+        }
     } else if matches!(ctx.mode(), OutputMode::Rich) {
         render_defer_rich(&deferred_issues, &skipped_issues, ctx);
     } else {
@@ -168,6 +163,9 @@ pub fn execute_defer(
 
 /// Execute the undefer command.
 ///
+/// Like defer, issues come from explicit IDs or filters and are updated in
+/// one transaction.
+///
 /// # Errors
 ///
 /// Returns an error if database operations fail or IDs cannot be resolved.
@@ -179,92 +177,63 @@ pub fn execute_undefer(
 ) -> Result<()> {
     tracing::info!("Executing undefer command");
 
-    if args.ids.is_empty() {
-        return Err(BeadsError::validation(
-            "ids",
-            "at least one issue ID is required",
-        ));
-    }
+    validate_targets(&args.ids, &args.filters)?;
 
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
     let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
 
     let config_layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
     let actor = config::resolve_actor(&config_layer);
-    let id_config = config::id_config_from_layer(&config_layer);
-    let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
+    let resolver = IdResolver::new(config::resolver_config_from_layer(&config_layer));
     let all_ids = storage_ctx.storage.get_all_ids()?;
     let storage = &mut storage_ctx.storage;
 
-    // Resolve all IDs
-    let resolved_ids = resolver.resolve_all(
-        &args.ids,
-        |id| all_ids.iter().any(|existing| existing == id),
-        |hash| find_matching_ids(&all_ids, hash),
-    )?;
-
-    let mut undeferred_issues: Vec<DeferredIssue> = Vec::new();
-    let mut undeferred_full: Vec<Issue> = Vec::new();
-    let mut skipped_issues: Vec<SkippedIssue> = Vec::new();
-
-    for resolved in &resolved_ids {
-        let id = &resolved.id;
-        tracing::info!(id = %id, "Undeferring issue");
-
-        // Get current issue
-        let Some(issue) = storage.get_issue(id)? else {
-            skipped_issues.push(SkippedIssue {
-                id: id.clone(),
-                reason: "issue not found".to_string(),
-            });
-            continue;
-        };
+    let (candidates, mut skipped_issues) =
+        select_issues(storage, &resolver, &all_ids, &args.ids, &args.filters, true)?;
 
+    let mut eligible: Vec<Issue> = Vec::new();
+    for issue in candidates {
         // Check if actually deferred (status or date)
         if issue.status != Status::Deferred && issue.defer_until.is_none() {
-            tracing::debug!(id = %id, status = ?issue.status, "Issue is not deferred");
+            tracing::debug!(id = %issue.id, status = ?issue.status, "Issue is not deferred");
             skipped_issues.push(SkippedIssue {
-                id: id.clone(),
+                id: issue.id.clone(),
                 reason: format!("not deferred (status: {})", issue.status.as_str()),
             });
             continue;
         }
 
-        // Build update: set status=open, clear defer_until
-        let update = IssueUpdate {
-            status: Some(Status::Open),
-            defer_until: Some(None), // Clear defer_until
-            ..Default::default()
-        };
-
-        // Apply update
-        storage.update_issue(id, &update, &actor)?;
-        tracing::info!(id = %id, "Issue undeferred");
-
-        // Update last touched
-        crate::util::set_last_touched_id(&beads_dir, id);
+        eligible.push(issue);
+    }
 
-        // Get updated issue for JSON output
-        if let Some(updated) = storage.get_issue(id)? {
-            undeferred_full.push(updated);
+    let undeferred_full = if args.dry_run || eligible.is_empty() {
+        eligible
+    } else {
+        let ids: Vec<String> = eligible.iter().map(|issue| issue.id.clone()).collect();
+        tracing::info!(count = ids.len(), "Undeferring issues");
+        let updated = storage.undefer_issues(&ids, &actor)?;
+        for issue in &updated {
+            crate::util::set_last_touched_id(&beads_dir, &issue.id);
         }
 
-        undeferred_issues.push(DeferredIssue {
-            id: id.clone(),
-            title: issue.title.clone(),
-            status: "open".to_string(),
-            defer_until: None,
-        });
-    }
-
-    // Rebuild blocked cache since undeferred issues may become blockers
-    if !undeferred_issues.is_empty() {
+        // Rebuild blocked cache since undeferred issues may become blockers
         tracing::info!(
             "Rebuilding blocked cache after undeferring {} issues",
-            undeferred_issues.len()
+            updated.len()
         );
         storage.rebuild_blocked_cache(true)?;
-    }
+        updated
+    };
+
+    let undeferred_issues: Vec<DeferredIssue> = undeferred_full
+        .iter()
+        .map(|issue| DeferredIssue {
+            id: issue.id.clone(),
+            title: issue.title.clone(),
+            status: "open".to_string(),
+            defer_until: None,
+        })
+        .collect();
 
     // Output
     let use_json = ctx.is_json() || args.robot;
@@ -272,6 +241,17 @@ pub fn execute_undefer(
         // bd outputs a bare array of updated issues
         let json_output: Vec<ReadyIssue> = undeferred_full.iter().map(ReadyIssue::from).collect();
         ctx.json_pretty(&json_output);
+    } else if args.dry_run {
+        println!(
+            "Dry run: would undefer {} issue(s)",
+            undeferred_issues.len()
+        );
+        for undeferred in &undeferred_issues {
+            println!("  {}: {}", undeferred.id, undeferred.title);
+        }
+        for skipped in &skipped_issues {
+            println!("\u{2298} Skipped {}: {}", skipped.id, skipped.reason);
+        }
     } else if matches!(ctx.mode(), OutputMode::Rich) {
         render_undefer_rich(&undeferred_issues, &skipped_issues, ctx);
     } else {
@@ -292,6 +272,94 @@ pub fn execute_undefer(
     Ok(())
 }
 
+/// Require either explicit IDs or filters, but not both.
+pub(crate) fn validate_targets(ids: &[String], filters: &DeferFilterArgs) -> Result<()> {
+    if ids.is_empty() && filters.is_empty() {
+        return Err(BeadsError::validation(
+            "ids",
+            "at least one issue ID or filter is required",
+        ));
+    }
+    if !ids.is_empty() && !filters.is_empty() {
+        return Err(BeadsError::validation(
+            "ids",
+            "pass issue IDs or filters (--label/--type/--priority/--assignee), not both",
+        ));
+    }
+    Ok(())
+}
+
+/// Load the issues named by `ids`, or every non-closed issue matching `filters`.
+///
+/// With `deferred_only`, filter matches are narrowed to issues that are
+/// deferred (by status or date) so bulk undefer doesn't report every open match.
+pub(crate) fn select_issues(
+    storage: &SqliteStorage,
+    resolver: &IdResolver,
+    all_ids: &[String],
+    ids: &[String],
+    filters: &DeferFilterArgs,
+    deferred_only: bool,
+) -> Result<(Vec<Issue>, Vec<SkippedIssue>)> {
+    let mut skipped = Vec::new();
+
+    if !ids.is_empty() {
+        let resolved_ids = resolver.resolve_all(
+            ids,
+            |id| all_ids.iter().any(|existing| existing == id),
+            |hash| find_matching_ids(all_ids, hash),
+        )?;
+        let mut issues = Vec::with_capacity(resolved_ids.len());
+        for resolved in resolved_ids {
+            match storage.get_issue(&resolved.id)? {
+                Some(issue) => issues.push(issue),
+                None => skipped.push(SkippedIssue {
+                    id: resolved.id,
+                    reason: "issue not found".to_string(),
+                }),
+            }
+        }
+        return Ok((issues, skipped));
+    }
+
+    let list_filters = ListFilters {
+        types: non_empty(parse_types(&filters.type_)?),
+        priorities: non_empty(parse_priorities(&filters.priority)?),
+        assignee: filters.assignee.clone(),
+        labels: non_empty(filters.label.clone()),
+        include_deferred: true,
+        ..ListFilters::default()
+    };
+    let mut issues = storage.list_issues(&list_filters)?;
+    if deferred_only {
+        issues.retain(|issue| issue.status == Status::Deferred || issue.defer_until.is_some());
+    }
+    tracing::debug!(count = issues.len(), "Selected issues by filter");
+    Ok((issues, skipped))
+}
+
+fn non_empty<T>(values: Vec<T>) -> Option<Vec<T>> {
+    if values.is_empty() {
+        None
+    } else {
+        Some(values)
+    }
+}
+
+fn parse_types(values: &[String]) -> Result<Vec<IssueType>> {
+    values
+        .iter()
+        .map(|value| value.parse())
+        .collect::<Result<Vec<IssueType>>>()
+}
+
+fn parse_priorities(values: &[String]) -> Result<Vec<Priority>> {
+    values
+        .iter()
+        .map(|value| value.parse())
+        .collect::<Result<Vec<Priority>>>()
+}
+
 // ─────────────────────────────────────────────────────────────
 // Rich Output Rendering
 // ─────────────────────────────────────────────────────────────
@@ -593,6 +661,8 @@ mod tests {
         let args = DeferArgs {
             ids: vec!["bd-defer-1".to_string()],
             until: Some("+1d".to_string()),
+            filters: DeferFilterArgs::default(),
+            dry_run: false,
             robot: true,
         };
         execute_defer(&args, true, &CliOverrides::default(), &ctx).expect("defer");
@@ -618,6 +688,8 @@ mod tests {
         let args = DeferArgs {
             ids: vec!["bd-defer-2".to_string()],
             until: None,
+            filters: DeferFilterArgs::default(),
+            dry_run: false,
             robot: true,
         };
         execute_defer(&args, true, &CliOverrides::default(), &ctx).expect("defer");
@@ -643,12 +715,16 @@ mod tests {
         let defer_args = DeferArgs {
             ids: vec!["bd-defer-3".to_string()],
             until: Some("+1d".to_string()),
+            filters: DeferFilterArgs::default(),
+            dry_run: false,
             robot: true,
         };
         execute_defer(&defer_args, true, &CliOverrides::default(), &ctx).expect("defer");
 
         let undefer_args = UndeferArgs {
             ids: vec!["bd-defer-3".to_string()],
+            filters: DeferFilterArgs::default(),
+            dry_run: false,
             robot: true,
         };
         execute_undefer(&undefer_args, true, &CliOverrides::default(), &ctx).expect("undefer");
@@ -657,4 +733,71 @@ mod tests {
         assert_eq!(updated.status, Status::Open);
         assert!(updated.defer_until.is_none());
     }
+
+    #[test]
+    fn execute_defer_by_label_with_dry_run() {
+        let _lock = TEST_DIR_LOCK.lock().expect("dir lock");
+        let temp = TempDir::new().expect("tempdir");
+        let ctx = OutputContext::from_flags(false, false, true);
+        commands::init::execute(None, false, Some(temp.path()), &ctx).expect("init");
+
+        let beads_dir = temp.path().join(".beads");
+        let mut storage = SqliteStorage::open(&beads_dir.join("beads.db")).expect("storage");
+        for id in ["bd-vendor-1", "bd-vendor-2", "bd-other"] {
+            storage
+                .create_issue(&make_issue(id, id), "tester")
+                .expect("create");
+        }
+        for id in ["bd-vendor-1", "bd-vendor-2"] {
+            storage
+                .add_label(id, "blocked-on-vendor", "tester")
+                .expect("label");
+        }
+
+        let _guard = DirGuard::new(temp.path());
+        let mut args = DeferArgs {
+            ids: vec![],
+            until: Some("+1w".to_string()),
+            filters: DeferFilterArgs {
+                label: vec!["blocked-on-vendor".to_string()],
+                ..DeferFilterArgs::default()
+            },
+            dry_run: true,
+            robot: true,
+        };
+        execute_defer(&args, true, &CliOverrides::default(), &ctx).expect("dry run");
+        let untouched = storage.get_issue("bd-vendor-1").expect("get").unwrap();
+        assert_eq!(untouched.status, Status::Open);
+
+        args.dry_run = false;
+        execute_defer(&args, true, &CliOverrides::default(), &ctx).expect("defer");
+        for id in ["bd-vendor-1", "bd-vendor-2"] {
+            let updated = storage.get_issue(id).expect("get").unwrap();
+            assert_eq!(updated.status, Status::Deferred);
+            assert!(updated.defer_until.is_some());
+        }
+        let other = storage.get_issue("bd-other").expect("get").unwrap();
+        assert_eq!(other.status, Status::Open);
+
+        let undefer_args = UndeferArgs {
+            ids: vec![],
+            filters: args.filters.clone(),
+            dry_run: false,
+            robot: true,
+        };
+        execute_undefer(&undefer_args, true, &CliOverrides::default(), &ctx).expect("undefer");
+        let reopened = storage.get_issue("bd-vendor-2").expect("get").unwrap();
+        assert_eq!(reopened.status, Status::Open);
+    }
+
+    #[test]
+    fn validate_targets_requires_ids_or_filters_but_not_both() {
+        let filters = DeferFilterArgs {
+            assignee: Some("alice".to_string()),
+            ..DeferFilterArgs::default()
+        };
+        assert!(validate_targets(&[], &DeferFilterArgs::default()).is_err());
+        assert!(validate_targets(&["bd-1".to_string()], &filters).is_err());
+        assert!(validate_targets(&[], &filters).is_ok());
+    }
 }
@@ -1,19 +1,22 @@
 //! Dependency command implementation.
 
 use crate::cli::{
-    DepAddArgs, DepCommands, DepCyclesArgs, DepDirection, DepListArgs, DepRemoveArgs, DepTreeArgs,
-    OutputFormat, resolve_output_format_basic,
+    DepAddArgs, DepCommands, DepConflictPolicy, DepCyclesArgs, DepDirection, DepGraphvizArgs,
+    DepImportArgs, DepListArgs, DepRemoveArgs, DepTreeArgs, DepWhyArgs, OutputFormat,
+    resolve_output_format_basic,
 };
 use crate::config;
 use crate::error::{BeadsError, Result};
 use crate::format::truncate_title;
-use crate::model::DependencyType;
+use crate::model::{Dependency, DependencyType, Issue, Status};
 use crate::output::{OutputContext, OutputMode};
 use crate::storage::SqliteStorage;
-use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
+use crate::util::id::{IdResolver, find_matching_ids};
 use rich_rust::prelude::*;
 use serde::Serialize;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::fs;
+use std::io::Read;
 use std::path::PathBuf;
 
 /// Execute the dep command.
@@ -33,14 +36,15 @@ pub fn execute(
     let config_layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
     let use_color = config::should_use_color(&config_layer);
     let quiet = cli.quiet.unwrap_or(false);
-    let id_config = config::id_config_from_layer(&config_layer);
-    let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
+    let resolver = IdResolver::new(config::resolver_config_from_layer(&config_layer));
     let all_ids = storage_ctx.storage.get_all_ids()?;
     let storage = &mut storage_ctx.storage;
 
     let actor = config::resolve_actor(&config_layer);
 
-    let external_db_paths = config::external_project_db_paths(&config_layer, &beads_dir);
+    let external_projects = config::external_project_db_paths(&config_layer, &beads_dir);
+    external_projects.report_unresolved();
+    let external_db_paths = external_projects.resolved;
 
     match command {
         DepCommands::Add(args) => dep_add(args, storage, &resolver, &all_ids, &actor, json, ctx),
@@ -67,6 +71,17 @@ pub fn execute(
             ctx,
         ),
         DepCommands::Cycles(args) => dep_cycles(args, storage, json, ctx),
+        DepCommands::Why(args) => dep_why(args, storage, &resolver, &all_ids, ctx),
+        DepCommands::Import(args) => dep_import(args, storage, &resolver, &all_ids, &actor, ctx),
+        DepCommands::Graphviz(args) => dep_graphviz(
+            args,
+            storage,
+            &resolver,
+            &all_ids,
+            &external_db_paths,
+            json,
+            ctx,
+        ),
     }?;
 
     storage_ctx.flush_no_db_if_dirty()?;
@@ -115,6 +130,41 @@ struct CyclesResult {
     count: usize,
 }
 
+/// One edge of a dependency path
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct DepPathEdge {
+    issue_id: String,
+    depends_on_id: String,
+    #[serde(rename = "type")]
+    dep_type: String,
+}
+
+/// JSON output for dep why
+#[derive(Serialize)]
+struct DepWhyResult {
+    issue_id: String,
+    target_id: String,
+    paths: Vec<Vec<DepPathEdge>>,
+    count: usize,
+}
+
+/// JSON output for dep import
+#[derive(Serialize)]
+struct DepImportResult {
+    dry_run: bool,
+    added: Vec<DepPathEdge>,
+    skipped: Vec<DepPathEdge>,
+}
+
+/// One line of a `dep import` edge list
+#[derive(Debug, PartialEq, Eq)]
+struct EdgeSpec {
+    line: usize,
+    from: String,
+    to: String,
+    dep_type: Option<String>,
+}
+
 fn dep_add(
     args: &DepAddArgs,
     storage: &mut SqliteStorage,
@@ -133,28 +183,7 @@ fn dep_add(
         resolve_issue_id(storage, resolver, all_ids, &args.depends_on)?
     };
 
-    // Parse and validate dependency type
-    let dep_type_str = &args.dep_type;
-    let dep_type: DependencyType = dep_type_str.parse().map_err(|_| BeadsError::Validation {
-        field: "type".to_string(),
-        reason: format!("Invalid dependency type: {dep_type_str}"),
-    })?;
-
-    // Disallow accidental custom types from typos
-    if let DependencyType::Custom(_) = dep_type {
-        // We enforce standard types for reliability unless it looks like a deliberate custom type
-        // For now, let's strictly enforce known types to prevent typos like "parent_child"
-        // which would otherwise be accepted as a non-blocking custom type.
-        return Err(BeadsError::Validation {
-            field: "type".to_string(),
-            reason: format!(
-                "Unknown dependency type: '{dep_type_str}'. \
-                 Allowed types: blocks, parent-child, conditional-blocks, waits-for, \
-                 related, discovered-from, replies-to, relates-to, duplicates, \
-                 supersedes, caused-by"
-            ),
-        });
-    }
+    let dep_type = parse_dep_type(&args.dep_type)?;
 
     // Self-dependency check
     if issue_id == depends_on_id {
@@ -221,6 +250,32 @@ fn dep_add(
     Ok(())
 }
 
+/// Parse a dependency type, rejecting custom types.
+fn parse_dep_type(dep_type_str: &str) -> Result<DependencyType> {
+    let dep_type: DependencyType = dep_type_str.parse().map_err(|_| BeadsError::Validation {
+        field: "type".to_string(),
+        reason: format!("Invalid dependency type: {dep_type_str}"),
+    })?;
+
+    // Disallow accidental custom types from typos
+    if let DependencyType::Custom(_) = dep_type {
+        // We enforce standard types for reliability unless it looks like a deliberate custom type
+        // For now, let's strictly enforce known types to prevent typos like "parent_child"
+        // which would otherwise be accepted as a non-blocking custom type.
+        return Err(BeadsError::Validation {
+            field: "type".to_string(),
+            reason: format!(
+                "Unknown dependency type: '{dep_type_str}'. \
+                 Allowed types: blocks, parent-child, conditional-blocks, waits-for, \
+                 related, discovered-from, replies-to, relates-to, duplicates, \
+                 supersedes, caused-by"
+            ),
+        });
+    }
+
+    Ok(dep_type)
+}
+
 fn dep_remove(
     args: &DepRemoveArgs,
     storage: &mut SqliteStorage,
@@ -239,6 +294,13 @@ fn dep_remove(
         resolve_issue_id(storage, resolver, all_ids, &args.depends_on)?
     };
 
+    // Capture the edge type before removal so JSON output can report it
+    let removed_type = storage
+        .get_dependencies_full(&issue_id)?
+        .into_iter()
+        .find(|dep| dep.depends_on_id == depends_on_id)
+        .map(|dep| dep.dep_type.as_str().to_string());
+
     let removed = storage.remove_dependency(&issue_id, &depends_on_id, actor)?;
 
     if ctx.is_json() || ctx.is_toon() {
@@ -246,7 +308,7 @@ fn dep_remove(
             status: if removed { "ok" } else { "not_found" }.to_string(),
             issue_id: issue_id.clone(),
             depends_on_id: depends_on_id.clone(),
-            dep_type: "unknown".to_string(),
+            dep_type: removed_type.unwrap_or_else(|| "unknown".to_string()),
             action: if removed { "removed" } else { "not_found" }.to_string(),
         };
         if ctx.is_toon() {
@@ -358,7 +420,7 @@ fn dep_list(
             ctx.toon_with_stats(&items, args.stats);
             return Ok(());
         }
-        OutputFormat::Text | OutputFormat::Csv => {}
+        OutputFormat::Text | OutputFormat::Csv | OutputFormat::Tsv => {}
     }
 
     if items.is_empty() {
@@ -764,7 +826,7 @@ fn build_tree_node_rich(
     tree_node
 }
 
-fn parse_external_dep_id(dep_id: &str) -> Option<(String, String)> {
+pub(crate) fn parse_external_dep_id(dep_id: &str) -> Option<(String, String)> {
     let mut parts = dep_id.splitn(3, ':');
     let prefix = parts.next()?;
     if prefix != "external" {
@@ -813,6 +875,543 @@ fn dep_cycles(
     Ok(())
 }
 
+fn dep_why(
+    args: &DepWhyArgs,
+    storage: &SqliteStorage,
+    resolver: &IdResolver,
+    all_ids: &[String],
+    ctx: &OutputContext,
+) -> Result<()> {
+    let from_id = resolve_issue_id(storage, resolver, all_ids, &args.issue)?;
+    let to_id = if args.target.starts_with("external:") {
+        args.target.clone()
+    } else {
+        resolve_issue_id(storage, resolver, all_ids, &args.target)?
+    };
+
+    let edges = storage.get_all_dependency_records()?;
+    let paths = find_dependency_paths(&edges, &from_id, &to_id, args.all_paths, args.max_depth);
+    let count = paths.len();
+
+    if ctx.is_json() || ctx.is_toon() {
+        let result = DepWhyResult {
+            issue_id: from_id,
+            target_id: to_id,
+            paths,
+            count,
+        };
+        if ctx.is_toon() {
+            ctx.toon(&result);
+        } else {
+            ctx.json_pretty(&result);
+        }
+        return Ok(());
+    }
+
+    if paths.is_empty() {
+        ctx.info(&format!("No dependency path from {from_id} to {to_id}"));
+        return Ok(());
+    }
+
+    for (i, path) in paths.iter().enumerate() {
+        let mut line = from_id.clone();
+        for edge in path {
+            line.push_str(&format!(" --{}--> {}", edge.dep_type, edge.depends_on_id));
+        }
+        if count > 1 {
+            ctx.print(&format!("{}. {line}", i + 1));
+        } else {
+            ctx.print(&line);
+        }
+    }
+
+    Ok(())
+}
+
+/// Find dependency paths from `from` to `to` following `issue -> depends_on` edges.
+///
+/// Returns the shortest path (BFS) unless `all_paths` is set, in which case every
+/// simple path up to `max_depth` edges is returned, shortest first.
+fn find_dependency_paths(
+    edges: &HashMap<String, Vec<Dependency>>,
+    from: &str,
+    to: &str,
+    all_paths: bool,
+    max_depth: usize,
+) -> Vec<Vec<DepPathEdge>> {
+    let step = |dep: &Dependency| DepPathEdge {
+        issue_id: dep.issue_id.clone(),
+        depends_on_id: dep.depends_on_id.clone(),
+        dep_type: dep.dep_type.as_str().to_string(),
+    };
+
+    if !all_paths {
+        let mut previous: HashMap<String, DepPathEdge> = HashMap::new();
+        let mut queue = VecDeque::from([(from.to_string(), 0usize)]);
+        while let Some((id, depth)) = queue.pop_front() {
+            if id == to && depth > 0 {
+                let mut path = Vec::new();
+                let mut current = id;
+                while let Some(edge) = previous.get(&current) {
+                    current.clone_from(&edge.issue_id);
+                    path.push(edge.clone());
+                }
+                path.reverse();
+                return vec![path];
+            }
+            if depth >= max_depth {
+                continue;
+            }
+            for dep in edges.get(&id).into_iter().flatten() {
+                let next = &dep.depends_on_id;
+                if next != from && !previous.contains_key(next) {
+                    previous.insert(next.clone(), step(dep));
+                    queue.push_back((next.clone(), depth + 1));
+                }
+            }
+        }
+        return Vec::new();
+    }
+
+    let mut paths = Vec::new();
+    let mut stack: Vec<(String, Vec<DepPathEdge>)> = vec![(from.to_string(), Vec::new())];
+    while let Some((id, path)) = stack.pop() {
+        if id == to && !path.is_empty() {
+            paths.push(path);
+            continue;
+        }
+        if path.len() >= max_depth {
+            continue;
+        }
+        for dep in edges.get(&id).into_iter().flatten() {
+            let next = &dep.depends_on_id;
+            let visited = next == from || path.iter().any(|edge| &edge.depends_on_id == next);
+            if !visited {
+                let mut extended = path.clone();
+                extended.push(step(dep));
+                stack.push((next.clone(), extended));
+            }
+        }
+    }
+    paths.sort_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+    paths
+}
+
+fn dep_import(
+    args: &DepImportArgs,
+    storage: &mut SqliteStorage,
+    resolver: &IdResolver,
+    all_ids: &[String],
+    actor: &str,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let content = if args.file.as_os_str() == "-" {
+        let mut buffer = String::new();
+        std::io::stdin().read_to_string(&mut buffer)?;
+        buffer
+    } else {
+        fs::read_to_string(&args.file)?
+    };
+    let specs = parse_edge_list(&content)?;
+
+    // Blocking edges already in the graph, used to reject cycles before writing
+    let mut present: HashSet<(String, String)> = HashSet::new();
+    let mut blocking: HashMap<String, Vec<String>> = HashMap::new();
+    for dep in storage
+        .get_all_dependency_records()?
+        .into_values()
+        .flatten()
+    {
+        if dep.dep_type.is_blocking() {
+            blocking
+                .entry(dep.issue_id.clone())
+                .or_default()
+                .push(dep.depends_on_id.clone());
+        }
+        present.insert((dep.issue_id, dep.depends_on_id));
+    }
+
+    let mut added = Vec::new();
+    let mut skipped = Vec::new();
+    for spec in &specs {
+        let issue_id = resolve_issue_id(storage, resolver, all_ids, &spec.from)?;
+        let depends_on_id = if spec.to.starts_with("external:") {
+            spec.to.clone()
+        } else {
+            resolve_issue_id(storage, resolver, all_ids, &spec.to)?
+        };
+        let dep_type = parse_dep_type(spec.dep_type.as_deref().unwrap_or("blocks"))?;
+
+        if issue_id == depends_on_id {
+            return Err(BeadsError::SelfDependency { id: issue_id });
+        }
+
+        let edge = DepPathEdge {
+            issue_id: issue_id.clone(),
+            depends_on_id: depends_on_id.clone(),
+            dep_type: dep_type.as_str().to_string(),
+        };
+
+        if !present.insert((issue_id.clone(), depends_on_id.clone())) {
+            if args.on_conflict == DepConflictPolicy::Error {
+                return Err(BeadsError::validation(
+                    "file",
+                    format!(
+                        "line {}: dependency {issue_id} -> {depends_on_id} already exists",
+                        spec.line
+                    ),
+                ));
+            }
+            skipped.push(edge);
+            continue;
+        }
+
+        if dep_type.is_blocking() && !depends_on_id.starts_with("external:") {
+            if graph_reaches(&blocking, &depends_on_id, &issue_id) {
+                return Err(BeadsError::DependencyCycle {
+                    path: format!("line {}: {issue_id} -> {depends_on_id}", spec.line),
+                });
+            }
+            blocking
+                .entry(issue_id.clone())
+                .or_default()
+                .push(depends_on_id.clone());
+        }
+
+        added.push(edge);
+    }
+
+    if !args.dry_run && !added.is_empty() {
+        let edges: Vec<(String, String, String)> = added
+            .iter()
+            .map(|edge| {
+                (
+                    edge.issue_id.clone(),
+                    edge.depends_on_id.clone(),
+                    edge.dep_type.clone(),
+                )
+            })
+            .collect();
+        storage.add_dependencies(&edges, actor)?;
+    }
+
+    if ctx.is_json() || ctx.is_toon() {
+        let result = DepImportResult {
+            dry_run: args.dry_run,
+            added,
+            skipped,
+        };
+        if ctx.is_toon() {
+            ctx.toon(&result);
+        } else {
+            ctx.json_pretty(&result);
+        }
+        return Ok(());
+    }
+
+    if args.dry_run {
+        ctx.info(&format!(
+            "Dry run: would add {} dependencies ({} skipped)",
+            added.len(),
+            skipped.len()
+        ));
+        for edge in &added {
+            ctx.print(&format!(
+                "  {} -> {} ({})",
+                edge.issue_id, edge.depends_on_id, edge.dep_type
+            ));
+        }
+    } else {
+        ctx.success(&format!(
+            "Imported {} dependencies ({} skipped)",
+            added.len(),
+            skipped.len()
+        ));
+    }
+
+    Ok(())
+}
+
+/// JSON form of `dep graphviz`: the same nodes and edges the DOT output draws.
+#[derive(Serialize)]
+struct DepGraphJson {
+    nodes: Vec<DepGraphNode>,
+    edges: Vec<DepGraphEdge>,
+}
+
+#[derive(Serialize)]
+struct DepGraphNode {
+    id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<String>,
+    external: bool,
+    /// For external dependencies, whether the capability is satisfied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    satisfied: Option<bool>,
+}
+
+#[derive(Serialize)]
+struct DepGraphEdge {
+    issue_id: String,
+    depends_on_id: String,
+    #[serde(rename = "type")]
+    dep_type: String,
+}
+
+fn dep_graphviz(
+    args: &DepGraphvizArgs,
+    storage: &SqliteStorage,
+    resolver: &IdResolver,
+    all_ids: &[String],
+    external_db_paths: &HashMap<String, PathBuf>,
+    json: bool,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let root_id = args
+        .issue
+        .as_deref()
+        .map(|input| resolve_issue_id(storage, resolver, all_ids, input))
+        .transpose()?;
+
+    let all_edges = storage.get_all_dependency_records()?;
+    let (edges, node_ids) = select_graph(&all_edges, root_id.as_deref(), args.max_depth);
+
+    let issue_ids: Vec<String> = node_ids
+        .iter()
+        .filter(|id| !id.starts_with("external:"))
+        .cloned()
+        .collect();
+    let issues: HashMap<String, Issue> = storage
+        .get_issues_by_ids(&issue_ids)?
+        .into_iter()
+        .map(|issue| (issue.id.clone(), issue))
+        .collect();
+    let external_statuses =
+        storage.resolve_external_dependency_statuses(external_db_paths, false)?;
+
+    if json {
+        ctx.json_pretty(&graph_json(&edges, &node_ids, &issues, &external_statuses));
+        return Ok(());
+    }
+
+    // Use print! directly: DOT is meant to be piped, not styled
+    print!(
+        "{}",
+        render_dot(&edges, &node_ids, &issues, &external_statuses)
+    );
+    Ok(())
+}
+
+/// Pick the edges and nodes to draw: everything, or what `root` reaches within `max_depth`.
+fn select_graph(
+    all_edges: &HashMap<String, Vec<Dependency>>,
+    root: Option<&str>,
+    max_depth: usize,
+) -> (Vec<Dependency>, BTreeSet<String>) {
+    let mut edges = Vec::new();
+    let mut nodes = BTreeSet::new();
+
+    let Some(root) = root else {
+        for dep in all_edges.values().flatten() {
+            nodes.insert(dep.issue_id.clone());
+            nodes.insert(dep.depends_on_id.clone());
+            edges.push(dep.clone());
+        }
+        edges.sort_by(|a, b| (&a.issue_id, &a.depends_on_id).cmp(&(&b.issue_id, &b.depends_on_id)));
+        return (edges, nodes);
+    };
+
+    nodes.insert(root.to_string());
+    let mut queue = VecDeque::from([(root.to_string(), 0usize)]);
+    let mut expanded = HashSet::new();
+    while let Some((id, depth)) = queue.pop_front() {
+        if depth >= max_depth || !expanded.insert(id.clone()) {
+            continue;
+        }
+        for dep in all_edges.get(&id).into_iter().flatten() {
+            edges.push(dep.clone());
+            if nodes.insert(dep.depends_on_id.clone()) {
+                queue.push_back((dep.depends_on_id.clone(), depth + 1));
+            }
+        }
+    }
+    (edges, nodes)
+}
+
+/// Build the `--json` form of a dependency graph.
+fn graph_json(
+    edges: &[Dependency],
+    node_ids: &BTreeSet<String>,
+    issues: &HashMap<String, Issue>,
+    external_statuses: &HashMap<String, bool>,
+) -> DepGraphJson {
+    let nodes = node_ids
+        .iter()
+        .map(|id| {
+            let external = id.starts_with("external:");
+            let issue = issues.get(id);
+            DepGraphNode {
+                id: id.clone(),
+                title: issue.map(|issue| issue.title.clone()),
+                status: issue.map(|issue| issue.status.as_str().to_string()),
+                external,
+                satisfied: external.then(|| external_statuses.get(id).copied().unwrap_or(false)),
+            }
+        })
+        .collect();
+    let edges = edges
+        .iter()
+        .map(|dep| DepGraphEdge {
+            issue_id: dep.issue_id.clone(),
+            depends_on_id: dep.depends_on_id.clone(),
+            dep_type: dep.dep_type.as_str().to_string(),
+        })
+        .collect();
+    DepGraphJson { nodes, edges }
+}
+
+/// Render a dependency graph as Graphviz DOT.
+///
+/// Nodes are filled by status, external dependencies are dashed ellipses
+/// (green when satisfied), and edges point from an issue to what it depends on,
+/// labeled with the dependency type.
+fn render_dot(
+    edges: &[Dependency],
+    node_ids: &BTreeSet<String>,
+    issues: &HashMap<String, Issue>,
+    external_statuses: &HashMap<String, bool>,
+) -> String {
+    let mut out = String::from("digraph dependencies {\n");
+    out.push_str("  rankdir=LR;\n");
+    out.push_str("  node [shape=box, style=\"rounded,filled\", fontname=\"Helvetica\"];\n");
+    out.push_str("  edge [fontname=\"Helvetica\", fontsize=10];\n");
+
+    for id in node_ids {
+        if id.starts_with("external:") {
+            let label = parse_external_dep_id(id)
+                .map_or_else(|| id.clone(), |(project, cap)| format!("{project}:{cap}"));
+            let satisfied = external_statuses.get(id).copied().unwrap_or(false);
+            let color = if satisfied {
+                "forestgreen"
+            } else {
+                "firebrick"
+            };
+            out.push_str(&format!(
+                "  \"{}\" [label=\"{}\", shape=ellipse, style=dashed, color={color}];\n",
+                dot_escape(id),
+                dot_escape(&label)
+            ));
+        } else if let Some(issue) = issues.get(id) {
+            out.push_str(&format!(
+                "  \"{}\" [label=\"{}\\n{}\", fillcolor={}];\n",
+                dot_escape(id),
+                dot_escape(id),
+                dot_escape(&truncate_title(&issue.title, 40)),
+                status_fill_color(&issue.status)
+            ));
+        } else {
+            out.push_str(&format!(
+                "  \"{}\" [style=\"rounded,dashed\"];\n",
+                dot_escape(id)
+            ));
+        }
+    }
+
+    for dep in edges {
+        let style = if dep.dep_type.is_blocking() {
+            ""
+        } else {
+            ", style=dashed"
+        };
+        out.push_str(&format!(
+            "  \"{}\" -> \"{}\" [label=\"{}\"{style}];\n",
+            dot_escape(&dep.issue_id),
+            dot_escape(&dep.depends_on_id),
+            dot_escape(dep.dep_type.as_str())
+        ));
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+const fn status_fill_color(status: &Status) -> &'static str {
+    match status {
+        Status::Open => "lightblue",
+        Status::InProgress => "gold",
+        Status::Blocked => "salmon",
+        Status::Deferred | Status::Pinned => "lightgray",
+        Status::Closed => "palegreen",
+        Status::Tombstone => "gray",
+        Status::Custom(_) => "white",
+    }
+}
+
+/// Escape a string for use inside a double-quoted DOT identifier or label.
+fn dot_escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Parse an edge list: one `from -> to [type]` per line.
+///
+/// Blank lines and `#` comments are ignored; the type defaults to `blocks`.
+fn parse_edge_list(content: &str) -> Result<Vec<EdgeSpec>> {
+    let mut specs = Vec::new();
+    for (idx, raw) in content.lines().enumerate() {
+        let line = raw.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let malformed = || {
+            BeadsError::validation(
+                "file",
+                format!(
+                    "line {}: expected '<from> -> <to> [type]', got '{line}'",
+                    idx + 1
+                ),
+            )
+        };
+        let (from, rest) = line.split_once("->").ok_or_else(malformed)?;
+        let from = from.trim();
+        let mut parts = rest.split_whitespace();
+        let to = parts.next().ok_or_else(malformed)?;
+        let dep_type = parts.next();
+        if from.is_empty() || from.contains(char::is_whitespace) || parts.next().is_some() {
+            return Err(malformed());
+        }
+
+        specs.push(EdgeSpec {
+            line: idx + 1,
+            from: from.to_string(),
+            to: to.to_string(),
+            dep_type: dep_type.map(str::to_string),
+        });
+    }
+    Ok(specs)
+}
+
+/// Whether `to` is reachable from `from` in `graph`.
+fn graph_reaches(graph: &HashMap<String, Vec<String>>, from: &str, to: &str) -> bool {
+    let mut seen: HashSet<&str> = HashSet::new();
+    let mut stack = vec![from];
+    while let Some(id) = stack.pop() {
+        if id == to {
+            return true;
+        }
+        if !seen.insert(id) {
+            continue;
+        }
+        stack.extend(graph.get(id).into_iter().flatten().map(String::as_str));
+    }
+    false
+}
+
 /// Render cycles in rich mode with red highlighting
 fn render_cycles_rich(ctx: &OutputContext, cycles: &[Vec<String>], count: usize) {
     let theme = ctx.theme();
@@ -1306,6 +1905,157 @@ mod tests {
         info!("test_apply_external_dep_list_metadata_external_issue_id: assertions passed");
     }
 
+    #[test]
+    fn test_find_dependency_paths() {
+        init_test_logging();
+        info!("test_find_dependency_paths: starting");
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        for id in ["bd-a", "bd-b", "bd-c", "bd-d"] {
+            storage
+                .create_issue(&make_test_issue(id, id), "tester")
+                .unwrap();
+        }
+        // bd-a -> bd-b -> bd-d and bd-a -> bd-c -> bd-d (bd-c via parent-child)
+        storage
+            .add_dependency("bd-a", "bd-b", "blocks", "tester")
+            .unwrap();
+        storage
+            .add_dependency("bd-b", "bd-d", "blocks", "tester")
+            .unwrap();
+        storage
+            .add_dependency("bd-a", "bd-c", "parent-child", "tester")
+            .unwrap();
+        storage
+            .add_dependency("bd-c", "bd-d", "blocks", "tester")
+            .unwrap();
+        let edges = storage.get_all_dependency_records().unwrap();
+
+        let shortest = find_dependency_paths(&edges, "bd-a", "bd-d", false, 10);
+        assert_eq!(shortest.len(), 1);
+        assert_eq!(shortest[0].len(), 2);
+        assert_eq!(shortest[0][0].issue_id, "bd-a");
+        assert_eq!(shortest[0][1].depends_on_id, "bd-d");
+
+        let all = find_dependency_paths(&edges, "bd-a", "bd-d", true, 10);
+        assert_eq!(all.len(), 2);
+        assert!(all.iter().any(|p| p[0].dep_type == "parent-child"));
+
+        assert!(find_dependency_paths(&edges, "bd-d", "bd-a", false, 10).is_empty());
+        assert!(find_dependency_paths(&edges, "bd-a", "bd-d", true, 1).is_empty());
+        info!("test_find_dependency_paths: assertions passed");
+    }
+
+    #[test]
+    fn test_parse_edge_list() {
+        init_test_logging();
+        info!("test_parse_edge_list: starting");
+        let specs = parse_edge_list(
+            "# migrated from tracker\nbd-a -> bd-b\n\n  bd-b->bd-c parent-child  # epic\n",
+        )
+        .unwrap();
+        assert_eq!(specs.len(), 2);
+        assert_eq!(specs[0].line, 2);
+        assert_eq!(specs[0].dep_type, None);
+        assert_eq!(specs[1].from, "bd-b");
+        assert_eq!(specs[1].to, "bd-c");
+        assert_eq!(specs[1].dep_type.as_deref(), Some("parent-child"));
+
+        assert!(parse_edge_list("bd-a bd-b\n").is_err());
+        assert!(parse_edge_list("bd-a ->\n").is_err());
+        assert!(parse_edge_list("bd-a -> bd-b blocks extra\n").is_err());
+
+        let graph = HashMap::from([
+            ("bd-a".to_string(), vec!["bd-b".to_string()]),
+            ("bd-b".to_string(), vec!["bd-c".to_string()]),
+        ]);
+        assert!(graph_reaches(&graph, "bd-a", "bd-c"));
+        assert!(!graph_reaches(&graph, "bd-c", "bd-a"));
+        info!("test_parse_edge_list: assertions passed");
+    }
+
+    fn make_edge(from: &str, to: &str, dep_type: DependencyType) -> Dependency {
+        Dependency {
+            issue_id: from.to_string(),
+            depends_on_id: to.to_string(),
+            dep_type,
+            created_at: Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+            created_by: None,
+            metadata: None,
+            thread_id: None,
+        }
+    }
+
+    #[test]
+    fn test_graphviz_select_and_render() {
+        init_test_logging();
+        info!("test_graphviz_select_and_render: starting");
+        let all_edges = HashMap::from([
+            (
+                "bd-a".to_string(),
+                vec![make_edge("bd-a", "bd-b", DependencyType::Blocks)],
+            ),
+            (
+                "bd-b".to_string(),
+                vec![
+                    make_edge("bd-b", "bd-c", DependencyType::Related),
+                    make_edge("bd-b", "external:api:auth", DependencyType::Blocks),
+                ],
+            ),
+            (
+                "bd-x".to_string(),
+                vec![make_edge("bd-x", "bd-y", DependencyType::Blocks)],
+            ),
+        ]);
+
+        let (edges, nodes) = select_graph(&all_edges, None, 10);
+        assert_eq!(edges.len(), 4);
+        assert_eq!(nodes.len(), 6);
+
+        let (edges, nodes) = select_graph(&all_edges, Some("bd-a"), 1);
+        assert_eq!(edges.len(), 1);
+        assert!(nodes.contains("bd-b") && !nodes.contains("bd-c"));
+
+        let (edges, nodes) = select_graph(&all_edges, Some("bd-a"), 10);
+        assert_eq!(edges.len(), 3);
+        assert!(!nodes.contains("bd-x"));
+
+        let mut blocked = make_test_issue("bd-b", "Say \"hi\"");
+        blocked.status = Status::Blocked;
+        let issues = HashMap::from([
+            ("bd-a".to_string(), make_test_issue("bd-a", "Root")),
+            ("bd-b".to_string(), blocked),
+        ]);
+        let externals = HashMap::from([("external:api:auth".to_string(), true)]);
+        let dot = render_dot(&edges, &nodes, &issues, &externals);
+
+        assert!(dot.starts_with("digraph dependencies {\n"));
+        assert!(dot.ends_with("}\n"));
+        assert!(dot.contains("\"bd-a\" [label=\"bd-a\\nRoot\", fillcolor=lightblue];"));
+        assert!(dot.contains("label=\"bd-b\\nSay \\\"hi\\\"\", fillcolor=salmon"));
+        assert!(dot.contains("\"bd-a\" -> \"bd-b\" [label=\"blocks\"];"));
+        assert!(dot.contains("\"bd-b\" -> \"bd-c\" [label=\"related\", style=dashed];"));
+        assert!(
+            dot.contains("[label=\"api:auth\", shape=ellipse, style=dashed, color=forestgreen]")
+        );
+
+        let json = serde_json::to_value(graph_json(&edges, &nodes, &issues, &externals)).unwrap();
+        assert_eq!(json["edges"].as_array().unwrap().len(), 3);
+        assert_eq!(json["edges"][0]["type"], "blocks");
+        let node = |id: &str| {
+            json["nodes"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|node| node["id"] == id)
+                .cloned()
+                .unwrap()
+        };
+        assert_eq!(node("bd-b")["status"], "blocked");
+        assert_eq!(node("external:api:auth")["satisfied"], true);
+        assert!(node("bd-c").get("title").is_none());
+        info!("test_graphviz_select_and_render: assertions passed");
+    }
+
     #[test]
     fn test_dep_direction_variants() {
         init_test_logging();
@@ -2,17 +2,21 @@
 
 #![allow(clippy::option_if_let_else)]
 
+use crate::cli::DoctorArgs;
 use crate::config;
 use crate::error::Result;
 use crate::output::OutputContext;
+use crate::storage::schema::apply_schema;
 use crate::sync::{
     PathValidation, scan_conflict_markers, validate_no_git_path, validate_sync_path,
 };
+use chrono::Utc;
 use rich_rust::prelude::*;
 use rusqlite::{Connection, OpenFlags};
 use serde::Serialize;
+use std::collections::{BTreeMap, HashSet};
 use std::fs::{self, File};
-use std::io::{BufRead, BufReader};
+use std::io::{self, BufRead, BufReader, IsTerminal, Write};
 use std::path::{Path, PathBuf};
 
 /// Check result status.
@@ -38,6 +42,33 @@ struct CheckResult {
 struct DoctorReport {
     ok: bool,
     checks: Vec<CheckResult>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<FixResult>,
+}
+
+/// Outcome of a `--fix` repair.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum FixStatus {
+    Applied,
+    Planned,
+    Skipped,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct FixResult {
+    /// Name of the check the repair addresses.
+    check: String,
+    status: FixStatus,
+    action: String,
+}
+
+fn push_fix(fixes: &mut Vec<FixResult>, check: &str, status: FixStatus, action: String) {
+    fixes.push(FixResult {
+        check: check.to_string(),
+        status,
+        action,
+    });
 }
 
 fn push_check(
@@ -93,6 +124,14 @@ fn print_report_plain(report: &DoctorReport) {
             println!("{label} {}", check.name);
         }
     }
+    for fix in &report.fixes {
+        let label = match fix.status {
+            FixStatus::Applied => "FIXED",
+            FixStatus::Planned => "WOULD FIX",
+            FixStatus::Skipped => "SKIPPED",
+        };
+        println!("{label} {}: {}", fix.check, fix.action);
+    }
 }
 
 fn render_doctor_rich(report: &DoctorReport, ctx: &OutputContext) {
@@ -157,6 +196,24 @@ fn render_doctor_rich(report: &DoctorReport, ctx: &OutputContext) {
         }
     }
 
+    if !report.fixes.is_empty() {
+        content.append("\n");
+        content.append_styled("Repairs\n", theme.emphasis.clone());
+        for fix in &report.fixes {
+            let (label, style) = match fix.status {
+                FixStatus::Applied => ("[FIXED]", theme.success.clone()),
+                FixStatus::Planned => ("[WOULD FIX]", theme.accent.clone()),
+                FixStatus::Skipped => ("[SKIPPED]", theme.warning.clone()),
+            };
+            content.append_styled(label, style);
+            content.append(" ");
+            content.append_styled(&fix.check, theme.issue_title.clone());
+            content.append_styled(": ", theme.dimmed.clone());
+            content.append(&fix.action);
+            content.append("\n");
+        }
+    }
+
     let panel = Panel::from_rich_text(&content, ctx.width())
         .title(Text::styled("Doctor", theme.panel_title.clone()))
         .box_style(theme.box_style)
@@ -444,6 +501,30 @@ fn check_db_count(
     Ok(())
 }
 
+fn check_hash_prefix_len(conn: &Connection, checks: &mut Vec<CheckResult>) -> Result<()> {
+    let mut stmt = conn.prepare("SELECT id FROM issues")?;
+    let ids = stmt
+        .query_map([], |row| row.get::<_, String>(0))?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    let min_len = crate::util::id::min_unique_hash_len(&ids);
+
+    push_check(
+        checks,
+        "ids.min_hash_len",
+        CheckStatus::Ok,
+        Some(format!(
+            "{min_len} hash characters uniquely identify {} issues",
+            ids.len()
+        )),
+        Some(serde_json::json!({
+            "min_hash_len": min_len,
+            "issues": ids.len()
+        })),
+    );
+
+    Ok(())
+}
+
 // ============================================================================
 // SYNC SAFETY CHECKS (beads_rust-0v1.2.6)
 // ============================================================================
@@ -785,60 +866,513 @@ fn check_sync_metadata(
     }
 }
 
-/// Execute the doctor command.
-///
-/// # Errors
-///
-/// Returns an error if report serialization fails or if IO operations fail.
-#[allow(clippy::too_many_lines)]
-pub fn execute(cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
-    let mut checks = Vec::new();
-    let Ok(beads_dir) = config::discover_beads_dir(None) else {
+/// Check config files for keys that shadow each other after normalization.
+fn check_config_shadowing(
+    layers: Vec<(&str, Result<config::ConfigLayer>)>,
+    checks: &mut Vec<CheckResult>,
+) {
+    let mut shadowed = Vec::new();
+    for (source, layer) in layers {
+        match layer {
+            Ok(layer) => {
+                for (key, spellings) in config::shadowed_keys(&layer) {
+                    shadowed.push(serde_json::json!({
+                        "source": source,
+                        "key": key,
+                        "spellings": spellings,
+                    }));
+                }
+            }
+            Err(err) => {
+                push_check(
+                    checks,
+                    "config.parse",
+                    CheckStatus::Error,
+                    Some(format!("Failed to load {source} config: {err}")),
+                    None,
+                );
+                return;
+            }
+        }
+    }
+
+    if shadowed.is_empty() {
+        push_check(checks, "config.shadowing", CheckStatus::Ok, None, None);
+    } else {
         push_check(
-            &mut checks,
-            "beads_dir",
-            CheckStatus::Error,
-            Some("Missing .beads directory (run `br init`)".to_string()),
+            checks,
+            "config.shadowing",
+            CheckStatus::Warn,
+            Some(format!(
+                "{} config key(s) set under multiple spellings",
+                shadowed.len()
+            )),
+            Some(serde_json::json!({
+                "keys": shadowed,
+                "remediation": "Keep a single spelling per key (e.g. issue-prefix)"
+            })),
+        );
+    }
+}
+
+/// Check that configured external projects resolve to an existing database.
+fn check_external_projects(
+    beads_dir: &Path,
+    layer: &config::ConfigLayer,
+    checks: &mut Vec<CheckResult>,
+) {
+    let projects = config::external_projects_from_layer(layer, beads_dir);
+    if projects.is_empty() {
+        return;
+    }
+
+    let mut names: Vec<&String> = projects.keys().collect();
+    names.sort();
+
+    let mut unresolved = Vec::new();
+    for name in names {
+        let path = &projects[name];
+        let external_beads = if path.file_name().is_some_and(|n| n == ".beads") {
+            path.clone()
+        } else {
+            path.join(".beads")
+        };
+        let db_path = config::resolve_paths(&external_beads, None)
+            .ok()
+            .map(|paths| paths.db_path)
+            .filter(|db| external_beads.is_dir() && db.exists());
+        if db_path.is_none() {
+            unresolved.push(serde_json::json!({
+                "project": name,
+                "path": external_beads.display().to_string(),
+            }));
+        }
+    }
+
+    if unresolved.is_empty() {
+        push_check(
+            checks,
+            "config.external_projects",
+            CheckStatus::Ok,
+            Some(format!("{} external project(s) resolved", projects.len())),
             None,
         );
-        let report = DoctorReport {
-            ok: !has_error(&checks),
+    } else {
+        push_check(
             checks,
-        };
-        print_report(&report, ctx)?;
-        std::process::exit(1);
+            "config.external_projects",
+            CheckStatus::Warn,
+            Some(format!(
+                "{} external project(s) have no reachable database",
+                unresolved.len()
+            )),
+            Some(serde_json::json!({
+                "projects": unresolved,
+                "remediation": "Fix external_projects.<name> paths in config"
+            })),
+        );
+    }
+}
+
+/// Config layers that `--fix` repairs for shadowed keys.
+struct ConfigTargets {
+    /// `.beads/config.yaml`.
+    project: PathBuf,
+    /// The user config file, when HOME is set.
+    user: Option<PathBuf>,
+    /// The database holding the `config` table.
+    db: PathBuf,
+}
+
+/// Which spelling of a shadowed key survives the repair.
+///
+/// `preferred` wins when present; otherwise the first spelling is kept.
+fn kept_spelling<'a>(spellings: &'a [String], preferred: &str) -> &'a str {
+    spellings
+        .iter()
+        .find(|spelling| spelling.as_str() == preferred)
+        .unwrap_or(&spellings[0])
+}
+
+/// Drop shadowed keys from every config layer.
+fn fix_config_shadowing(
+    targets: &ConfigTargets,
+    dry_run: bool,
+    fixes: &mut Vec<FixResult>,
+) -> Result<()> {
+    fix_config_file(&targets.project, "project", dry_run, fixes)?;
+    if let Some(user) = targets.user.as_deref() {
+        fix_config_file(user, "user", dry_run, fixes)?;
+    }
+    fix_db_config(&targets.db, dry_run, fixes)
+}
+
+/// The key of a top-level `key: value` line, if `line` starts one.
+fn top_level_key(line: &str) -> Option<&str> {
+    let first = line.chars().next()?;
+    if first.is_whitespace() || matches!(first, '#' | '-') {
+        return None;
+    }
+    let (key, rest) = if first == '"' || first == '\'' {
+        let end = line[1..].find(first)? + 1;
+        (&line[1..end], &line[end + 1..])
+    } else {
+        let colon = line.find(':')?;
+        (line[..colon].trim_end(), &line[colon..])
     };
+    let rest = rest.trim_start().strip_prefix(':')?;
+    (rest.is_empty() || rest.starts_with(char::is_whitespace)).then_some(key)
+}
 
-    let paths = match config::resolve_paths(&beads_dir, cli.db.as_ref()) {
-        Ok(paths) => paths,
-        Err(err) => {
-            push_check(
-                &mut checks,
-                "metadata",
-                CheckStatus::Error,
-                Some(format!("Failed to read metadata.json: {err}")),
-                None,
+/// Whether `line` continues the block value of the preceding top-level key.
+fn continues_block(line: &str) -> bool {
+    line.starts_with([' ', '\t']) || (line.starts_with('-') && !line.starts_with("---"))
+}
+
+/// Remove the lines of the top-level keys in `shadowed`, keeping everything else.
+fn remove_top_level_keys(contents: &str, shadowed: &HashSet<String>) -> String {
+    let mut out = String::with_capacity(contents.len());
+    let mut dropping = false;
+    // Blank lines inside a dropped block are only dropped if the block goes on.
+    let mut pending_blank = String::new();
+    for line in contents.split_inclusive('\n') {
+        if let Some(key) = top_level_key(line) {
+            dropping = shadowed.contains(key);
+        } else if dropping && line.trim().is_empty() {
+            pending_blank.push_str(line);
+            continue;
+        } else if dropping && continues_block(line) {
+            pending_blank.clear();
+            continue;
+        } else {
+            dropping = false;
+        }
+        out.push_str(&pending_blank);
+        pending_blank.clear();
+        if !dropping {
+            out.push_str(line);
+        }
+    }
+    out.push_str(&pending_blank);
+    out
+}
+
+/// A backup path next to `path` that does not clobber an earlier backup.
+fn backup_path(path: &Path) -> PathBuf {
+    let name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("config.yaml");
+    let timestamp = Utc::now().format("%Y%m%d_%H%M%S");
+    let mut candidate = path.with_file_name(format!("{name}.{timestamp}.bak"));
+    let mut attempt = 1;
+    while candidate.exists() {
+        candidate = path.with_file_name(format!("{name}.{timestamp}.{attempt}.bak"));
+        attempt += 1;
+    }
+    candidate
+}
+
+/// Drop shadowed top-level keys from a YAML config file.
+///
+/// Only keys whose spellings all carry the same value are repaired: the
+/// hyphenated `issue-prefix` form is kept (or the first spelling if none is
+/// hyphenated) and the other lines are removed in place, so comments and key
+/// order survive. Conflicting values are left for the user to resolve. The
+/// original file is kept as a timestamped `config.yaml.<time>.bak`.
+fn fix_config_file(
+    path: &Path,
+    source: &str,
+    dry_run: bool,
+    fixes: &mut Vec<FixResult>,
+) -> Result<()> {
+    if !path.exists() {
+        return Ok(());
+    }
+    let contents = fs::read_to_string(path)?;
+    let serde_yaml::Value::Mapping(map) = serde_yaml::from_str(&contents)? else {
+        return Ok(());
+    };
+
+    let mut groups: BTreeMap<String, Vec<(String, serde_yaml::Value)>> = BTreeMap::new();
+    for (key, value) in map {
+        if let Some(key) = key.as_str() {
+            groups
+                .entry(config::normalize_key(key))
+                .or_default()
+                .push((key.to_string(), value));
+        }
+    }
+
+    let mut shadowed = HashSet::new();
+    for (canonical, entries) in groups {
+        if entries.len() < 2 {
+            continue;
+        }
+        let spellings: Vec<String> = entries.iter().map(|(key, _)| key.clone()).collect();
+        let listed = spellings.join(", ");
+        if entries.iter().any(|(_, value)| *value != entries[0].1) {
+            push_fix(
+                fixes,
+                "config.shadowing",
+                FixStatus::Skipped,
+                format!(
+                    "{listed} have different values; keep one in {}",
+                    path.display()
+                ),
             );
-            let report = DoctorReport {
-                ok: !has_error(&checks),
-                checks,
-            };
-            print_report(&report, ctx)?;
-            std::process::exit(1);
+            continue;
         }
+
+        let kept = kept_spelling(&spellings, &canonical);
+        let dropped: Vec<&str> = spellings
+            .iter()
+            .map(String::as_str)
+            .filter(|key| *key != kept)
+            .collect();
+        let removed = dropped.join(", ");
+        push_fix(
+            fixes,
+            "config.shadowing",
+            if dry_run {
+                FixStatus::Planned
+            } else {
+                FixStatus::Applied
+            },
+            format!(
+                "{} {removed} from {source} config {} (keeping {kept})",
+                if dry_run { "would remove" } else { "removed" },
+                path.display()
+            ),
+        );
+        shadowed.extend(dropped.into_iter().map(str::to_string));
+    }
+
+    if !dry_run && !shadowed.is_empty() {
+        fs::copy(path, backup_path(path))?;
+        fs::write(path, remove_top_level_keys(&contents, &shadowed))?;
+    }
+    Ok(())
+}
+
+/// Read the `config` table; a database without one has no entries.
+fn read_db_config(conn: &Connection) -> Result<Vec<(String, String)>> {
+    if collect_table_columns(conn, "config")?.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut stmt = conn.prepare("SELECT key, value FROM config ORDER BY key")?;
+    let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+    let mut entries = Vec::new();
+    for row in rows {
+        entries.push(row?);
+    }
+    Ok(entries)
+}
+
+/// The database config as a layer, for the shadowing check.
+fn load_db_config(db_path: &Path) -> Result<config::ConfigLayer> {
+    let mut layer = config::ConfigLayer::default();
+    if !db_path.exists() {
+        return Ok(layer);
+    }
+    let conn = Connection::open_with_flags(db_path, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+    for (key, value) in read_db_config(&conn)? {
+        if !config::is_startup_key(&key) {
+            layer.runtime.insert(key, value);
+        }
+    }
+    Ok(layer)
+}
+
+/// Drop shadowed keys from the database `config` table.
+///
+/// The database is read by its snake_case keys (`issue_prefix`), so that
+/// spelling is kept; keys with conflicting values are left alone.
+fn fix_db_config(db_path: &Path, dry_run: bool, fixes: &mut Vec<FixResult>) -> Result<()> {
+    if !db_path.exists() {
+        return Ok(());
+    }
+    let mut conn = Connection::open(db_path)?;
+    let mut groups: BTreeMap<String, Vec<(String, String)>> = BTreeMap::new();
+    for (key, value) in read_db_config(&conn)? {
+        if !config::is_startup_key(&key) {
+            groups
+                .entry(config::normalize_key(&key))
+                .or_default()
+                .push((key, value));
+        }
+    }
+
+    let mut shadowed = Vec::new();
+    for (canonical, entries) in groups {
+        if entries.len() < 2 {
+            continue;
+        }
+        let spellings: Vec<String> = entries.iter().map(|(key, _)| key.clone()).collect();
+        let listed = spellings.join(", ");
+        if entries.iter().any(|(_, value)| *value != entries[0].1) {
+            push_fix(
+                fixes,
+                "config.shadowing",
+                FixStatus::Skipped,
+                format!("{listed} have different values in db config; keep one"),
+            );
+            continue;
+        }
+
+        let kept = kept_spelling(&spellings, &canonical.replace('-', "_")).to_string();
+        for key in spellings.into_iter().filter(|key| *key != kept) {
+            push_fix(
+                fixes,
+                "config.shadowing",
+                if dry_run {
+                    FixStatus::Planned
+                } else {
+                    FixStatus::Applied
+                },
+                format!(
+                    "{} {key} from db config (keeping {kept})",
+                    if dry_run { "would remove" } else { "removed" }
+                ),
+            );
+            shadowed.push(key);
+        }
+    }
+
+    if !dry_run && !shadowed.is_empty() {
+        let tx = conn.transaction()?;
+        for key in &shadowed {
+            tx.execute("DELETE FROM config WHERE key = ?", [key])?;
+        }
+        tx.commit()?;
+    }
+    Ok(())
+}
+
+/// Ask on the terminal before rewriting config; declines without a TTY.
+fn confirm_config_rewrite(planned: &[FixResult]) -> Result<bool> {
+    if !io::stdin().is_terminal() {
+        return Ok(false);
+    }
+    eprintln!("The following config changes will be made:");
+    for fix in planned
+        .iter()
+        .filter(|fix| fix.status == FixStatus::Planned)
+    {
+        eprintln!("  {}", fix.action);
+    }
+    eprint!("Continue? [y/N] ");
+    io::stderr().flush()?;
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    Ok(input.trim().eq_ignore_ascii_case("y"))
+}
+
+/// Re-apply the schema and migrations to restore missing tables and columns.
+fn fix_schema(db_path: &Path, dry_run: bool, fixes: &mut Vec<FixResult>) -> Result<()> {
+    if dry_run {
+        push_fix(
+            fixes,
+            "schema",
+            FixStatus::Planned,
+            "would apply pending schema migrations".to_string(),
+        );
+        return Ok(());
+    }
+    let conn = Connection::open(db_path)?;
+    match apply_schema(&conn) {
+        Ok(()) => push_fix(
+            fixes,
+            "schema",
+            FixStatus::Applied,
+            "applied pending schema migrations".to_string(),
+        ),
+        // Tables too old or damaged for the migrations; report rather than abort.
+        Err(err) => push_fix(
+            fixes,
+            "schema",
+            FixStatus::Skipped,
+            format!("schema migrations failed: {err}"),
+        ),
+    }
+    Ok(())
+}
+
+/// Run the repairs that address failing `checks`.
+///
+/// Config rewrites are planned first and only applied with `yes` or after an
+/// interactive confirmation.
+fn apply_fixes(
+    targets: &ConfigTargets,
+    checks: &[CheckResult],
+    dry_run: bool,
+    yes: bool,
+) -> Result<Vec<FixResult>> {
+    let failing = |name: &str| {
+        checks
+            .iter()
+            .any(|check| check.name == name && check.status != CheckStatus::Ok)
     };
 
-    check_merge_artifacts(&beads_dir, &mut checks)?;
+    let mut fixes = Vec::new();
+    if failing("config.shadowing") {
+        let mut planned = Vec::new();
+        fix_config_shadowing(targets, true, &mut planned)?;
+        let pending = planned.iter().any(|fix| fix.status == FixStatus::Planned);
+        if dry_run || !pending {
+            fixes.extend(planned);
+        } else if yes || confirm_config_rewrite(&planned)? {
+            fix_config_shadowing(targets, false, &mut fixes)?;
+        } else {
+            fixes.extend(planned.into_iter().map(|fix| {
+                if fix.status == FixStatus::Planned {
+                    FixResult {
+                        status: FixStatus::Skipped,
+                        action: format!("{} (not confirmed; re-run with --yes)", fix.action),
+                        ..fix
+                    }
+                } else {
+                    fix
+                }
+            }));
+        }
+    }
+    if targets.db.exists() && (failing("schema.tables") || failing("schema.columns")) {
+        fix_schema(&targets.db, dry_run, &mut fixes)?;
+    }
+    Ok(fixes)
+}
+
+/// Run every check against a discovered workspace.
+fn run_checks(
+    beads_dir: &Path,
+    paths: &config::ConfigPaths,
+    cli: &config::CliOverrides,
+) -> Result<Vec<CheckResult>> {
+    let mut checks = Vec::new();
+    check_merge_artifacts(beads_dir, &mut checks)?;
+    check_config_shadowing(
+        vec![
+            ("project", config::load_project_config(beads_dir)),
+            ("user", config::load_user_config()),
+            ("db", load_db_config(&paths.db_path)),
+        ],
+        &mut checks,
+    );
+    // Parse failures are reported by the config.shadowing check.
+    if let Ok(layer) = config::load_config(beads_dir, None, cli) {
+        check_external_projects(beads_dir, &layer, &mut checks);
+    }
 
     let jsonl_path = if paths.jsonl_path.exists() {
         Some(paths.jsonl_path.clone())
     } else {
-        discover_jsonl(&beads_dir)
+        discover_jsonl(beads_dir)
     };
     let jsonl_count = if let Some(path) = jsonl_path.as_ref() {
         // SYNC SAFETY CHECKS (beads_rust-0v1.2.6)
         // Check JSONL path is within sync allowlist
-        check_sync_jsonl_path(path, &beads_dir, &mut checks);
+        check_sync_jsonl_path(path, beads_dir, &mut checks);
 
         // Check for merge conflict markers
         check_sync_conflict_markers(path, &mut checks);
@@ -867,13 +1401,14 @@ pub fn execute(cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
         None
     };
 
-    let db_path = paths.db_path;
+    let db_path = &paths.db_path;
     if db_path.exists() {
-        match Connection::open_with_flags(&db_path, OpenFlags::SQLITE_OPEN_READ_ONLY) {
+        match Connection::open_with_flags(db_path, OpenFlags::SQLITE_OPEN_READ_ONLY) {
             Ok(conn) => {
                 required_schema_checks(&conn, &mut checks)?;
                 check_integrity(&conn, &mut checks)?;
                 check_db_count(&conn, jsonl_count, &mut checks)?;
+                check_hash_prefix_len(&conn, &mut checks)?;
 
                 // SYNC SAFETY CHECK: metadata consistency (beads_rust-0v1.2.6)
                 check_sync_metadata(&conn, Some(&paths.jsonl_path), &mut checks);
@@ -898,9 +1433,76 @@ pub fn execute(cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
         );
     }
 
+    Ok(checks)
+}
+
+/// Execute the doctor command.
+///
+/// With `--fix`, repairs what can be fixed safely and re-runs the checks so
+/// the report reflects the repaired workspace.
+///
+/// # Errors
+///
+/// Returns an error if report serialization fails, if IO operations fail, or
+/// if a repair cannot be applied.
+pub fn execute(args: &DoctorArgs, cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+    let mut checks = Vec::new();
+    let Ok(beads_dir) = config::discover_beads_dir(None) else {
+        push_check(
+            &mut checks,
+            "beads_dir",
+            CheckStatus::Error,
+            Some("Missing .beads directory (run `br init`)".to_string()),
+            None,
+        );
+        let report = DoctorReport {
+            ok: !has_error(&checks),
+            checks,
+            fixes: Vec::new(),
+        };
+        print_report(&report, ctx)?;
+        std::process::exit(1);
+    };
+
+    let paths = match config::resolve_paths(&beads_dir, cli.db.as_ref()) {
+        Ok(paths) => paths,
+        Err(err) => {
+            push_check(
+                &mut checks,
+                "metadata",
+                CheckStatus::Error,
+                Some(format!("Failed to read metadata.json: {err}")),
+                None,
+            );
+            let report = DoctorReport {
+                ok: !has_error(&checks),
+                checks,
+                fixes: Vec::new(),
+            };
+            print_report(&report, ctx)?;
+            std::process::exit(1);
+        }
+    };
+
+    let mut checks = run_checks(&beads_dir, &paths, cli)?;
+    let fixes = if args.fix {
+        let targets = ConfigTargets {
+            project: beads_dir.join("config.yaml"),
+            user: paths.user_config_path(),
+            db: paths.db_path.clone(),
+        };
+        apply_fixes(&targets, &checks, args.dry_run, args.yes)?
+    } else {
+        Vec::new()
+    };
+    if fixes.iter().any(|fix| fix.status == FixStatus::Applied) {
+        checks = run_checks(&beads_dir, &paths, cli)?;
+    }
+
     let report = DoctorReport {
         ok: !has_error(&checks),
         checks,
+        fixes,
     };
     print_report(&report, ctx)?;
 
@@ -937,6 +1539,189 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_check_config_shadowing_warns_on_duplicate_spellings() {
+        let temp = tempfile::TempDir::new().unwrap();
+        fs::write(
+            temp.path().join("config.yaml"),
+            "issue_prefix: bd\nissue-prefix: br\n",
+        )
+        .unwrap();
+
+        let mut checks = Vec::new();
+        check_config_shadowing(
+            vec![("project", config::load_project_config(temp.path()))],
+            &mut checks,
+        );
+
+        let check = find_check(&checks, "config.shadowing").expect("shadowing check");
+        assert!(matches!(check.status, CheckStatus::Warn));
+    }
+
+    fn config_targets(dir: &Path) -> ConfigTargets {
+        ConfigTargets {
+            project: dir.join("config.yaml"),
+            user: None,
+            db: dir.join("beads.db"),
+        }
+    }
+
+    fn backups(dir: &Path) -> Vec<PathBuf> {
+        fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with("config.yaml.") && name.ends_with(".bak"))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_fix_config_shadowing_removes_shadowed_lines_in_place() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("config.yaml");
+        let original = "# project settings\nissue_prefix: bd\nDEFAULT_TYPE: bug\n\n\
+                        # defaults\nissue-prefix: bd\ndefault-type: task\n\
+                        hierarchy_max_depth:\n  - nested\n";
+        fs::write(&path, original).unwrap();
+
+        let mut fixes = Vec::new();
+        fix_config_shadowing(&config_targets(temp.path()), true, &mut fixes).unwrap();
+        let statuses: Vec<FixStatus> = fixes.iter().map(|fix| fix.status).collect();
+        assert_eq!(statuses, [FixStatus::Skipped, FixStatus::Planned]);
+        assert!(fixes[1].action.starts_with("would remove issue_prefix"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), original);
+        assert!(backups(temp.path()).is_empty());
+
+        let mut fixes = Vec::new();
+        fix_config_shadowing(&config_targets(temp.path()), false, &mut fixes).unwrap();
+        let statuses: Vec<FixStatus> = fixes.iter().map(|fix| fix.status).collect();
+        assert_eq!(statuses, [FixStatus::Skipped, FixStatus::Applied]);
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "# project settings\nDEFAULT_TYPE: bug\n\n\
+             # defaults\nissue-prefix: bd\ndefault-type: task\n\
+             hierarchy_max_depth:\n  - nested\n"
+        );
+
+        let backups = backups(temp.path());
+        assert_eq!(backups.len(), 1);
+        assert_eq!(fs::read_to_string(&backups[0]).unwrap(), original);
+    }
+
+    #[test]
+    fn test_backup_path_does_not_clobber_earlier_backups() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("config.yaml");
+        let first = backup_path(&path);
+        fs::write(&first, "").unwrap();
+        let second = backup_path(&path);
+        assert_ne!(first, second);
+        assert!(!second.exists());
+    }
+
+    #[test]
+    fn test_remove_top_level_keys_drops_block_values() {
+        let shadowed: HashSet<String> = ["labels".to_string()].into_iter().collect();
+        let contents = "labels:\n  - a\n\n  - b\n\n# keep\nissue-prefix: bd\n";
+        assert_eq!(
+            remove_top_level_keys(contents, &shadowed),
+            "\n# keep\nissue-prefix: bd\n"
+        );
+    }
+
+    #[test]
+    fn test_fix_config_shadowing_dedupes_db_config() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let targets = config_targets(temp.path());
+        let conn = Connection::open(&targets.db).unwrap();
+        apply_schema(&conn).unwrap();
+        conn.execute_batch(
+            "INSERT INTO config (key, value) VALUES \
+             ('issue_prefix', 'bd'), ('issue-prefix', 'bd'), \
+             ('default_type', 'bug'), ('DEFAULT-TYPE', 'task');",
+        )
+        .unwrap();
+
+        let layer = load_db_config(&targets.db).unwrap();
+        assert_eq!(config::shadowed_keys(&layer).len(), 2);
+
+        let mut fixes = apply_fixes(
+            &targets,
+            &[CheckResult {
+                name: "config.shadowing".to_string(),
+                status: CheckStatus::Warn,
+                message: None,
+                details: None,
+            }],
+            false,
+            true,
+        )
+        .unwrap();
+        fixes.sort_by_key(|fix| fix.status != FixStatus::Applied);
+        assert_eq!(fixes[0].status, FixStatus::Applied);
+        assert_eq!(
+            fixes[0].action,
+            "removed issue-prefix from db config (keeping issue_prefix)"
+        );
+        assert_eq!(fixes[1].status, FixStatus::Skipped);
+
+        let keys: Vec<String> = read_db_config(&conn)
+            .unwrap()
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect();
+        assert!(keys.contains(&"issue_prefix".to_string()));
+        assert!(!keys.contains(&"issue-prefix".to_string()));
+        assert!(keys.contains(&"DEFAULT-TYPE".to_string()));
+    }
+
+    #[test]
+    fn test_fix_schema_dry_run_reports_pending_migrations() {
+        let mut fixes = Vec::new();
+        fix_schema(Path::new("missing.db"), true, &mut fixes).unwrap();
+        assert_eq!(fixes[0].status, FixStatus::Planned);
+        assert_eq!(fixes[0].action, "would apply pending schema migrations");
+    }
+
+    #[test]
+    fn test_fix_schema_creates_missing_tables() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let db_path = temp.path().join("beads.db");
+        Connection::open(&db_path).unwrap();
+
+        let mut fixes = Vec::new();
+        fix_schema(&db_path, false, &mut fixes).unwrap();
+        assert_eq!(fixes[0].status, FixStatus::Applied);
+
+        let conn = Connection::open(&db_path).unwrap();
+        let mut checks = Vec::new();
+        required_schema_checks(&conn, &mut checks).unwrap();
+        let tables = find_check(&checks, "schema.tables").expect("tables check");
+        assert!(matches!(tables.status, CheckStatus::Ok));
+    }
+
+    #[test]
+    fn test_check_external_projects_warns_on_missing_db() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let beads_dir = temp.path().join(".beads");
+        fs::create_dir_all(&beads_dir).unwrap();
+        fs::write(
+            beads_dir.join("config.yaml"),
+            "external_projects:\n  other: ../missing\n",
+        )
+        .unwrap();
+
+        let mut checks = Vec::new();
+        let layer = config::load_project_config(&beads_dir).unwrap();
+        check_external_projects(&beads_dir, &layer, &mut checks);
+
+        let check = find_check(&checks, "config.external_projects").expect("external check");
+        assert!(matches!(check.status, CheckStatus::Warn));
+    }
+
     #[test]
     fn test_required_schema_checks_missing_tables() {
         let conn = Connection::open_in_memory().unwrap();
@@ -946,4 +1731,20 @@ mod tests {
         let tables = find_check(&checks, "schema.tables").expect("tables check");
         assert!(matches!(tables.status, CheckStatus::Error));
     }
+
+    #[test]
+    fn test_check_hash_prefix_len_reports_collisions() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE issues (id TEXT PRIMARY KEY);
+             INSERT INTO issues (id) VALUES ('bd-abc1'), ('bd-abd2'), ('bd-x9');",
+        )
+        .unwrap();
+        let mut checks = Vec::new();
+        check_hash_prefix_len(&conn, &mut checks).unwrap();
+
+        let check = find_check(&checks, "ids.min_hash_len").expect("hash len check");
+        assert!(matches!(check.status, CheckStatus::Ok));
+        assert_eq!(check.details.as_ref().unwrap()["min_hash_len"], 3);
+    }
 }
@@ -0,0 +1,141 @@
+//! GC command implementation.
+//!
+//! Permanently removes expired tombstones (and, on request, old audit
+//! events), rewrites the JSONL export so pruned issues are not re-imported,
+//! and runs `VACUUM` to shrink the database file.
+
+use crate::cli::GcArgs;
+use crate::config;
+use crate::error::Result;
+use crate::output::OutputContext;
+use crate::storage::GcPruneReport;
+use crate::sync::{ExportConfig, export_to_jsonl_with_policy, finalize_export};
+use chrono::{Duration, Utc};
+use serde::Serialize;
+
+/// Tombstone retention used when neither `--older-than` nor metadata sets one.
+const DEFAULT_TOMBSTONE_RETENTION_DAYS: u64 = 30;
+
+/// Retention values are clamped to this so the cutoff stays a valid timestamp.
+const MAX_RETENTION_DAYS: u64 = 365 * 1000;
+
+/// Result of a gc run for JSON output.
+#[derive(Debug, Serialize)]
+struct GcResult {
+    dry_run: bool,
+    tombstone_retention_days: u64,
+    event_retention_days: Option<u64>,
+    #[serde(flatten)]
+    pruned: GcPruneReport,
+    bytes_before: u64,
+    bytes_after: u64,
+    bytes_reclaimed: u64,
+}
+
+/// Execute the gc command.
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be opened, pruning or vacuuming
+/// fails, or the JSONL export cannot be rewritten.
+pub fn execute(args: &GcArgs, cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let config_layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let actor = config::resolve_actor(&config_layer);
+
+    let tombstone_days = args
+        .older_than
+        .or(storage_ctx.paths.metadata.deletions_retention_days)
+        .unwrap_or(DEFAULT_TOMBSTONE_RETENTION_DAYS);
+    let now = Utc::now();
+    let days_ago = |days: u64| {
+        now - Duration::days(i64::try_from(days.min(MAX_RETENTION_DAYS)).unwrap_or_default())
+    };
+
+    let (bytes_before, free_before) = storage_ctx.storage.database_size()?;
+    let pruned = storage_ctx.storage.gc_prune(
+        days_ago(tombstone_days),
+        args.events_older_than.map(days_ago),
+        args.dry_run,
+        &actor,
+    )?;
+
+    let bytes_after = if args.dry_run {
+        bytes_before.saturating_sub(free_before)
+    } else {
+        if !pruned.tombstones.is_empty() {
+            // The JSONL still holds the pruned tombstones; rewrite it so the
+            // next auto-import does not bring them back.
+            let paths = &storage_ctx.paths;
+            let export_config = ExportConfig {
+                force: true,
+                is_default_path: paths.jsonl_path == paths.beads_dir.join("issues.jsonl"),
+                beads_dir: Some(paths.beads_dir.clone()),
+                ..Default::default()
+            };
+            let (export_result, _) = export_to_jsonl_with_policy(
+                &storage_ctx.storage,
+                &paths.jsonl_path,
+                &export_config,
+            )?;
+            finalize_export(
+                &mut storage_ctx.storage,
+                &export_result,
+                Some(&export_result.issue_hashes),
+            )?;
+        }
+        if !storage_ctx.no_db {
+            storage_ctx.storage.vacuum()?;
+        }
+        storage_ctx.storage.database_size()?.0
+    };
+
+    let result = GcResult {
+        dry_run: args.dry_run,
+        tombstone_retention_days: tombstone_days,
+        event_retention_days: args.events_older_than,
+        pruned,
+        bytes_before,
+        bytes_after,
+        bytes_reclaimed: bytes_before.saturating_sub(bytes_after),
+    };
+
+    if ctx.is_json() {
+        ctx.json_pretty(&result);
+        return Ok(());
+    }
+    if ctx.is_quiet() {
+        return Ok(());
+    }
+
+    let verb = if result.dry_run {
+        "Would prune"
+    } else {
+        "Pruned"
+    };
+    println!(
+        "{verb} {} tombstone(s) deleted more than {tombstone_days} day(s) ago",
+        result.pruned.tombstones.len()
+    );
+    for id in &result.pruned.tombstones {
+        println!("  - {id}");
+    }
+    if let Some(days) = result.event_retention_days {
+        println!(
+            "{verb} {} audit event(s) older than {days} day(s)",
+            result.pruned.events
+        );
+    }
+    let reclaim = if result.dry_run {
+        "reclaimable by vacuum"
+    } else {
+        "reclaimed"
+    };
+    println!(
+        "Database: {} -> {} bytes ({} {reclaim})",
+        result.bytes_before, result.bytes_after, result.bytes_reclaimed
+    );
+
+    Ok(())
+}
@@ -2,6 +2,7 @@ use crate::cli::HistoryArgs;
 use crate::cli::HistoryCommands;
 use crate::config;
 use crate::error::{BeadsError, Result};
+use crate::format::{TimeFormat, format_timestamp_or};
 use crate::output::OutputContext;
 use crate::sync::history;
 use rich_rust::prelude::*;
@@ -28,12 +29,20 @@ pub fn execute(args: HistoryArgs, cli: &config::CliOverrides, ctx: &OutputContex
         Some(HistoryCommands::Prune { keep, older_than }) => {
             prune_backups(&history_dir, keep, older_than, ctx)
         }
-        Some(HistoryCommands::List) | None => list_backups(&history_dir, ctx),
+        Some(HistoryCommands::List) | None => {
+            let config_layer = config::load_config(&beads_dir, None, cli)?;
+            let time_format = config::time_format_from_layer(&config_layer)?;
+            list_backups(&history_dir, time_format, ctx)
+        }
     }
 }
 
 /// List available backups.
-fn list_backups(history_dir: &Path, ctx: &OutputContext) -> Result<()> {
+fn list_backups(
+    history_dir: &Path,
+    time_format: Option<TimeFormat>,
+    ctx: &OutputContext,
+) -> Result<()> {
     let backups = history::list_backups(history_dir, None)?;
 
     if ctx.is_json() {
@@ -101,7 +110,8 @@ fn list_backups(history_dir: &Path, ctx: &OutputContext) -> Result<()> {
                 .to_string_lossy()
                 .to_string();
             let size = format_size(entry.size);
-            let timestamp = entry.timestamp.format("%Y-%m-%d %H:%M:%S UTC").to_string();
+            let timestamp =
+                format_timestamp_or(&entry.timestamp, time_format, "%Y-%m-%d %H:%M:%S UTC");
             let row = Row::new(vec![
                 Cell::new(Text::styled(filename, theme.emphasis.clone())),
                 Cell::new(Text::new(size)),
@@ -119,7 +129,8 @@ fn list_backups(history_dir: &Path, ctx: &OutputContext) -> Result<()> {
         for entry in backups {
             let filename = entry.path.file_name().unwrap_or_default().to_string_lossy();
             let size = format_size(entry.size);
-            let timestamp = entry.timestamp.format("%Y-%m-%d %H:%M:%S UTC").to_string();
+            let timestamp =
+                format_timestamp_or(&entry.timestamp, time_format, "%Y-%m-%d %H:%M:%S UTC");
             println!("{filename:<30} {size:<10} {timestamp:<20}");
         }
     }
@@ -2,8 +2,33 @@ use crate::error::{BeadsError, Result};
 use crate::output::{OutputContext, OutputMode};
 use crate::storage::SqliteStorage;
 use rich_rust::prelude::*;
+use std::collections::BTreeSet;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+/// Builtin templates for `init --template`, as `(name, config.yaml contents)`.
+const BUILTIN_TEMPLATES: &[(&str, &str)] = &[
+    (
+        "strict",
+        r"# Beads Project Configuration (template: strict)
+# Reject IDs from other projects instead of hash-matching them
+strict-prefix: true
+default_priority: 2
+default_type: task
+",
+    ),
+    (
+        "agents",
+        r"# Beads Project Configuration (template: agents)
+# Stable, machine-friendly output for agent-driven workflows
+display:
+  color: false
+  time-format: rfc3339
+default_priority: 2
+default_type: task
+",
+    ),
+];
 
 /// Execute the init command.
 ///
@@ -15,10 +40,39 @@ pub fn execute(
     force: bool,
     root_dir: Option<&Path>,
     ctx: &OutputContext,
+) -> Result<()> {
+    execute_with_template(prefix, force, root_dir, None, ctx)
+}
+
+/// Execute the init command, seeding `config.yaml` from a named template.
+///
+/// Templates are read from `~/.config/beads/templates/<name>.yaml` when present,
+/// otherwise from the builtins. Their format is the YAML `br config import` accepts.
+///
+/// # Errors
+///
+/// Returns an error if the template is unknown or invalid, or if the directory
+/// or database cannot be created.
+#[allow(clippy::too_many_lines)]
+pub fn execute_with_template(
+    prefix: Option<String>,
+    force: bool,
+    root_dir: Option<&Path>,
+    template: Option<&str>,
+    ctx: &OutputContext,
 ) -> Result<()> {
     let base_dir = root_dir.unwrap_or_else(|| Path::new("."));
     let beads_dir = base_dir.join(".beads");
 
+    // Resolve the template before touching the filesystem so a typo leaves nothing behind
+    let template_config = template.map(load_template).transpose()?;
+    if template_config.is_some() && beads_dir.join("config.yaml").exists() && !force {
+        return Err(BeadsError::validation(
+            "template",
+            "config.yaml already exists (use --force to replace it with the template)",
+        ));
+    }
+
     let mut created_dir = false;
     if beads_dir.exists() {
         // Check if DB exists
@@ -58,7 +112,9 @@ pub fn execute(
     // Write config.yaml template
     let config_path = beads_dir.join("config.yaml");
     let config_existed = config_path.exists();
-    if !config_existed {
+    if let Some(contents) = &template_config {
+        fs::write(config_path, contents)?;
+    } else if !config_existed {
         let config = r"# Beads Project Configuration
 # issue_prefix: bd
 # default_priority: 2
@@ -108,12 +164,16 @@ last-touched
             gitignore_existed,
             jsonl_existed,
             prefix_set.as_deref(),
+            template,
         );
         render_init_rich(&beads_dir, &steps, prefix_set.as_deref(), ctx);
     } else {
         if let Some(p) = prefix_set.as_deref() {
             println!("Prefix set to: {p}");
         }
+        if let Some(name) = template {
+            println!("Applied template: {name}");
+        }
         println!("Initialized beads workspace in .beads/");
     }
 
@@ -142,6 +202,7 @@ fn build_init_steps(
     gitignore_existed: bool,
     jsonl_existed: bool,
     prefix: Option<&str>,
+    template: Option<&str>,
 ) -> Vec<InitStep> {
     let mut steps = Vec::new();
 
@@ -176,11 +237,14 @@ fn build_init_steps(
     });
 
     steps.push(InitStep {
-        label: "config.yaml".to_string(),
-        status: if config_existed {
-            InitStepStatus::Existing
-        } else {
-            InitStepStatus::Created
+        label: template.map_or_else(
+            || "config.yaml".to_string(),
+            |name| format!("config.yaml (template '{name}')"),
+        ),
+        status: match (config_existed, template.is_some()) {
+            (false, _) => InitStepStatus::Created,
+            (true, true) => InitStepStatus::Updated,
+            (true, false) => InitStepStatus::Existing,
         },
     });
 
@@ -212,6 +276,78 @@ fn build_init_steps(
     steps
 }
 
+/// Load and validate a named `init` template.
+fn load_template(name: &str) -> Result<String> {
+    let valid_name = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid_name {
+        return Err(BeadsError::validation(
+            "template",
+            format!("invalid template name '{name}'"),
+        ));
+    }
+
+    let user_path = user_templates_dir()
+        .map(|dir| dir.join(format!("{name}.yaml")))
+        .filter(|path| path.is_file());
+    let contents = if let Some(path) = user_path {
+        fs::read_to_string(path)?
+    } else {
+        let builtin = BUILTIN_TEMPLATES
+            .iter()
+            .find(|(builtin, _)| *builtin == name)
+            .map(|(_, contents)| (*contents).to_string());
+        builtin.ok_or_else(|| {
+            BeadsError::validation(
+                "template",
+                format!(
+                    "unknown template '{name}' (available: {})",
+                    available_templates().join(", ")
+                ),
+            )
+        })?
+    };
+
+    match serde_yaml::from_str::<serde_yaml::Value>(&contents)? {
+        serde_yaml::Value::Mapping(_) | serde_yaml::Value::Null => Ok(contents),
+        _ => Err(BeadsError::validation(
+            "template",
+            format!("template '{name}' must be a YAML mapping of config keys"),
+        )),
+    }
+}
+
+/// `~/.config/beads/templates`, if HOME is set.
+fn user_templates_dir() -> Option<PathBuf> {
+    std::env::var_os("HOME").map(|home| {
+        PathBuf::from(home)
+            .join(".config")
+            .join("beads")
+            .join("templates")
+    })
+}
+
+/// Names of all builtin and user templates, sorted.
+fn available_templates() -> Vec<String> {
+    let mut names: BTreeSet<String> = BUILTIN_TEMPLATES
+        .iter()
+        .map(|(name, _)| (*name).to_string())
+        .collect();
+    if let Some(entries) = user_templates_dir().and_then(|dir| fs::read_dir(dir).ok()) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().is_some_and(|ext| ext == "yaml") {
+                if let Some(stem) = path.file_stem() {
+                    names.insert(stem.to_string_lossy().to_string());
+                }
+            }
+        }
+    }
+    names.into_iter().collect()
+}
+
 fn render_init_rich(
     beads_dir: &Path,
     steps: &[InitStep],
@@ -410,4 +546,34 @@ mod tests {
         assert!(content.contains("*.lock"));
         info!("test_gitignore_excludes_db_files: assertions passed");
     }
+
+    #[test]
+    fn test_init_with_builtin_template() {
+        init_logging();
+        info!("test_init_with_builtin_template: starting");
+        let temp_dir = TempDir::new().unwrap();
+        let ctx = OutputContext::from_flags(false, false, true);
+        execute_with_template(None, false, Some(temp_dir.path()), Some("strict"), &ctx).unwrap();
+
+        let content = fs::read_to_string(temp_dir.path().join(".beads/config.yaml")).unwrap();
+        assert!(content.contains("strict-prefix: true"));
+        info!("test_init_with_builtin_template: assertions passed");
+    }
+
+    #[test]
+    fn test_init_unknown_template_lists_available() {
+        init_logging();
+        info!("test_init_unknown_template_lists_available: starting");
+        let temp_dir = TempDir::new().unwrap();
+        let ctx = OutputContext::from_flags(false, false, true);
+        let err = execute_with_template(None, false, Some(temp_dir.path()), Some("nope"), &ctx)
+            .unwrap_err()
+            .to_string();
+
+        assert!(err.contains("unknown template 'nope'"));
+        assert!(err.contains("strict"));
+        assert!(!temp_dir.path().join(".beads").exists());
+        assert!(load_template("../etc/passwd").is_err());
+        info!("test_init_unknown_template_lists_available: assertions passed");
+    }
 }
@@ -8,7 +8,7 @@ use crate::error::{BeadsError, Result};
 use crate::model::{Issue, IssueType, Status};
 use crate::output::OutputContext;
 use crate::storage::{ListFilters, SqliteStorage};
-use crate::util::id::{IdResolver, ResolverConfig};
+use crate::util::id::IdResolver;
 use rich_rust::prelude::*;
 use serde::Serialize;
 use std::collections::BTreeMap;
@@ -266,8 +266,7 @@ fn resolve_issues(
     cli: &config::CliOverrides,
 ) -> Result<Vec<Issue>> {
     let config_layer = config::load_config(beads_dir, Some(storage), cli)?;
-    let id_config = config::id_config_from_layer(&config_layer);
-    let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
+    let resolver = IdResolver::new(config::resolver_config_from_layer(&config_layer));
 
     let mut issues = Vec::new();
     for id_input in &args.ids {
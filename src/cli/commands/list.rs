@@ -3,17 +3,22 @@
 //! Primary discovery interface with classic filter semantics and
 //! `IssueWithCounts` JSON output. Supports text, JSON, and CSV formats.
 
-use crate::cli::{ListArgs, OutputFormat, resolve_output_format};
+use crate::cli::{CountBy, ListArgs, OutputFormat, resolve_output_format};
 use crate::config;
 use crate::error::{BeadsError, Result};
 use crate::format::csv;
 use crate::format::{IssueWithCounts, TextFormatOptions, format_issue_line_with, terminal_width};
 use crate::model::{IssueType, Priority, Status};
-use crate::output::{IssueTable, IssueTableColumns, OutputContext, OutputMode};
-use crate::storage::{ListFilters, SqliteStorage};
+use crate::output::{IssueTable, IssueTableColumns, OutputContext, OutputMode, stdout_is_terminal};
+use crate::storage::{
+    CompareOp, IssueCountField, ListFilters, ListPredicate, PredicateValue, SqliteStorage,
+    parse_list_predicate, parse_list_sort,
+};
+use crate::util::time::parse_duration_spec;
 use chrono::Utc;
-use std::collections::HashSet;
-use std::io::IsTerminal;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::io::Write;
 
 /// Execute the list command.
 ///
@@ -33,7 +38,8 @@ pub fn execute(
     let storage = &storage_ctx.storage;
     let config_layer = config::load_config(&beads_dir, Some(storage), cli)?;
     let use_color = config::should_use_color(&config_layer);
-    let max_width = if std::io::stdout().is_terminal() {
+    let time_format = config::time_format_from_layer(&config_layer)?;
+    let max_width = if stdout_is_terminal() {
         Some(terminal_width())
     } else {
         None
@@ -47,6 +53,10 @@ pub fn execute(
     // Build filter from args
     let mut filters = build_filters(args)?;
     let client_filters = needs_client_filters(args);
+
+    if let Some(by) = args.count_by {
+        return print_grouped_counts(storage, &mut filters, args, by, client_filters, outer_ctx);
+    }
     let limit = if client_filters {
         filters.limit.take()
     } else {
@@ -56,6 +66,18 @@ pub fn execute(
     // Validate sort key before query
     validate_sort_key(args.sort.as_deref())?;
 
+    // Determine output format: --json flag overrides --format
+    let output_format = resolve_output_format(args.format, outer_ctx.is_json(), false);
+    let quiet = cli.quiet.unwrap_or(false);
+    let ctx = OutputContext::from_output_format(output_format, quiet, !use_color);
+    if matches!(ctx.mode(), OutputMode::Quiet) {
+        return Ok(());
+    }
+
+    if args.json_stream {
+        return stream_issues_json(storage, &filters, args, client_filters, limit);
+    }
+
     // Query issues
     let issues = storage.list_issues(&filters)?;
     let mut issues = if client_filters {
@@ -70,14 +92,6 @@ pub fn execute(
         }
     }
 
-    // Determine output format: --json flag overrides --format
-    let output_format = resolve_output_format(args.format, outer_ctx.is_json(), false);
-    let quiet = cli.quiet.unwrap_or(false);
-    let ctx = OutputContext::from_output_format(output_format, quiet, !use_color);
-    if matches!(ctx.mode(), OutputMode::Quiet) {
-        return Ok(());
-    }
-
     // Output
     match output_format {
         OutputFormat::Json | OutputFormat::Toon => {
@@ -119,6 +133,11 @@ pub fn execute(
             let csv_output = csv::format_csv(&issues, &fields);
             print!("{csv_output}");
         }
+        OutputFormat::Tsv => {
+            let fields = csv::parse_fields(args.fields.as_deref());
+            let tsv_output = csv::format_tsv(&issues, &fields);
+            print!("{tsv_output}");
+        }
         OutputFormat::Text => {
             if matches!(ctx.mode(), OutputMode::Rich) {
                 let columns = if args.long {
@@ -146,6 +165,7 @@ pub fn execute(
                 let mut table = IssueTable::new(&issues, ctx.theme())
                     .columns(columns)
                     .title(format!("Issues ({})", issues.len()))
+                    .time_format(time_format)
                     .wrap(args.wrap);
                 if args.wrap {
                     table = table.width(Some(ctx.width()));
@@ -165,6 +185,208 @@ pub fn execute(
     Ok(())
 }
 
+/// Print `--count-by` tallies as a `{group: count}` map (JSON) or `group: count` lines.
+///
+/// Uses a single `GROUP BY` query; when client-side filters are active the
+/// matching issues are fetched first and grouped in memory instead.
+fn print_grouped_counts(
+    storage: &SqliteStorage,
+    filters: &mut ListFilters,
+    args: &ListArgs,
+    by: CountBy,
+    client_filters: bool,
+    ctx: &OutputContext,
+) -> Result<()> {
+    filters.limit = None;
+    let field = match by {
+        CountBy::Status => IssueCountField::Status,
+        CountBy::Priority => IssueCountField::Priority,
+        CountBy::Type => IssueCountField::Type,
+        CountBy::Assignee => IssueCountField::Assignee,
+        CountBy::Label => IssueCountField::Label,
+        CountBy::Overdue => IssueCountField::Overdue,
+        CountBy::Labeled => IssueCountField::Labeled,
+    };
+
+    let counts: BTreeMap<String, usize> = if client_filters {
+        let issues = apply_client_filters(storage, storage.list_issues(filters)?, args)?;
+        let now = Utc::now();
+        let needs_labels = matches!(field, IssueCountField::Label | IssueCountField::Labeled);
+        let mut labels_map = if needs_labels {
+            let ids: Vec<String> = issues.iter().map(|i| i.id.clone()).collect();
+            storage.get_labels_for_issues(&ids)?
+        } else {
+            HashMap::new()
+        };
+        let mut counts = BTreeMap::new();
+        for issue in &issues {
+            let keys = match field {
+                IssueCountField::Status => vec![issue.status.as_str().to_string()],
+                IssueCountField::Priority => vec![issue.priority.to_string()],
+                IssueCountField::Type => vec![issue.issue_type.as_str().to_string()],
+                IssueCountField::Assignee => vec![
+                    issue
+                        .assignee
+                        .clone()
+                        .filter(|a| !a.is_empty())
+                        .unwrap_or_else(|| "(unassigned)".to_string()),
+                ],
+                IssueCountField::Label => labels_map
+                    .remove(&issue.id)
+                    .filter(|labels| !labels.is_empty())
+                    .unwrap_or_else(|| vec!["(no labels)".to_string()]),
+                IssueCountField::Overdue => {
+                    vec![super::count::overdue_group(issue, now).to_string()]
+                }
+                IssueCountField::Labeled => {
+                    let labeled = labels_map.get(&issue.id).is_some_and(|l| !l.is_empty());
+                    vec![if labeled { "labeled" } else { "unlabeled" }.to_string()]
+                }
+            };
+            for key in keys {
+                *counts.entry(key).or_insert(0) += 1;
+            }
+        }
+        counts
+    } else {
+        storage
+            .count_issues_grouped(filters, field)?
+            .into_iter()
+            .collect()
+    };
+
+    if ctx.is_json() {
+        ctx.json_pretty(&counts);
+    } else if !ctx.is_quiet() {
+        for (group, count) in &counts {
+            println!("{group}: {count}");
+        }
+    }
+    Ok(())
+}
+
+/// Issues read from storage between flushes of `--json-stream` output.
+const JSON_STREAM_BATCH: usize = 500;
+
+/// Write matching issues as a JSON array while reading them from storage.
+///
+/// The output is identical in shape to `--json` (an array of `IssueWithCounts`),
+/// but only one batch of issues is held in memory and stdout is flushed after
+/// each batch.
+fn stream_issues_json(
+    storage: &SqliteStorage,
+    filters: &ListFilters,
+    args: &ListArgs,
+    client_filters: bool,
+    limit: Option<usize>,
+) -> Result<()> {
+    let stdout = std::io::stdout();
+    let mut writer = JsonArrayWriter::new(std::io::BufWriter::new(stdout.lock()))?;
+    let mut remaining = limit.filter(|&limit| limit > 0);
+    let mut batch = Vec::with_capacity(JSON_STREAM_BATCH);
+
+    storage.for_each_issue(filters, |issue| {
+        batch.push(issue);
+        if batch.len() < JSON_STREAM_BATCH {
+            return Ok(true);
+        }
+        let issues = std::mem::take(&mut batch);
+        write_issue_batch(
+            storage,
+            &mut writer,
+            issues,
+            args,
+            client_filters,
+            &mut remaining,
+        )
+    })?;
+    write_issue_batch(
+        storage,
+        &mut writer,
+        batch,
+        args,
+        client_filters,
+        &mut remaining,
+    )?;
+
+    writer.finish()
+}
+
+/// Filter one batch of issues, attach labels and counts, and write it out.
+///
+/// Returns `false` once `remaining` reaches zero.
+fn write_issue_batch<W: Write>(
+    storage: &SqliteStorage,
+    writer: &mut JsonArrayWriter<W>,
+    issues: Vec<crate::model::Issue>,
+    args: &ListArgs,
+    client_filters: bool,
+    remaining: &mut Option<usize>,
+) -> Result<bool> {
+    let mut issues = if client_filters {
+        apply_client_filters(storage, issues, args)?
+    } else {
+        issues
+    };
+    if let Some(remaining) = remaining.as_mut() {
+        issues.truncate(*remaining);
+        *remaining -= issues.len();
+    }
+
+    let issue_ids: Vec<String> = issues.iter().map(|i| i.id.clone()).collect();
+    let mut labels_map = storage.get_labels_for_issues(&issue_ids)?;
+    let dependency_counts = storage.count_dependencies_for_issues(&issue_ids)?;
+    let dependent_counts = storage.count_dependents_for_issues(&issue_ids)?;
+
+    for mut issue in issues {
+        if let Some(labels) = labels_map.remove(&issue.id) {
+            issue.labels = labels;
+        }
+        let dependency_count = *dependency_counts.get(&issue.id).unwrap_or(&0);
+        let dependent_count = *dependent_counts.get(&issue.id).unwrap_or(&0);
+        writer.write(&IssueWithCounts {
+            issue,
+            dependency_count,
+            dependent_count,
+        })?;
+    }
+    writer.flush()?;
+
+    Ok(remaining.is_none_or(|remaining| remaining > 0))
+}
+
+/// Incremental JSON array writer with one element per line.
+struct JsonArrayWriter<W: Write> {
+    out: W,
+    empty: bool,
+}
+
+impl<W: Write> JsonArrayWriter<W> {
+    fn new(mut out: W) -> Result<Self> {
+        out.write_all(b"[")?;
+        Ok(Self { out, empty: true })
+    }
+
+    fn write<T: Serialize>(&mut self, item: &T) -> Result<()> {
+        self.out
+            .write_all(if self.empty { b"\n" } else { b",\n" })?;
+        self.empty = false;
+        serde_json::to_writer(&mut self.out, item)?;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.out.flush()?;
+        Ok(())
+    }
+
+    /// Close the array and flush.
+    fn finish(mut self) -> Result<()> {
+        self.out.write_all(b"\n]\n")?;
+        self.flush()
+    }
+}
+
 /// Convert CLI args to storage filter.
 fn build_filters(args: &ListArgs) -> Result<ListFilters> {
     // Parse status strings to Status enums
@@ -203,7 +425,13 @@ fn build_filters(args: &ListArgs) -> Result<ListFilters> {
         )
     };
 
+    let mut predicates = parse_where(&args.where_)?;
+    // A status predicate decides on its own which statuses match
+    let status_predicate = predicates.iter().any(|p| p.column == "status");
+    hide_tombstones_unless_named(&mut predicates, args.all);
+
     let include_closed = args.all
+        || status_predicate
         || statuses
             .as_ref()
             .is_some_and(|parsed| parsed.iter().any(Status::is_terminal));
@@ -212,6 +440,7 @@ fn build_filters(args: &ListArgs) -> Result<ListFilters> {
     // They are only excluded when explicitly filtering by status that doesn't include deferred.
     let include_deferred = args.deferred
         || args.all
+        || status_predicate
         || statuses.is_none()
         || statuses
             .as_ref()
@@ -241,10 +470,51 @@ fn build_filters(args: &ListArgs) -> Result<ListFilters> {
             Some(args.label_any.clone())
         },
         updated_before: None,
-        updated_after: None,
+        updated_after: updated_since_cutoff(args.updated_since.as_deref())?,
+        predicates,
     })
 }
 
+/// Parse repeated `--where` predicates.
+pub(crate) fn parse_where(specs: &[String]) -> Result<Vec<ListPredicate>> {
+    specs
+        .iter()
+        .map(String::as_str)
+        .map(parse_list_predicate)
+        .collect()
+}
+
+/// Keep tombstones hidden behind a `--where status...` predicate unless `--all`
+/// is set or a predicate names them.
+pub(crate) fn hide_tombstones_unless_named(predicates: &mut Vec<ListPredicate>, all: bool) {
+    let status_predicate = predicates.iter().any(|p| p.column == "status");
+    let names_tombstone = predicates.iter().any(|p| {
+        p.column == "status"
+            && matches!(&p.value, PredicateValue::Text(value) if value == "tombstone")
+    });
+    if status_predicate && !all && !names_tombstone {
+        predicates.push(ListPredicate {
+            column: "status",
+            op: CompareOp::Ne,
+            value: PredicateValue::Text(Status::Tombstone.as_str().to_string()),
+        });
+    }
+}
+
+/// Resolve `--updated-since <DURATION>` into an `updated_at` lower bound.
+pub(crate) fn updated_since_cutoff(
+    updated_since: Option<&str>,
+) -> Result<Option<chrono::DateTime<Utc>>> {
+    updated_since
+        .map(|value| {
+            let window = parse_duration_spec(value, "updated-since")?;
+            Utc::now().checked_sub_signed(window).ok_or_else(|| {
+                BeadsError::validation("updated-since", format!("'{value}' reaches too far back"))
+            })
+        })
+        .transpose()
+}
+
 fn needs_client_filters(args: &ListArgs) -> bool {
     !args.id.is_empty()
         || !args.label.is_empty()
@@ -370,13 +640,8 @@ fn validate_sort_key(sort: Option<&str>) -> Result<()> {
         return Ok(());
     };
 
-    match sort_key {
-        "priority" | "created_at" | "updated_at" | "title" | "created" | "updated" => Ok(()),
-        _ => Err(BeadsError::Validation {
-            field: "sort".to_string(),
-            reason: format!("invalid sort field '{sort_key}'"),
-        }),
-    }
+    // Multi-key specs (`priority:asc,created:desc`) share the storage parser
+    parse_list_sort(sort_key).map(|_| ())
 }
 
 #[cfg(test)]
@@ -389,6 +654,45 @@ mod tests {
         crate::logging::init_test_logging();
     }
 
+    #[test]
+    fn test_json_array_writer_is_valid_json() {
+        init_logging();
+        info!("test_json_array_writer_is_valid_json: starting");
+        let mut buf = Vec::new();
+        let mut writer = JsonArrayWriter::new(&mut buf).unwrap();
+        writer.write(&serde_json::json!({"id": "bd-1"})).unwrap();
+        writer.flush().unwrap();
+        writer.write(&serde_json::json!({"id": "bd-2"})).unwrap();
+        writer.finish().unwrap();
+        let parsed: Vec<serde_json::Value> = serde_json::from_slice(&buf).unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[1]["id"], "bd-2");
+
+        let mut empty = Vec::new();
+        JsonArrayWriter::new(&mut empty).unwrap().finish().unwrap();
+        let parsed: Vec<serde_json::Value> = serde_json::from_slice(&empty).unwrap();
+        assert!(parsed.is_empty());
+        info!("test_json_array_writer_is_valid_json: assertions passed");
+    }
+
+    #[test]
+    fn test_updated_since_cutoff_rejects_out_of_range_windows() {
+        init_logging();
+        info!("test_updated_since_cutoff_rejects_out_of_range_windows: starting");
+        let cutoff = updated_since_cutoff(Some("7d")).unwrap().expect("cutoff");
+        assert!(cutoff < Utc::now());
+        assert!(updated_since_cutoff(None).unwrap().is_none());
+        assert!(matches!(
+            updated_since_cutoff(Some("99999999999999w")),
+            Err(BeadsError::Validation { .. })
+        ));
+        assert!(matches!(
+            updated_since_cutoff(Some("200000000d")),
+            Err(BeadsError::Validation { .. })
+        ));
+        info!("test_updated_since_cutoff_rejects_out_of_range_windows: assertions passed");
+    }
+
     #[test]
     fn test_build_filters_includes_closed_for_terminal_status() {
         init_logging();
@@ -410,6 +714,35 @@ mod tests {
         info!("test_build_filters_includes_closed_for_terminal_status: assertions passed");
     }
 
+    #[test]
+    fn test_build_filters_status_predicate_keeps_tombstones_hidden() {
+        init_logging();
+        info!("test_build_filters_status_predicate_keeps_tombstones_hidden: starting");
+        let hides_tombstones = |filters: &ListFilters| {
+            filters.predicates.iter().any(|p| {
+                p.column == "status"
+                    && p.op == CompareOp::Ne
+                    && matches!(&p.value, PredicateValue::Text(v) if v == "tombstone")
+            })
+        };
+
+        let args = cli::ListArgs {
+            where_: vec!["status!=open".to_string()],
+            ..Default::default()
+        };
+        let filters = build_filters(&args).expect("build filters");
+        assert!(filters.include_closed);
+        assert!(hides_tombstones(&filters));
+
+        let args = cli::ListArgs {
+            where_: vec!["status=tombstone".to_string()],
+            ..Default::default()
+        };
+        let filters = build_filters(&args).expect("build filters");
+        assert!(!hides_tombstones(&filters));
+        info!("test_build_filters_status_predicate_keeps_tombstones_hidden: assertions passed");
+    }
+
     #[test]
     fn test_build_filters_parses_priorities() {
         init_logging();
@@ -426,6 +759,24 @@ mod tests {
         info!("test_build_filters_parses_priorities: assertions passed");
     }
 
+    #[test]
+    fn test_validate_sort_key_accepts_multi_key_spec() {
+        init_logging();
+        info!("test_validate_sort_key_accepts_multi_key_spec: starting");
+        validate_sort_key(Some("priority")).expect("legacy field");
+        validate_sort_key(Some("created_at")).expect("legacy alias");
+        validate_sort_key(Some("priority:asc,created:desc")).expect("multi key");
+        validate_sort_key(Some("id:desc")).expect("id with direction");
+
+        let err = validate_sort_key(Some("owner:asc")).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("priority, created, updated, id, title")
+        );
+        assert!(validate_sort_key(Some("priority:sideways")).is_err());
+        info!("test_validate_sort_key_accepts_multi_key_spec: assertions passed");
+    }
+
     #[test]
     fn test_needs_client_filters_detects_fields() {
         init_logging();
@@ -13,6 +13,7 @@ pub mod delete;
 pub mod dep;
 pub mod doctor;
 pub mod epic;
+pub mod gc;
 pub mod graph;
 pub mod history;
 pub mod info;
@@ -24,6 +25,7 @@ pub mod orphans;
 pub mod q;
 pub mod query;
 pub mod ready;
+pub mod reassign;
 pub mod reopen;
 pub mod schema;
 pub mod search;
@@ -60,6 +60,8 @@ pub struct SavedFilters {
     pub desc_contains: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub notes_contains: Option<String>,
+    #[serde(default, rename = "where", skip_serializing_if = "Vec::is_empty")]
+    pub where_: Vec<String>,
     #[serde(default, skip_serializing_if = "is_false")]
     pub all: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -72,6 +74,8 @@ pub struct SavedFilters {
     pub deferred: bool,
     #[serde(default, skip_serializing_if = "is_false")]
     pub overdue: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_since: Option<String>,
 }
 
 /// Helper for serde `skip_serializing_if` (requires reference signature).
@@ -96,12 +100,14 @@ impl From<&ListArgs> for SavedFilters {
             title_contains: args.title_contains.clone(),
             desc_contains: args.desc_contains.clone(),
             notes_contains: args.notes_contains.clone(),
+            where_: args.where_.clone(),
             all: args.all,
             limit: args.limit,
             sort: args.sort.clone(),
             reverse: args.reverse,
             deferred: args.deferred,
             overdue: args.overdue,
+            updated_since: args.updated_since.clone(),
         }
     }
 }
@@ -124,12 +130,14 @@ impl SavedFilters {
             title_contains: self.title_contains.clone(),
             desc_contains: self.desc_contains.clone(),
             notes_contains: self.notes_contains.clone(),
+            where_: self.where_.clone(),
             all: self.all,
             limit: self.limit,
             sort: self.sort.clone(),
             reverse: self.reverse,
             deferred: self.deferred,
             overdue: self.overdue,
+            updated_since: self.updated_since.clone(),
             // Output-related fields use defaults
             long: false,
             pretty: false,
@@ -137,6 +145,8 @@ impl SavedFilters {
             format: None,
             stats: false,
             fields: None,
+            json_stream: false,
+            count_by: None,
         }
     }
 
@@ -176,6 +186,11 @@ impl SavedFilters {
             } else {
                 cli.priority.clone()
             },
+            where_: if cli.where_.is_empty() {
+                base.where_
+            } else {
+                cli.where_.clone()
+            },
             // Option fields: CLI overrides if Some
             assignee: cli.assignee.clone().or(base.assignee),
             priority_min: cli.priority_min.or(base.priority_min),
@@ -185,6 +200,7 @@ impl SavedFilters {
             notes_contains: cli.notes_contains.clone().or(base.notes_contains),
             limit: cli.limit.or(base.limit),
             sort: cli.sort.clone().or(base.sort),
+            updated_since: cli.updated_since.clone().or(base.updated_since),
             // Bool fields: CLI true overrides saved
             unassigned: cli.unassigned || base.unassigned,
             all: cli.all || base.all,
@@ -198,6 +214,8 @@ impl SavedFilters {
             format: cli.format,
             stats: cli.stats,
             fields: cli.fields.clone(),
+            json_stream: cli.json_stream,
+            count_by: cli.count_by,
         }
     }
 }
@@ -1,16 +1,28 @@
 //! Ready command implementation.
 //!
 //! Shows issues ready to work on: unblocked, not deferred, not pinned, not ephemeral.
+//! Each exclusion except blocking can be relaxed with an `--include-*` flag.
 
-use crate::cli::{OutputFormat, ReadyArgs, SortPolicy, resolve_output_format_basic};
+use crate::cli::{
+    OutputFormat, ReadyArgs, ReadyOutputFormat, SortPolicy, resolve_output_format_basic,
+};
 use crate::config;
-use crate::error::Result;
-use crate::format::{ReadyIssue, format_priority_badge, terminal_width, truncate_title};
-use crate::model::{IssueType, Priority};
-use crate::output::{IssueTable, IssueTableColumns, OutputContext, OutputMode};
-use crate::storage::{ReadyFilters, ReadySortPolicy};
-use std::io::IsTerminal;
+use crate::error::{BeadsError, Result};
+use crate::format::{
+    IssueWithCounts, ReadyIssue, escape_markdown, format_priority, format_priority_badge,
+    terminal_width, truncate_title,
+};
+use crate::model::{IssueType, Priority, Status};
+use crate::output::{IssueTable, IssueTableColumns, OutputContext, OutputMode, stdout_is_terminal};
+use crate::storage::{
+    ClaimOutcome, ExternalCachePolicy, ListFilters, ReadyFilters, ReadySortPolicy,
+};
+use crate::util::score::{ScoreExpr, ScoreInputs};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::io::{IsTerminal, Write};
 use std::str::FromStr;
+use std::time::Duration;
 use tracing::{debug, info, trace};
 use unicode_width::UnicodeWidthStr;
 
@@ -19,6 +31,7 @@ use unicode_width::UnicodeWidthStr;
 /// # Errors
 ///
 /// Returns an error if the database cannot be opened or the query fails.
+#[allow(clippy::too_many_lines)]
 pub fn execute(
     args: &ReadyArgs,
     _json: bool,
@@ -27,29 +40,54 @@ pub fn execute(
 ) -> Result<()> {
     // Open storage
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
-    let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
     let storage = &storage_ctx.storage;
 
     let config_layer = config::load_config(&beads_dir, Some(storage), cli)?;
-    let external_db_paths = config::external_project_db_paths(&config_layer, &beads_dir);
+    let actor = config::resolve_actor(&config_layer);
+    let external_projects = config::external_project_db_paths(&config_layer, &beads_dir);
+    external_projects.report_unresolved();
+    let external_db_paths = external_projects.resolved;
+    let external_cache = ExternalCachePolicy {
+        ttl: config::external_cache_ttl_from_layer(&config_layer),
+        refresh: args.refresh_external,
+    };
     let use_color = config::should_use_color(&config_layer);
-    let max_width = if std::io::stdout().is_terminal() {
+    let max_width = if stdout_is_terminal() {
         Some(terminal_width())
     } else {
         None
     };
-    let output_format = resolve_output_format_basic(args.format, outer_ctx.is_json(), args.robot);
+    // An explicit --format wins over --json/--robot
+    let explicit_format = args.format.is_some();
+    let output_format = resolve_output_format_basic(
+        args.format.map(ReadyOutputFormat::basic),
+        outer_ctx.is_json() && !explicit_format,
+        args.robot && !explicit_format,
+    );
+    let markdown = matches!(args.format, Some(ReadyOutputFormat::Markdown))
+        && matches!(output_format, OutputFormat::Text);
     let quiet = cli.quiet.unwrap_or(false);
     let ctx = OutputContext::from_output_format(output_format, quiet, !use_color);
 
+    let (assignee, assignees) = resolve_assignees(args)?;
     let filters = ReadyFilters {
-        assignee: args.assignee.clone(),
+        assignee,
+        assignees,
         unassigned: args.unassigned,
+        assignee_or_unassigned: if args.only_mine_or_free {
+            Some(actor.clone())
+        } else {
+            args.assignee_or_unassigned.clone()
+        },
         labels_and: args.label.clone(),
         labels_or: args.label_any.clone(),
+        labels_not: args.exclude_label.clone(),
         types: parse_types(&args.type_)?,
         priorities: parse_priorities(&args.priority)?,
         include_deferred: args.include_deferred,
+        include_pinned: args.include_pinned,
+        include_ephemeral: args.include_ephemeral,
         // Fetch all candidates to allow post-filtering of external blockers
         limit: None,
     };
@@ -59,23 +97,155 @@ pub fn execute(
         SortPolicy::Priority => ReadySortPolicy::Priority,
         SortPolicy::Oldest => ReadySortPolicy::Oldest,
     };
+    let weighted_sort = args
+        .weighted_sort
+        .as_deref()
+        .map(ScoreExpr::parse)
+        .transpose()?;
+
+    if args.take {
+        let taken = take_ready(
+            &mut storage_ctx.storage,
+            &filters,
+            sort_policy,
+            &external_db_paths,
+            external_cache,
+            weighted_sort.as_ref(),
+            args.resume,
+            &actor,
+        )?;
+        if let Some((_, issue)) = &taken {
+            crate::util::set_last_touched_id(&beads_dir, &issue.id);
+        }
+        storage_ctx.flush_no_db_if_dirty()?;
+        print_taken(taken.as_ref(), &ctx);
+        return Ok(());
+    }
+
+    if args.jsonl {
+        if !matches!(ctx.mode(), OutputMode::Quiet) {
+            emit_ready_jsonl(
+                storage,
+                &filters,
+                sort_policy,
+                &external_db_paths,
+                external_cache,
+                args,
+            )?;
+        }
+        return Ok(());
+    }
+
+    // Watch mode only redraws for interactive text output; otherwise emit one snapshot.
+    let watch = args.watch
+        && matches!(output_format, OutputFormat::Text)
+        && !matches!(ctx.mode(), OutputMode::Quiet)
+        && std::io::stdout().is_terminal();
+    if args.watch && !watch {
+        debug!("Ignoring --watch for non-interactive output; emitting one snapshot");
+    }
+    let interval = Duration::from_secs(args.interval.max(1));
 
+    loop {
+        if watch {
+            print!("\x1b[2J\x1b[H");
+        }
+        render_ready(
+            storage,
+            &filters,
+            sort_policy,
+            &external_db_paths,
+            external_cache,
+            weighted_sort.as_ref(),
+            args,
+            &ctx,
+            output_format,
+            markdown,
+            use_color,
+            max_width,
+        )?;
+        if !watch {
+            return Ok(());
+        }
+        let _ = std::io::stdout().flush();
+        std::thread::sleep(interval);
+    }
+}
+
+/// Resolve `--assignee` and `--assignee-group` into the single and set filters.
+///
+/// A roster replaces the single-assignee filter with the union of both.
+fn resolve_assignees(args: &ReadyArgs) -> Result<(Option<String>, Vec<String>)> {
+    let Some(path) = &args.assignee_group else {
+        return Ok((args.assignee.clone(), Vec::new()));
+    };
+    let mut roster = parse_assignee_group(&std::fs::read_to_string(path)?)?;
+    roster.extend(args.assignee.clone());
+    roster.sort();
+    roster.dedup();
+    Ok((None, roster))
+}
+
+/// Parse an `--assignee-group` roster: one identity per line, `#` starts a comment.
+fn parse_assignee_group(content: &str) -> Result<Vec<String>> {
+    let roster: Vec<String> = content
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default().trim())
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect();
+    if roster.is_empty() {
+        return Err(BeadsError::validation(
+            "assignee-group",
+            "roster file lists no assignees",
+        ));
+    }
+    Ok(roster)
+}
+
+/// Query and print one snapshot of the ready queue.
+#[allow(clippy::too_many_arguments, clippy::too_many_lines)]
+fn render_ready(
+    storage: &crate::storage::SqliteStorage,
+    filters: &ReadyFilters,
+    sort_policy: ReadySortPolicy,
+    external_db_paths: &std::collections::HashMap<String, std::path::PathBuf>,
+    external_cache: ExternalCachePolicy,
+    weighted_sort: Option<&ScoreExpr>,
+    args: &ReadyArgs,
+    ctx: &OutputContext,
+    output_format: OutputFormat,
+    markdown: bool,
+    use_color: bool,
+    max_width: Option<usize>,
+) -> Result<()> {
     info!("Fetching ready issues");
     debug!(filters = ?filters, sort = ?sort_policy, "Applied ready filters");
 
     // Get ready issues from storage (blocked cache only)
-    let mut ready_issues = storage.get_ready_issues(&filters, sort_policy)?;
+    let mut ready_issues = storage.get_ready_issues(filters, sort_policy)?;
 
-    let external_statuses =
-        storage.resolve_external_dependency_statuses(&external_db_paths, true)?;
+    let external_statuses = storage.resolve_external_dependency_statuses_cached(
+        external_db_paths,
+        true,
+        external_cache,
+    )?;
     let external_blockers = storage.external_blockers(&external_statuses)?;
     if !external_blockers.is_empty() {
         ready_issues.retain(|issue| !external_blockers.contains_key(&issue.id));
     }
+    if let Some(expr) = weighted_sort {
+        apply_weighted_sort(storage, &mut ready_issues, expr)?;
+    }
+
+    // Per-assignee cap applies after external filtering, before the overall limit
+    let hidden_for_assignee = args
+        .capacity_per_assignee
+        .map(|cap| cap_per_assignee(&mut ready_issues, cap));
 
     // Apply limit after external filtering
-    if args.limit > 0 && ready_issues.len() > args.limit {
-        ready_issues.truncate(args.limit);
+    if let Some(limit) = args.limit_cap() {
+        ready_issues.truncate(limit);
     }
 
     info!(count = ready_issues.len(), "Found ready issues");
@@ -83,20 +253,45 @@ pub fn execute(
         trace!(id = %issue.id, priority = issue.priority.0, "Ready issue");
     }
 
+    // Build the structured payload once for stdout and/or --output-json-to
+    let structured = matches!(output_format, OutputFormat::Json | OutputFormat::Toon);
+    let payload = if structured || args.output_json_to.is_some() {
+        Some(ready_payload(
+            storage,
+            &ready_issues,
+            args.include_counts,
+            hidden_for_assignee.as_ref(),
+        )?)
+    } else {
+        None
+    };
+    if let (Some(path), Some(payload)) = (args.output_json_to.as_deref(), &payload) {
+        write_json_file(path, payload);
+    }
+
     // Output
     if matches!(ctx.mode(), OutputMode::Quiet) {
         return Ok(());
     }
     match output_format {
-        OutputFormat::Json => {
-            let ready_output: Vec<ReadyIssue> = ready_issues.iter().map(ReadyIssue::from).collect();
-            ctx.json_pretty(&ready_output);
+        OutputFormat::Json | OutputFormat::Toon => {
+            if let Some(payload) = &payload {
+                if matches!(output_format, OutputFormat::Json) {
+                    ctx.json_pretty(payload);
+                } else {
+                    ctx.toon_with_stats(payload, args.stats);
+                }
+            }
         }
-        OutputFormat::Toon => {
-            let ready_output: Vec<ReadyIssue> = ready_issues.iter().map(ReadyIssue::from).collect();
-            ctx.toon_with_stats(&ready_output, args.stats);
+        OutputFormat::Text | OutputFormat::Csv | OutputFormat::Tsv if markdown => {
+            for issue in &ready_issues {
+                println!(
+                    "{}",
+                    format_ready_markdown_line(issue, args.links.as_deref())
+                );
+            }
         }
-        OutputFormat::Text | OutputFormat::Csv => {
+        OutputFormat::Text | OutputFormat::Csv | OutputFormat::Tsv => {
             if ready_issues.is_empty() {
                 // Match bd empty output format
                 println!("✨ No open issues");
@@ -137,6 +332,316 @@ pub fn execute(
         }
     }
 
+    let hidden = hidden_for_assignee.unwrap_or_default();
+    if matches!(output_format, OutputFormat::Text) && !hidden.is_empty() {
+        println!("\n{}", format_hidden_summary(&hidden));
+    }
+
+    Ok(())
+}
+
+/// Reorder issues by descending `--weighted-sort` score.
+///
+/// The sort is stable, so equal scores keep the order from the sort policy.
+fn apply_weighted_sort(
+    storage: &crate::storage::SqliteStorage,
+    issues: &mut Vec<crate::model::Issue>,
+    expr: &ScoreExpr,
+) -> Result<()> {
+    let ids: Vec<String> = issues.iter().map(|issue| issue.id.clone()).collect();
+    let dependents = storage.count_dependents_for_issues(&ids)?;
+    let now = chrono::Utc::now();
+    let mut scored: Vec<(f64, crate::model::Issue)> = issues
+        .drain(..)
+        .map(|issue| {
+            let inputs = ScoreInputs {
+                priority: f64::from(issue.priority.0),
+                urgency: f64::from(4 - issue.priority.0.clamp(0, 4)),
+                age_days: (now - issue.created_at).num_days() as f64,
+                dependent_count: dependents.get(&issue.id).copied().unwrap_or(0) as f64,
+            };
+            (expr.eval(&inputs), issue)
+        })
+        .collect();
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+    issues.extend(scored.into_iter().map(|(_, issue)| issue));
+    Ok(())
+}
+
+/// Ready issues in the bd-parity shape, or with counts for `--include-counts`.
+#[derive(Serialize)]
+#[serde(untagged)]
+enum ReadyIssues {
+    Parity(Vec<ReadyIssue>),
+    WithCounts(Vec<IssueWithCounts>),
+}
+
+/// JSON/TOON payload for `ready`: the issue array, wrapped with the
+/// per-assignee summary when `--capacity-per-assignee` is set.
+#[derive(Serialize)]
+#[serde(untagged)]
+enum ReadyPayload {
+    Issues(ReadyIssues),
+    Capped(CappedReadyOutput),
+}
+
+fn ready_payload(
+    storage: &crate::storage::SqliteStorage,
+    issues: &[crate::model::Issue],
+    include_counts: bool,
+    hidden_for_assignee: Option<&BTreeMap<String, usize>>,
+) -> Result<ReadyPayload> {
+    let issues = if include_counts {
+        ReadyIssues::WithCounts(ready_issues_with_counts(storage, issues)?)
+    } else {
+        ReadyIssues::Parity(issues.iter().map(ReadyIssue::from).collect())
+    };
+    Ok(match hidden_for_assignee {
+        Some(hidden) => ReadyPayload::Capped(CappedReadyOutput {
+            issues,
+            hidden_for_assignee: hidden.clone(),
+        }),
+        None => ReadyPayload::Issues(issues),
+    })
+}
+
+/// Attach labels and dependency/dependent counts for `--include-counts`,
+/// matching the `IssueWithCounts` shape used by `list --json`.
+fn ready_issues_with_counts(
+    storage: &crate::storage::SqliteStorage,
+    issues: &[crate::model::Issue],
+) -> Result<Vec<IssueWithCounts>> {
+    let ids: Vec<String> = issues.iter().map(|issue| issue.id.clone()).collect();
+    let mut labels = storage.get_labels_for_issues(&ids)?;
+    let dependency_counts = storage.count_dependencies_for_issues(&ids)?;
+    let dependent_counts = storage.count_dependents_for_issues(&ids)?;
+    Ok(issues
+        .iter()
+        .cloned()
+        .map(|mut issue| {
+            if let Some(issue_labels) = labels.remove(&issue.id) {
+                issue.labels = issue_labels;
+            }
+            IssueWithCounts {
+                dependency_count: dependency_counts.get(&issue.id).copied().unwrap_or(0),
+                dependent_count: dependent_counts.get(&issue.id).copied().unwrap_or(0),
+                issue,
+            }
+        })
+        .collect())
+}
+
+/// Write the `--output-json-to` copy, creating parent directories.
+///
+/// Failures only warn on stderr so the terminal listing still succeeds.
+fn write_json_file(path: &std::path::Path, payload: &ReadyPayload) {
+    let result = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .map_or(Ok(()), std::fs::create_dir_all)
+        .and_then(|()| {
+            let mut json = serde_json::to_string_pretty(payload)?;
+            json.push('\n');
+            std::fs::write(path, json)
+        });
+    if let Err(err) = result {
+        eprintln!("Warning: could not write {}: {err}", path.display());
+    }
+}
+
+/// Label used for the unassigned pool in `--capacity-per-assignee` summaries.
+const UNASSIGNED_KEY: &str = "(unassigned)";
+
+/// JSON/TOON output for `ready --capacity-per-assignee`.
+#[derive(Serialize)]
+struct CappedReadyOutput {
+    issues: ReadyIssues,
+    /// Issues dropped per assignee; only assignees over capacity appear.
+    hidden_for_assignee: BTreeMap<String, usize>,
+}
+
+/// Keep at most `cap` issues per assignee (unassigned issues, including blank
+/// assignees, share one pool), preserving order. Returns how many were dropped for each assignee.
+fn cap_per_assignee(issues: &mut Vec<crate::model::Issue>, cap: usize) -> BTreeMap<String, usize> {
+    let mut shown: HashMap<String, usize> = HashMap::new();
+    let mut hidden = BTreeMap::new();
+    issues.retain(|issue| {
+        let key = issue
+            .assignee
+            .as_deref()
+            .map(str::trim)
+            .filter(|assignee| !assignee.is_empty())
+            .unwrap_or(UNASSIGNED_KEY)
+            .to_string();
+        let count = shown.entry(key.clone()).or_default();
+        if *count < cap {
+            *count += 1;
+            true
+        } else {
+            *hidden.entry(key).or_default() += 1;
+            false
+        }
+    });
+    hidden
+}
+
+fn format_hidden_summary(hidden: &BTreeMap<String, usize>) -> String {
+    let parts: Vec<String> = hidden
+        .iter()
+        .map(|(assignee, count)| format!("{assignee} +{count}"))
+        .collect();
+    format!("Hidden by capacity limit: {}", parts.join(", "))
+}
+
+/// JSON output for `ready --take`.
+#[derive(Serialize)]
+struct TakeOutput {
+    /// `claimed` for newly taken work, `resumed` for work already in progress.
+    action: &'static str,
+    issue: ReadyIssue,
+}
+
+/// Pick the top ready issue and claim it for `actor`.
+///
+/// With `resume`, an issue the actor already has in progress is returned first.
+/// Candidates are tried in sort order so losing a race to another claimer
+/// falls through to the next issue. Returns `None` when nothing is available.
+#[allow(clippy::too_many_arguments)]
+fn take_ready(
+    storage: &mut crate::storage::SqliteStorage,
+    filters: &ReadyFilters,
+    sort_policy: ReadySortPolicy,
+    external_db_paths: &std::collections::HashMap<String, std::path::PathBuf>,
+    external_cache: ExternalCachePolicy,
+    weighted_sort: Option<&ScoreExpr>,
+    resume: bool,
+    actor: &str,
+) -> Result<Option<(&'static str, crate::model::Issue)>> {
+    if resume {
+        let mine = ListFilters {
+            statuses: Some(vec![Status::InProgress]),
+            assignee: Some(actor.to_string()),
+            limit: Some(1),
+            ..ListFilters::default()
+        };
+        if let Some(issue) = storage.list_issues(&mine)?.into_iter().next() {
+            return Ok(Some(("resumed", issue)));
+        }
+    }
+
+    let mut candidates = storage.get_ready_issues(filters, sort_policy)?;
+    let external_statuses = storage.resolve_external_dependency_statuses_cached(
+        external_db_paths,
+        true,
+        external_cache,
+    )?;
+    let external_blockers = storage.external_blockers(&external_statuses)?;
+    candidates.retain(|issue| !external_blockers.contains_key(&issue.id));
+    if let Some(expr) = weighted_sort {
+        apply_weighted_sort(storage, &mut candidates, expr)?;
+    }
+
+    for candidate in candidates {
+        let action = match storage.claim_issue(&candidate.id, actor)? {
+            ClaimOutcome::Claimed => "claimed",
+            ClaimOutcome::AlreadyHeld => "resumed",
+            ClaimOutcome::Unavailable => continue,
+        };
+        debug!(id = %candidate.id, actor, action, "Took ready issue");
+        if let Some(issue) = storage.get_issue(&candidate.id)? {
+            return Ok(Some((action, issue)));
+        }
+    }
+    Ok(None)
+}
+
+fn print_taken(taken: Option<&(&'static str, crate::model::Issue)>, ctx: &OutputContext) {
+    if ctx.is_json() || ctx.is_toon() {
+        let output = taken.map(|(action, issue)| TakeOutput {
+            action: *action,
+            issue: ReadyIssue::from(issue),
+        });
+        if ctx.is_json() {
+            ctx.json_pretty(&output);
+        } else {
+            ctx.toon(&output);
+        }
+        return;
+    }
+    if matches!(ctx.mode(), OutputMode::Quiet) {
+        return;
+    }
+    match taken {
+        Some((action, issue)) => {
+            let verb = if *action == "resumed" {
+                "Resuming"
+            } else {
+                "Took"
+            };
+            println!(
+                "{verb} {}: {} [{}]",
+                issue.id,
+                issue.title,
+                format_priority(&issue.priority)
+            );
+        }
+        None => println!("✨ No ready issues to take"),
+    }
+}
+
+/// Stream the ready queue as NDJSON.
+///
+/// With `--fields`, only the requested columns are queried and serialized;
+/// otherwise each line is a full `ReadyIssue`.
+fn emit_ready_jsonl(
+    storage: &crate::storage::SqliteStorage,
+    filters: &ReadyFilters,
+    sort_policy: ReadySortPolicy,
+    external_db_paths: &std::collections::HashMap<String, std::path::PathBuf>,
+    external_cache: ExternalCachePolicy,
+    args: &ReadyArgs,
+) -> Result<()> {
+    let external_statuses = storage.resolve_external_dependency_statuses_cached(
+        external_db_paths,
+        true,
+        external_cache,
+    )?;
+    let external_blockers = storage.external_blockers(&external_statuses)?;
+    let limit = args.limit_cap().unwrap_or(usize::MAX);
+
+    let stdout = std::io::stdout();
+    let mut out = std::io::BufWriter::new(stdout.lock());
+    if args.fields.is_empty() {
+        let issues = storage.get_ready_issues(filters, sort_policy)?;
+        for issue in issues
+            .iter()
+            .filter(|issue| !external_blockers.contains_key(&issue.id))
+            .take(limit)
+        {
+            serde_json::to_writer(&mut out, &ReadyIssue::from(issue))?;
+            out.write_all(b"\n")?;
+        }
+    } else {
+        let fields: Vec<String> = args.fields.iter().map(|f| f.trim().to_string()).collect();
+        let keep_id = fields.iter().any(|f| f == "id");
+        let rows = storage.get_ready_issue_columns(filters, sort_policy, &fields)?;
+        for mut row in rows
+            .into_iter()
+            .filter(|row| {
+                row.get("id")
+                    .and_then(|id| id.as_str())
+                    .is_none_or(|id| !external_blockers.contains_key(id))
+            })
+            .take(limit)
+        {
+            if !keep_id {
+                row.remove("id");
+            }
+            serde_json::to_writer(&mut out, &row)?;
+            out.write_all(b"\n")?;
+        }
+    }
+    out.flush()?;
     Ok(())
 }
 
@@ -174,6 +679,33 @@ fn format_ready_line(
     )
 }
 
+/// Format a ready issue as a Markdown checklist item.
+///
+/// `- [ ] {id} P{n} {title} ({assignee})`, with the ID linked when a base URL
+/// is given and Markdown-special characters in the title escaped.
+fn format_ready_markdown_line(issue: &crate::model::Issue, links: Option<&str>) -> String {
+    let id = links.map_or_else(
+        || issue.id.clone(),
+        |base| {
+            format!(
+                "[{}]({}/{})",
+                issue.id,
+                base.trim_end_matches('/'),
+                issue.id
+            )
+        },
+    );
+    let mut line = format!(
+        "- [ ] {id} {} {}",
+        format_priority(&issue.priority),
+        escape_markdown(&issue.title)
+    );
+    if let Some(assignee) = issue.assignee.as_deref().filter(|a| !a.is_empty()) {
+        line.push_str(&format!(" ({})", escape_markdown(assignee)));
+    }
+    line
+}
+
 /// Parse type filter strings to `IssueType` enums.
 fn parse_types(types: &[String]) -> Result<Option<Vec<IssueType>>> {
     if types.is_empty() {
@@ -222,6 +754,134 @@ mod tests {
         info!("test_parse_types: assertions passed");
     }
 
+    #[test]
+    fn test_apply_weighted_sort() {
+        init_logging();
+        info!("test_apply_weighted_sort: starting");
+        let mut storage = crate::storage::SqliteStorage::open_memory().unwrap();
+        let make = |id: &str, priority: i32| crate::model::Issue {
+            id: id.to_string(),
+            title: id.to_string(),
+            priority: Priority(priority),
+            ..crate::model::Issue::default()
+        };
+        let mut issues = vec![
+            make("bd-a", 1),
+            make("bd-b", 3),
+            make("bd-c", 2),
+            make("bd-d", 2),
+        ];
+        for issue in &issues {
+            storage.create_issue(issue, "tester").unwrap();
+        }
+        storage
+            .add_dependency("bd-a", "bd-c", "blocks", "tester")
+            .unwrap();
+        storage
+            .add_dependency("bd-b", "bd-c", "related", "tester")
+            .unwrap();
+
+        // urgency is 4 - P (P1 = 3), so more urgent issues score higher
+        let expr = ScoreExpr::parse("urgency*10 + age_days + dependent_count").unwrap();
+        apply_weighted_sort(&storage, &mut issues, &expr).unwrap();
+        let ids: Vec<&str> = issues.iter().map(|issue| issue.id.as_str()).collect();
+        assert_eq!(ids, ["bd-a", "bd-c", "bd-d", "bd-b"]);
+
+        // priority is the raw P value, so it ranks P3 above P1
+        let expr = ScoreExpr::parse("priority").unwrap();
+        apply_weighted_sort(&storage, &mut issues, &expr).unwrap();
+        let ids: Vec<&str> = issues.iter().map(|issue| issue.id.as_str()).collect();
+        assert_eq!(ids, ["bd-b", "bd-c", "bd-d", "bd-a"]);
+        info!("test_apply_weighted_sort: assertions passed");
+    }
+
+    #[test]
+    fn test_parse_assignee_group() {
+        init_logging();
+        info!("test_parse_assignee_group: starting");
+        let roster = parse_assignee_group("# platform team\nalice\n  bob  # on call\n\ncarol\n")
+            .expect("parse roster");
+        assert_eq!(roster, ["alice", "bob", "carol"]);
+        assert!(parse_assignee_group("# nobody yet\n\n").is_err());
+        info!("test_parse_assignee_group: assertions passed");
+    }
+
+    #[test]
+    fn test_cap_per_assignee() {
+        init_logging();
+        info!("test_cap_per_assignee: starting");
+        let make = |id: &str, assignee: Option<&str>| crate::model::Issue {
+            id: id.to_string(),
+            assignee: assignee.map(str::to_string),
+            ..crate::model::Issue::default()
+        };
+        let mut issues = vec![
+            make("bd-1", Some("alice")),
+            make("bd-2", Some("alice")),
+            make("bd-3", None),
+            make("bd-4", Some("bob")),
+            make("bd-5", Some("alice")),
+            make("bd-6", None),
+        ];
+        let hidden = cap_per_assignee(&mut issues, 1);
+        let ids: Vec<&str> = issues.iter().map(|issue| issue.id.as_str()).collect();
+        assert_eq!(ids, ["bd-1", "bd-3", "bd-4"]);
+        assert_eq!(hidden.get("alice"), Some(&2));
+        assert_eq!(hidden.get(UNASSIGNED_KEY), Some(&1));
+        assert!(!hidden.contains_key("bob"));
+        assert_eq!(
+            format_hidden_summary(&hidden),
+            "Hidden by capacity limit: (unassigned) +1, alice +2"
+        );
+        info!("test_cap_per_assignee: assertions passed");
+    }
+
+    #[test]
+    fn test_cap_per_assignee_treats_blank_as_unassigned() {
+        init_logging();
+        info!("test_cap_per_assignee_treats_blank_as_unassigned: starting");
+        let make = |id: &str, assignee: Option<&str>| crate::model::Issue {
+            id: id.to_string(),
+            assignee: assignee.map(str::to_string),
+            ..crate::model::Issue::default()
+        };
+        let mut issues = vec![
+            make("bd-1", Some("")),
+            make("bd-2", None),
+            make("bd-3", Some("   ")),
+            make("bd-4", Some("alice")),
+        ];
+        let hidden = cap_per_assignee(&mut issues, 1);
+        let ids: Vec<&str> = issues.iter().map(|issue| issue.id.as_str()).collect();
+        assert_eq!(ids, ["bd-1", "bd-4"]);
+        assert_eq!(hidden.get(UNASSIGNED_KEY), Some(&2));
+        assert_eq!(hidden.len(), 1);
+        info!("test_cap_per_assignee_treats_blank_as_unassigned: assertions passed");
+    }
+
+    #[test]
+    fn test_format_ready_markdown_line() {
+        init_logging();
+        info!("test_format_ready_markdown_line: starting");
+        let mut issue = crate::model::Issue {
+            id: "bd-abc".to_string(),
+            title: "Fix *bold* [link]".to_string(),
+            priority: Priority(1),
+            ..crate::model::Issue::default()
+        };
+        assert_eq!(
+            format_ready_markdown_line(&issue, None),
+            "- [ ] bd-abc P1 Fix \\*bold\\* \\[link\\]"
+        );
+
+        issue.assignee = Some("alice".to_string());
+        assert_eq!(
+            format_ready_markdown_line(&issue, Some("https://tracker/issues/")),
+            "- [ ] [bd-abc](https://tracker/issues/bd-abc) P1 Fix \\*bold\\* \\[link\\] (alice)"
+        );
+        info!("test_format_ready_markdown_line: assertions passed");
+    }
+
     #[test]
     fn test_parse_priorities() {
         init_logging();
@@ -0,0 +1,196 @@
+//! Reassign command implementation.
+//!
+//! Hands a batch of issues (explicit IDs or defer-style filters) to another
+//! assignee in one transaction, skipping work someone else has in progress
+//! unless `--steal` is given.
+
+use crate::cli::ReassignArgs;
+use crate::cli::commands::defer::{SkippedIssue, select_issues, validate_targets};
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::model::{Issue, Status};
+use crate::output::OutputContext;
+use crate::util::id::IdResolver;
+use serde::Serialize;
+
+/// A reassigned (or, on a dry run, reassignable) issue.
+#[derive(Debug, Serialize)]
+struct ReassignedIssue {
+    id: String,
+    title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    from: Option<String>,
+}
+
+/// JSON output for `br reassign`.
+#[derive(Debug, Serialize)]
+struct ReassignResult {
+    to: String,
+    dry_run: bool,
+    reassigned: Vec<ReassignedIssue>,
+    skipped: Vec<SkippedIssue>,
+}
+
+/// Execute the reassign command.
+///
+/// # Errors
+///
+/// Returns an error if no target is given, IDs cannot be resolved, or the
+/// database update fails.
+pub fn execute(
+    args: &ReassignArgs,
+    json: bool,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let to = args.to.trim();
+    if to.is_empty() {
+        return Err(BeadsError::validation("to", "assignee cannot be empty"));
+    }
+    validate_targets(&args.ids, &args.filters)?;
+
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+
+    let config_layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let actor = config::resolve_actor(&config_layer);
+    let resolver = IdResolver::new(config::resolver_config_from_layer(&config_layer));
+    let all_ids = storage_ctx.storage.get_all_ids()?;
+    let storage = &mut storage_ctx.storage;
+
+    let (candidates, mut skipped) = select_issues(
+        storage,
+        &resolver,
+        &all_ids,
+        &args.ids,
+        &args.filters,
+        false,
+    )?;
+
+    let mut eligible: Vec<Issue> = Vec::new();
+    for issue in candidates {
+        if let Some(reason) = skip_reason(&issue, to, args.steal) {
+            skipped.push(SkippedIssue {
+                id: issue.id.clone(),
+                reason,
+            });
+            continue;
+        }
+        eligible.push(issue);
+    }
+
+    let reassigned_ids: Vec<String> = if args.dry_run || eligible.is_empty() {
+        eligible.iter().map(|issue| issue.id.clone()).collect()
+    } else {
+        let ids: Vec<String> = eligible.iter().map(|issue| issue.id.clone()).collect();
+        tracing::info!(count = ids.len(), to, "Reassigning issues");
+        let (updated, claimed) = storage.reassign_issues(&ids, to, args.steal, &actor)?;
+        // Claimed between selection and the write; report like any other skip.
+        skipped.extend(claimed.into_iter().map(|id| SkippedIssue {
+            id,
+            reason: "claimed by another actor".to_string(),
+        }));
+        for issue in &updated {
+            crate::util::set_last_touched_id(&beads_dir, &issue.id);
+        }
+        updated.into_iter().map(|issue| issue.id).collect()
+    };
+
+    let reassigned: Vec<ReassignedIssue> = eligible
+        .iter()
+        .filter(|issue| reassigned_ids.contains(&issue.id))
+        .map(|issue| ReassignedIssue {
+            id: issue.id.clone(),
+            title: issue.title.clone(),
+            from: issue.assignee.clone(),
+        })
+        .collect();
+
+    let result = ReassignResult {
+        to: to.to_string(),
+        dry_run: args.dry_run,
+        reassigned,
+        skipped,
+    };
+
+    if json || ctx.is_json() {
+        ctx.json_pretty(&result);
+    } else if !ctx.is_quiet() {
+        let verb = if args.dry_run {
+            "Would reassign"
+        } else {
+            "Reassigned"
+        };
+        println!(
+            "{verb} {} issue(s) to {to}, skipped {}",
+            result.reassigned.len(),
+            result.skipped.len()
+        );
+        for issue in &result.reassigned {
+            let from = issue.from.as_deref().unwrap_or("unassigned");
+            println!("  {}: {} (was {from})", issue.id, issue.title);
+        }
+        for item in &result.skipped {
+            println!("\u{2298} Skipped {}: {}", item.id, item.reason);
+        }
+    }
+
+    storage_ctx.flush_no_db_if_dirty()?;
+    Ok(())
+}
+
+/// Why `issue` should not be handed to `to`, if anything.
+///
+/// Work another actor has in progress counts as claimed and is only taken
+/// with `steal`.
+fn skip_reason(issue: &Issue, to: &str, steal: bool) -> Option<String> {
+    let assignee = issue.assignee.as_deref().filter(|a| !a.is_empty());
+    if issue.status.is_terminal() {
+        return Some(format!("cannot reassign {} issue", issue.status.as_str()));
+    }
+    if assignee == Some(to) {
+        return Some(format!("already assigned to {to}"));
+    }
+    match assignee {
+        Some(owner) if issue.status == Status::InProgress && !steal => {
+            Some(format!("in progress by {owner} (use --steal to take it)"))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(status: Status, assignee: Option<&str>) -> Issue {
+        Issue {
+            id: "bd-1".to_string(),
+            title: "Task".to_string(),
+            status,
+            assignee: assignee.map(str::to_string),
+            ..Issue::default()
+        }
+    }
+
+    #[test]
+    fn test_skip_reason_respects_claims() {
+        assert!(skip_reason(&issue(Status::Open, Some("alice")), "bob", false).is_none());
+        assert!(skip_reason(&issue(Status::InProgress, None), "bob", false).is_none());
+
+        let claimed = issue(Status::InProgress, Some("alice"));
+        assert!(
+            skip_reason(&claimed, "bob", false)
+                .unwrap()
+                .contains("--steal")
+        );
+        assert!(skip_reason(&claimed, "bob", true).is_none());
+
+        assert!(
+            skip_reason(&issue(Status::Open, Some("bob")), "bob", true)
+                .unwrap()
+                .contains("already assigned")
+        );
+        assert!(skip_reason(&issue(Status::Closed, None), "bob", true).is_some());
+    }
+}
@@ -78,7 +78,7 @@ pub fn execute(
         OutputFormat::Json => {
             ctx.json_pretty(&payload);
         }
-        OutputFormat::Text | OutputFormat::Csv => {
+        OutputFormat::Text | OutputFormat::Csv | OutputFormat::Tsv => {
             // Text mode: still emit JSON Schema; don't require callers to pass --json.
             let json = serde_json::to_string_pretty(&payload).expect("schema payload is JSON");
             println!("{json}");
@@ -9,12 +9,11 @@ use crate::format::{
     IssueWithCounts, TextFormatOptions, csv, format_issue_line_with, terminal_width,
 };
 use crate::model::{IssueType, Priority, Status};
-use crate::output::{IssueTable, IssueTableColumns, OutputContext, OutputMode};
+use crate::output::{IssueTable, IssueTableColumns, OutputContext, OutputMode, stdout_is_terminal};
 use crate::storage::{ListFilters, SqliteStorage};
 use chrono::Utc;
 use regex::{Regex, RegexBuilder};
 use std::collections::{HashMap, HashSet};
-use std::io::IsTerminal;
 use std::str::FromStr;
 
 /// Execute the search command.
@@ -43,7 +42,7 @@ pub fn execute(
     let storage = &storage_ctx.storage;
     let config_layer = config::load_config(&beads_dir, Some(storage), cli)?;
     let use_color = config::should_use_color(&config_layer);
-    let max_width = if std::io::stdout().is_terminal() {
+    let max_width = if stdout_is_terminal() {
         Some(terminal_width())
     } else {
         None
@@ -132,6 +131,16 @@ pub fn execute(
             print!("{csv_output}");
             return Ok(());
         }
+        OutputFormat::Tsv => {
+            let issues: Vec<_> = issues_with_counts
+                .iter()
+                .map(|iwc| iwc.issue.clone())
+                .collect();
+            let fields = csv::parse_fields(args.filters.fields.as_deref());
+            let tsv_output = csv::format_tsv(&issues, &fields);
+            print!("{tsv_output}");
+            return Ok(());
+        }
         OutputFormat::Text => {}
     }
 
@@ -292,7 +301,12 @@ fn build_filters(args: &ListArgs) -> Result<ListFilters> {
         Some(parsed)
     };
 
+    let mut predicates = super::list::parse_where(&args.where_)?;
+    let status_predicate = predicates.iter().any(|p| p.column == "status");
+    super::list::hide_tombstones_unless_named(&mut predicates, args.all);
+
     let include_closed = args.all
+        || status_predicate
         || statuses
             .as_ref()
             .is_some_and(|parsed| parsed.iter().any(Status::is_terminal));
@@ -300,6 +314,7 @@ fn build_filters(args: &ListArgs) -> Result<ListFilters> {
     // Deferred issues are included by default (consistent with "open" status semantics).
     let include_deferred = args.deferred
         || args.all
+        || status_predicate
         || statuses.is_none()
         || statuses
             .as_ref()
@@ -325,7 +340,8 @@ fn build_filters(args: &ListArgs) -> Result<ListFilters> {
         },
         labels_or: None,
         updated_before: None,
-        updated_after: None,
+        updated_after: super::list::updated_since_cutoff(args.updated_since.as_deref())?,
+        predicates,
     })
 }
 
@@ -603,4 +619,40 @@ mod tests {
         apply_sort(&mut items, Some("created_at")).expect("sort");
         assert_eq!(items[0].issue.id, "bd-new");
     }
+
+    #[test]
+    fn test_build_filters_status_predicate_keeps_tombstones_hidden() {
+        use crate::storage::{CompareOp, PredicateValue};
+
+        let hides_tombstones = |filters: &ListFilters| {
+            filters.predicates.iter().any(|p| {
+                p.column == "status"
+                    && p.op == CompareOp::Ne
+                    && matches!(&p.value, PredicateValue::Text(v) if v == "tombstone")
+            })
+        };
+
+        let args = ListArgs {
+            where_: vec!["status!=open".to_string()],
+            ..Default::default()
+        };
+        let filters = build_filters(&args).expect("build filters");
+        assert!(filters.include_closed);
+        assert!(hides_tombstones(&filters));
+
+        let args = ListArgs {
+            where_: vec!["status=tombstone".to_string()],
+            ..Default::default()
+        };
+        let filters = build_filters(&args).expect("build filters");
+        assert!(!hides_tombstones(&filters));
+
+        let args = ListArgs {
+            where_: vec!["status!=open".to_string()],
+            all: true,
+            ..Default::default()
+        };
+        let filters = build_filters(&args).expect("build filters");
+        assert!(!hides_tombstones(&filters));
+    }
 }
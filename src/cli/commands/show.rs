@@ -3,16 +3,54 @@
 use crate::cli::{ShowArgs, resolve_output_format_basic};
 use crate::config;
 use crate::error::{BeadsError, Result};
-use crate::format::{format_priority_label, format_status_icon_colored};
+use crate::format::{
+    IssueDetails, IssueWithDependencyMetadata, TimeFormat, format_priority_label,
+    format_status_icon_colored, format_timestamp_or,
+};
+use crate::model::DependencyType;
 use crate::output::{IssuePanel, OutputContext, OutputMode};
-use crate::util::id::{IdResolver, ResolverConfig};
+use crate::util::id::IdResolver;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
 use std::fmt::Write as FmtWrite;
 
+/// Field names accepted by `--fields`.
+const SHOW_FIELDS: &[&str] = &[
+    "id",
+    "title",
+    "description",
+    "design",
+    "acceptance_criteria",
+    "notes",
+    "status",
+    "priority",
+    "issue_type",
+    "assignee",
+    "owner",
+    "estimated_minutes",
+    "created_at",
+    "created_by",
+    "updated_at",
+    "closed_at",
+    "close_reason",
+    "due_at",
+    "defer_until",
+    "external_ref",
+    "labels",
+    "dependencies",
+    "dependents",
+    "comments",
+    "events",
+    "parent",
+];
+
 /// Execute the show command.
 ///
 /// # Errors
 ///
 /// Returns an error if the database cannot be opened or issues are not found.
+#[allow(clippy::too_many_lines)]
 pub fn execute(
     args: &ShowArgs,
     _json: bool,
@@ -36,12 +74,13 @@ pub fn execute(
     }
 
     let config_layer = config::load_config(&beads_dir, Some(storage), cli)?;
-    let id_config = config::id_config_from_layer(&config_layer);
-    let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
+    let resolver = IdResolver::new(config::resolver_config_from_layer(&config_layer));
     let use_color = config::should_use_color(&config_layer);
+    let time_format = config::time_format_from_layer(&config_layer)?;
     let output_format = resolve_output_format_basic(args.format, outer_ctx.is_json(), false);
     let quiet = cli.quiet.unwrap_or(false);
     let ctx = OutputContext::from_output_format(output_format, quiet, !use_color);
+    let fields = validate_fields(&args.fields)?;
 
     let mut details_list = Vec::new();
     for id_input in target_ids {
@@ -52,7 +91,10 @@ pub fn execute(
         )?;
 
         // Fetch full details including comments and events
-        if let Some(details) = storage.get_issue_details(&resolution.id, true, false, 10)? {
+        if let Some(mut details) = storage.get_issue_details(&resolution.id, true, false, 10)? {
+            if args.no_body {
+                details.issue.description = None;
+            }
             details_list.push(details);
         } else {
             return Err(BeadsError::IssueNotFound { id: resolution.id });
@@ -62,6 +104,51 @@ pub fn execute(
     if matches!(ctx.mode(), OutputMode::Quiet) {
         return Ok(());
     }
+    if !fields.is_empty() {
+        let projected = details_list
+            .iter()
+            .map(|details| project_fields(details, &fields))
+            .collect::<Result<Vec<_>>>()?;
+        match output_format {
+            crate::cli::OutputFormat::Json => ctx.json_pretty(&projected),
+            crate::cli::OutputFormat::Toon => ctx.toon_with_stats(&projected, args.stats),
+            crate::cli::OutputFormat::Text
+            | crate::cli::OutputFormat::Csv
+            | crate::cli::OutputFormat::Tsv => {
+                for (i, object) in projected.iter().enumerate() {
+                    if i > 0 {
+                        println!();
+                    }
+                    print!("{}", format_projected(object, &fields));
+                }
+            }
+        }
+        return Ok(());
+    }
+    if args.related {
+        let external_projects = config::external_project_db_paths(&config_layer, &beads_dir);
+        external_projects.report_unresolved();
+        let external_db_paths = external_projects.resolved;
+        let external_statuses =
+            storage.resolve_external_dependency_statuses(&external_db_paths, false)?;
+        let with_links: Vec<ShowWithLinks<'_>> = details_list
+            .iter()
+            .map(|details| ShowWithLinks {
+                details,
+                linked: collect_linked(details, &external_statuses),
+            })
+            .collect();
+        render_with_links(
+            &with_links,
+            output_format,
+            &ctx,
+            args,
+            use_color,
+            time_format,
+        );
+        return Ok(());
+    }
+
     match output_format {
         crate::cli::OutputFormat::Json => {
             ctx.json_pretty(&details_list);
@@ -69,16 +156,19 @@ pub fn execute(
         crate::cli::OutputFormat::Toon => {
             ctx.toon_with_stats(&details_list, args.stats);
         }
-        crate::cli::OutputFormat::Text | crate::cli::OutputFormat::Csv => {
+        crate::cli::OutputFormat::Text
+        | crate::cli::OutputFormat::Csv
+        | crate::cli::OutputFormat::Tsv => {
             for (i, details) in details_list.iter().enumerate() {
                 if i > 0 {
                     println!(); // Separate multiple issues
                 }
                 if matches!(ctx.mode(), OutputMode::Rich) {
-                    let panel = IssuePanel::from_details(details, ctx.theme());
+                    let panel =
+                        IssuePanel::from_details(details, ctx.theme()).time_format(time_format);
                     panel.print(&ctx, args.wrap);
                 } else {
-                    print_issue_details(details, use_color);
+                    print_issue_details(details, use_color, time_format);
                 }
             }
         }
@@ -87,12 +177,203 @@ pub fn execute(
     Ok(())
 }
 
-fn print_issue_details(details: &crate::format::IssueDetails, use_color: bool) {
-    let output = format_issue_details(details, use_color);
+/// Issue details plus its linked issues, for `show --related`.
+#[derive(Serialize)]
+struct ShowWithLinks<'a> {
+    #[serde(flatten)]
+    details: &'a IssueDetails,
+    linked: LinkedIssues,
+}
+
+/// Linked issues grouped by relationship.
+#[derive(Debug, Default, Serialize)]
+struct LinkedIssues {
+    /// Issues (or external capabilities) this issue waits on.
+    blocked_by: Vec<LinkedIssue>,
+    /// Issues waiting on this one.
+    blocks: Vec<LinkedIssue>,
+    /// Non-blocking links in either direction.
+    related: Vec<LinkedIssue>,
+}
+
+#[derive(Debug, Serialize)]
+struct LinkedIssue {
+    id: String,
+    title: String,
+    status: String,
+    dependency_type: String,
+}
+
+/// Group an issue's dependencies and dependents into blockers, blocked, and related.
+///
+/// External dependencies are reported as `closed` when satisfied and `blocked` otherwise.
+fn collect_linked(
+    details: &IssueDetails,
+    external_statuses: &HashMap<String, bool>,
+) -> LinkedIssues {
+    let is_blocking = |dep: &IssueWithDependencyMetadata| {
+        dep.dep_type
+            .parse::<DependencyType>()
+            .is_ok_and(|dep_type| dep_type.is_blocking())
+    };
+    let to_linked = |dep: &IssueWithDependencyMetadata| {
+        if dep.id.starts_with("external:") {
+            let satisfied = external_statuses.get(&dep.id).copied().unwrap_or(false);
+            let title = super::dep::parse_external_dep_id(&dep.id).map_or_else(
+                || dep.id.clone(),
+                |(project, capability)| format!("{project}:{capability}"),
+            );
+            LinkedIssue {
+                id: dep.id.clone(),
+                title,
+                status: if satisfied { "closed" } else { "blocked" }.to_string(),
+                dependency_type: dep.dep_type.clone(),
+            }
+        } else {
+            LinkedIssue {
+                id: dep.id.clone(),
+                title: dep.title.clone(),
+                status: dep.status.as_str().to_string(),
+                dependency_type: dep.dep_type.clone(),
+            }
+        }
+    };
+
+    let mut linked = LinkedIssues::default();
+    for dep in &details.dependencies {
+        if is_blocking(dep) {
+            linked.blocked_by.push(to_linked(dep));
+        } else {
+            linked.related.push(to_linked(dep));
+        }
+    }
+    for dep in &details.dependents {
+        if is_blocking(dep) {
+            linked.blocks.push(to_linked(dep));
+        } else if !linked.related.iter().any(|item| item.id == dep.id) {
+            linked.related.push(to_linked(dep));
+        }
+    }
+    linked
+}
+
+fn render_with_links(
+    with_links: &[ShowWithLinks<'_>],
+    output_format: crate::cli::OutputFormat,
+    ctx: &OutputContext,
+    args: &ShowArgs,
+    use_color: bool,
+    time_format: Option<TimeFormat>,
+) {
+    match output_format {
+        crate::cli::OutputFormat::Json => ctx.json_pretty(&with_links),
+        crate::cli::OutputFormat::Toon => ctx.toon_with_stats(&with_links, args.stats),
+        crate::cli::OutputFormat::Text
+        | crate::cli::OutputFormat::Csv
+        | crate::cli::OutputFormat::Tsv => {
+            for (i, item) in with_links.iter().enumerate() {
+                if i > 0 {
+                    println!();
+                }
+                if matches!(ctx.mode(), OutputMode::Rich) {
+                    IssuePanel::from_details(item.details, ctx.theme())
+                        .time_format(time_format)
+                        .print(ctx, args.wrap);
+                } else {
+                    print_issue_details(item.details, use_color, time_format);
+                }
+                print!("{}", format_linked(&item.linked));
+            }
+        }
+    }
+}
+
+fn format_linked(linked: &LinkedIssues) -> String {
+    let mut output = String::new();
+    for (heading, items) in [
+        ("Blocked by", &linked.blocked_by),
+        ("Blocks", &linked.blocks),
+        ("Related", &linked.related),
+    ] {
+        output.push('\n');
+        let _ = writeln!(output, "{heading}:");
+        if items.is_empty() {
+            let _ = writeln!(output, "  (none)");
+        }
+        for item in items {
+            let _ = writeln!(
+                output,
+                "  {} [{}] {} ({})",
+                item.id, item.status, item.title, item.dependency_type
+            );
+        }
+    }
+    output
+}
+
+/// Validate `--fields` against [`SHOW_FIELDS`], returning the trimmed names.
+fn validate_fields(requested: &[String]) -> Result<Vec<String>> {
+    let fields: Vec<String> = requested
+        .iter()
+        .map(|field| field.trim().to_string())
+        .filter(|field| !field.is_empty())
+        .collect();
+    if let Some(unknown) = fields.iter().find(|f| !SHOW_FIELDS.contains(&f.as_str())) {
+        return Err(BeadsError::validation(
+            "fields",
+            format!(
+                "unknown field '{unknown}'; valid: {}",
+                SHOW_FIELDS.join(", ")
+            ),
+        ));
+    }
+    Ok(fields)
+}
+
+/// Project issue details onto the requested fields.
+///
+/// Fields that are unset on the issue are included as `null`.
+fn project_fields(
+    details: &IssueDetails,
+    fields: &[String],
+) -> Result<serde_json::Map<String, Value>> {
+    let Value::Object(full) = serde_json::to_value(details)? else {
+        return Ok(serde_json::Map::new());
+    };
+    Ok(fields
+        .iter()
+        .map(|field| {
+            (
+                field.clone(),
+                full.get(field).cloned().unwrap_or(Value::Null),
+            )
+        })
+        .collect())
+}
+
+fn format_projected(object: &serde_json::Map<String, Value>, fields: &[String]) -> String {
+    let mut output = String::new();
+    for field in fields {
+        let value = match object.get(field) {
+            Some(Value::String(s)) => s.clone(),
+            Some(Value::Null) | None => String::new(),
+            Some(other) => other.to_string(),
+        };
+        let _ = writeln!(output, "{field}: {value}");
+    }
+    output
+}
+
+fn print_issue_details(details: &IssueDetails, use_color: bool, time_format: Option<TimeFormat>) {
+    let output = format_issue_details(details, use_color, time_format);
     print!("{output}");
 }
 
-fn format_issue_details(details: &crate::format::IssueDetails, use_color: bool) -> String {
+fn format_issue_details(
+    details: &IssueDetails,
+    use_color: bool,
+    time_format: Option<TimeFormat>,
+) -> String {
     let mut output = String::new();
     let issue = &details.issue;
     let status_icon = format_status_icon_colored(&issue.status, use_color);
@@ -118,12 +399,14 @@ fn format_issue_details(details: &crate::format::IssueDetails, use_color: bool)
         issue.issue_type.as_str()
     );
 
-    // Created/Updated line
+    // Created/Updated line (date-only by default to match bd)
+    let stamp =
+        |dt: &chrono::DateTime<chrono::Utc>| format_timestamp_or(dt, time_format, "%Y-%m-%d");
     let _ = writeln!(
         output,
         "Created: {} · Updated: {}",
-        issue.created_at.format("%Y-%m-%d"),
-        issue.updated_at.format("%Y-%m-%d")
+        stamp(&issue.created_at),
+        stamp(&issue.updated_at)
     );
 
     if let Some(assignee) = &issue.assignee {
@@ -162,7 +445,7 @@ fn format_issue_details(details: &crate::format::IssueDetails, use_color: bool)
             let _ = writeln!(
                 output,
                 "  [{}] {}: {}",
-                comment.created_at.format("%Y-%m-%d %H:%M UTC"),
+                format_timestamp_or(&comment.created_at, time_format, "%Y-%m-%d %H:%M UTC"),
                 comment.author,
                 comment.body
             );
@@ -174,12 +457,16 @@ fn format_issue_details(details: &crate::format::IssueDetails, use_color: bool)
 
 #[cfg(test)]
 mod tests {
-    use super::format_issue_details;
+    use super::{
+        collect_linked, format_issue_details, format_linked, format_projected, project_fields,
+        validate_fields,
+    };
     use crate::format::{IssueDetails, IssueWithDependencyMetadata};
     use crate::model::{Comment, Issue, IssueType, Priority, Status};
     use crate::storage::SqliteStorage;
     use crate::util::id::{IdResolver, ResolverConfig};
     use chrono::{TimeZone, Utc};
+    use std::collections::HashMap;
     use tracing::info;
 
     fn init_logging() {
@@ -420,11 +707,97 @@ mod tests {
             events: Vec::new(),
             parent: None,
         };
-        let output = format_issue_details(&details, false);
+        let output = format_issue_details(&details, false, None);
         assert!(output.contains("Dependencies:"));
         assert!(output.contains("-> bd-002 (blocks) - Dep"));
         assert!(output.contains("Comments:"));
         assert!(output.contains("alice: Looks good"));
         info!("test_show_text_includes_dependencies_and_comments: assertions passed");
     }
+
+    fn linked_dep(id: &str, title: &str, dep_type: &str) -> IssueWithDependencyMetadata {
+        IssueWithDependencyMetadata {
+            id: id.to_string(),
+            title: title.to_string(),
+            status: Status::Open,
+            priority: Priority::MEDIUM,
+            dep_type: dep_type.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_show_related_groups_links() {
+        init_logging();
+        info!("test_show_related_groups_links: starting");
+        let details = IssueDetails {
+            issue: make_test_issue("bd-001", "Test Issue"),
+            labels: Vec::new(),
+            dependencies: vec![
+                linked_dep("bd-002", "Blocker", "blocks"),
+                linked_dep("external:api:auth", "", "blocks"),
+                linked_dep("bd-003", "Sibling", "related"),
+            ],
+            dependents: vec![
+                linked_dep("bd-004", "Waiter", "blocks"),
+                linked_dep("bd-003", "Sibling", "related"),
+            ],
+            comments: Vec::new(),
+            events: Vec::new(),
+            parent: None,
+        };
+        let externals = HashMap::from([("external:api:auth".to_string(), true)]);
+        let linked = collect_linked(&details, &externals);
+
+        assert_eq!(linked.blocked_by.len(), 2);
+        assert_eq!(linked.blocked_by[1].title, "api:auth");
+        assert_eq!(linked.blocked_by[1].status, "closed");
+        assert_eq!(linked.blocks.len(), 1);
+        assert_eq!(linked.blocks[0].id, "bd-004");
+        assert_eq!(linked.related.len(), 1);
+
+        let text = format_linked(&linked);
+        assert!(text.contains("Blocked by:\n  bd-002 [open] Blocker (blocks)"));
+        assert!(text.contains("Blocks:\n  bd-004 [open] Waiter (blocks)"));
+        assert!(text.contains("Related:\n  bd-003 [open] Sibling (related)"));
+
+        let json = serde_json::to_value(&linked).unwrap();
+        assert!(json["blocked_by"].is_array());
+        info!("test_show_related_groups_links: assertions passed");
+    }
+
+    #[test]
+    fn test_show_fields_projection() {
+        init_logging();
+        info!("test_show_fields_projection: starting");
+        let mut issue = make_test_issue("bd-001", "Test Issue");
+        issue.assignee = Some("alice".to_string());
+        let details = IssueDetails {
+            issue,
+            labels: vec!["bug".to_string()],
+            dependencies: Vec::new(),
+            dependents: Vec::new(),
+            comments: Vec::new(),
+            events: Vec::new(),
+            parent: None,
+        };
+        let fields = validate_fields(&["status".to_string(), " id".to_string()]).unwrap();
+        let projected = project_fields(&details, &fields).unwrap();
+        assert_eq!(projected.len(), 2);
+        assert_eq!(projected["id"], "bd-001");
+        assert_eq!(
+            format_projected(&projected, &fields),
+            "status: open\nid: bd-001\n"
+        );
+
+        let fields = validate_fields(&["owner".to_string()]).unwrap();
+        let projected = project_fields(&details, &fields).unwrap();
+        assert_eq!(projected["owner"], serde_json::Value::Null);
+        info!("test_show_fields_projection: assertions passed");
+    }
+
+    #[test]
+    fn test_show_fields_rejects_unknown() {
+        let err = validate_fields(&["bogus".to_string()]).unwrap_err();
+        assert!(err.to_string().contains("valid: id, title"));
+    }
 }
@@ -1,7 +1,7 @@
 use crate::cli::StaleArgs;
 use crate::config;
 use crate::error::{BeadsError, Result};
-use crate::format::StaleIssue;
+use crate::format::{StaleIssue, format_timestamp};
 use crate::model::{Issue, Status};
 use crate::output::{OutputContext, OutputMode};
 use crate::storage::ListFilters;
@@ -20,6 +20,8 @@ pub fn execute(args: &StaleArgs, cli: &config::CliOverrides, ctx: &OutputContext
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
     let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
     let storage = &storage_ctx.storage;
+    let config_layer = config::load_config(&beads_dir, Some(storage), cli)?;
+    let time_format = config::time_format_from_layer(&config_layer)?;
 
     let statuses = if args.status.is_empty() {
         vec![Status::Open, Status::InProgress]
@@ -56,23 +58,27 @@ pub fn execute(args: &StaleArgs, cli: &config::CliOverrides, ctx: &OutputContext
             args.days
         );
         for (idx, issue) in stale.iter().enumerate() {
-            let days_stale = (now - issue.updated_at).num_days().max(0);
+            // Default to whole days stale; --time-format shows the last update instead.
+            let age = time_format.map_or_else(
+                || format!("{}d", (now - issue.updated_at).num_days().max(0)),
+                |fmt| format_timestamp(&issue.updated_at, fmt, now),
+            );
             let status = issue.status.as_str();
             if let Some(assignee) = issue.assignee.as_deref() {
                 println!(
-                    "{}. [{}] {}d {} {} ({assignee})",
+                    "{}. [{}] {} {} {} ({assignee})",
                     idx + 1,
                     status,
-                    days_stale,
+                    age,
                     issue.id,
                     issue.title
                 );
             } else {
                 println!(
-                    "{}. [{}] {}d {} {}",
+                    "{}. [{}] {} {} {}",
                     idx + 1,
                     status,
-                    days_stale,
+                    age,
                     issue.id,
                     issue.title
                 );
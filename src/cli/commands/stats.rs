@@ -7,7 +7,8 @@ use crate::cli::{OutputFormat, StatsArgs, resolve_output_format_basic};
 use crate::config;
 use crate::error::Result;
 use crate::format::{
-    Breakdown, BreakdownEntry, RecentActivity, Statistics, StatsSummary, truncate_title,
+    Breakdown, BreakdownEntry, OldestOpenIssue, RecentActivity, Statistics, StatsSummary,
+    TimeFormat, format_timestamp_or, truncate_title,
 };
 use crate::model::{IssueType, Status};
 use crate::output::{OutputContext, OutputMode};
@@ -36,6 +37,7 @@ pub fn execute(
     let storage = &storage_ctx.storage;
     let config_layer = config::load_config(&beads_dir, Some(storage), cli)?;
     let use_color = config::should_use_color(&config_layer);
+    let time_format = config::time_format_from_layer(&config_layer)?;
     let output_format = resolve_output_format_basic(args.format, outer_ctx.is_json(), args.robot);
     let quiet = cli.quiet.unwrap_or(false);
     let ctx = OutputContext::from_output_format(output_format, quiet, !use_color);
@@ -103,11 +105,11 @@ pub fn execute(
         OutputFormat::Toon => {
             ctx.toon_with_stats(&output, args.stats);
         }
-        OutputFormat::Text | OutputFormat::Csv => {
+        OutputFormat::Text | OutputFormat::Csv | OutputFormat::Tsv => {
             if matches!(ctx.mode(), OutputMode::Rich) {
-                render_stats_rich(&output, &ctx);
+                render_stats_rich(&output, time_format, &ctx);
             } else {
-                print_text_output(&output);
+                print_text_output(&output, time_format);
             }
         }
     }
@@ -194,6 +196,21 @@ fn compute_summary(
         Some(sum / lead_times.len() as f64)
     };
 
+    // Oldest issue that is still open (any non-terminal status, templates excluded)
+    let oldest_open_issue = issues
+        .iter()
+        .filter(|i| !i.status.is_terminal() && !i.is_template)
+        .min_by(|a, b| {
+            a.created_at
+                .cmp(&b.created_at)
+                .then_with(|| a.id.cmp(&b.id))
+        })
+        .map(|i| OldestOpenIssue {
+            id: i.id.clone(),
+            title: i.title.clone(),
+            created_at: i.created_at,
+        });
+
     // Total excludes tombstones
     let total = issues
         .iter()
@@ -215,6 +232,7 @@ fn compute_summary(
         pinned_issues: pinned,
         epics_eligible_for_closure: epics_eligible,
         average_lead_time_hours: avg_lead_time,
+        oldest_open_issue,
     })
 }
 
@@ -440,6 +458,14 @@ fn print_robot_output(output: &Statistics) {
         println!("avg_lead_time_hours={:.1}", avg);
     }
 
+    if let Some(oldest) = &s.oldest_open_issue {
+        println!(
+            "oldest_open={} oldest_open_created_at={}",
+            oldest.id,
+            oldest.created_at.to_rfc3339()
+        );
+    }
+
     for breakdown in &output.breakdowns {
         for entry in &breakdown.counts {
             // Normalize key for shell-friendly output
@@ -461,7 +487,7 @@ fn print_robot_output(output: &Statistics) {
 }
 
 /// Print text output for stats.
-fn print_text_output(output: &Statistics) {
+fn print_text_output(output: &Statistics, time_format: Option<TimeFormat>) {
     // Match bd format: 📊 Issue Database Status
     println!("📊 Issue Database Status\n");
 
@@ -490,7 +516,10 @@ fn print_text_output(output: &Statistics) {
     }
 
     // Extended section (matches bd format)
-    if s.average_lead_time_hours.is_some() || s.tombstone_issues > 0 {
+    if s.average_lead_time_hours.is_some()
+        || s.tombstone_issues > 0
+        || s.oldest_open_issue.is_some()
+    {
         println!("\nExtended:");
         if let Some(avg_hours) = s.average_lead_time_hours {
             // Format like bd: "N.N hours" or "N days" for large values
@@ -508,6 +537,13 @@ fn print_text_output(output: &Statistics) {
                 s.tombstone_issues
             );
         }
+        if let Some(oldest) = &s.oldest_open_issue {
+            println!(
+                "  Oldest Open:            {} (created {})",
+                oldest.id,
+                format_timestamp_or(&oldest.created_at, time_format, "%Y-%m-%d")
+            );
+        }
     }
 
     for breakdown in &output.breakdowns {
@@ -533,7 +569,7 @@ fn print_text_output(output: &Statistics) {
 
 /// Render stats with rich formatting.
 #[allow(clippy::cast_precision_loss)]
-fn render_stats_rich(output: &Statistics, ctx: &OutputContext) {
+fn render_stats_rich(output: &Statistics, time_format: Option<TimeFormat>, ctx: &OutputContext) {
     let console = Console::default();
     let theme = ctx.theme();
     let width = ctx.width();
@@ -559,7 +595,20 @@ fn render_stats_rich(output: &Statistics, ctx: &OutputContext) {
     if s.blocked_issues > 0 {
         content.append_styled(" \u{26a0}", theme.warning.clone());
     }
-    content.append("\n\n");
+    content.append("\n");
+    if let Some(oldest) = &s.oldest_open_issue {
+        content.append_styled("   Oldest open: ", theme.dimmed.clone());
+        content.append_styled(&oldest.id, theme.issue_id.clone());
+        content.append_styled(
+            &format!(
+                " (created {})",
+                format_timestamp_or(&oldest.created_at, time_format, "%Y-%m-%d")
+            ),
+            theme.dimmed.clone(),
+        );
+        content.append("\n");
+    }
+    content.append("\n");
 
     // === Status Breakdown ===
     content.append_styled("\u{1f4c8} By Status\n", theme.section.clone());
@@ -875,6 +924,23 @@ mod tests {
         assert_eq!(summary.closed_issues, 1);
     }
 
+    #[test]
+    fn test_compute_summary_oldest_open_issue() {
+        let storage = SqliteStorage::open_memory().unwrap();
+
+        let mut oldest_closed = make_issue("t-1", Status::Closed, IssueType::Task);
+        oldest_closed.created_at = Utc::now() - chrono::Duration::days(30);
+        let mut oldest_open = make_issue("t-2", Status::InProgress, IssueType::Task);
+        oldest_open.created_at = Utc::now() - chrono::Duration::days(10);
+        let newer_open = make_issue("t-3", Status::Open, IssueType::Task);
+
+        let all_issues = vec![oldest_closed, oldest_open, newer_open];
+        let summary = compute_summary(&storage, &all_issues).unwrap();
+
+        let oldest = summary.oldest_open_issue.expect("oldest open issue");
+        assert_eq!(oldest.id, "t-2");
+    }
+
     #[test]
     fn test_blocked_by_blocks_deps() {
         let mut storage = SqliteStorage::open_memory().unwrap();
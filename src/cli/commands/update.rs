@@ -6,7 +6,7 @@ use crate::error::{BeadsError, Result};
 use crate::model::{DependencyType, Issue, Status};
 use crate::output::OutputContext;
 use crate::storage::{IssueUpdate, SqliteStorage};
-use crate::util::id::{IdResolver, ResolverConfig};
+use crate::util::id::IdResolver;
 use crate::util::time::parse_flexible_timestamp;
 use crate::validation::LabelValidator;
 use chrono::{DateTime, Utc};
@@ -175,8 +175,7 @@ fn print_update_summary(id: &str, title: &str, before: Option<&Issue>, after: &I
 }
 
 fn build_resolver(config_layer: &config::ConfigLayer, _storage: &SqliteStorage) -> IdResolver {
-    let id_config = config::id_config_from_layer(config_layer);
-    IdResolver::new(ResolverConfig::with_prefix(id_config.prefix))
+    IdResolver::new(config::resolver_config_from_layer(config_layer))
 }
 
 fn resolve_target_ids(
@@ -45,6 +45,10 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub lock_timeout: Option<u64>,
 
+    /// Timestamp format for text output (JSON always uses RFC3339)
+    #[arg(long, global = true, value_parser = ["rfc3339", "relative", "local"])]
+    pub time_format: Option<String>,
+
     /// JSONL-only mode (no DB connection)
     #[arg(long, global = true)]
     pub no_db: bool,
@@ -60,6 +64,14 @@ pub struct Cli {
     /// Disable colored output
     #[arg(long, global = true)]
     pub no_color: bool,
+
+    /// Do not pipe long text output through a pager (env: BEADS_PAGER, PAGER)
+    #[arg(long, global = true)]
+    pub no_pager: bool,
+
+    /// Do not update the last-touched issue marker (for scripted bulk operations)
+    #[arg(long, global = true)]
+    pub no_last_touched: bool,
 }
 
 #[derive(Subcommand, Debug)]
@@ -77,6 +89,11 @@ pub enum Commands {
         /// Backend type (ignored, always sqlite)
         #[arg(long)]
         backend: Option<String>,
+
+        /// Seed config.yaml from a template (builtin: strict, agents;
+        /// or ~/.config/beads/templates/<name>.yaml)
+        #[arg(long)]
+        template: Option<String>,
     },
 
     /// Create a new issue
@@ -113,6 +130,7 @@ pub enum Commands {
     Search(SearchArgs),
 
     /// Manage dependencies
+    #[command(visible_alias = "deps")]
     Dep {
         #[command(subcommand)]
         command: DepCommands,
@@ -155,6 +173,9 @@ pub enum Commands {
     /// Undefer issues (make ready again)
     Undefer(UndeferArgs),
 
+    /// Hand a batch of issues to another assignee
+    Reassign(ReassignArgs),
+
     /// Configuration management
     Config {
         #[command(subcommand)]
@@ -199,8 +220,8 @@ EXAMPLES:
   br sync --status               Show current sync status")]
     Sync(SyncArgs),
 
-    /// Run read-only diagnostics
-    Doctor,
+    /// Run diagnostics and optionally repair common problems
+    Doctor(DoctorArgs),
 
     /// Show diagnostic metadata about the workspace
     Info(InfoArgs),
@@ -230,6 +251,9 @@ EXAMPLES:
 
     /// Manage local history backups
     History(HistoryArgs),
+
+    /// Prune old tombstones and compact the database
+    Gc(GcArgs),
     /// List orphan issues (referenced in commits but open)
     Orphans(OrphansArgs),
     /// Generate changelog from closed issues
@@ -491,6 +515,22 @@ pub struct DeleteArgs {
     pub dry_run: bool,
 }
 
+/// Arguments for the doctor command.
+#[derive(Args, Debug, Default, Clone)]
+pub struct DoctorArgs {
+    /// Repair problems that can be fixed safely (schema, duplicate config keys)
+    #[arg(long)]
+    pub fix: bool,
+
+    /// With --fix, report the repairs without applying them
+    #[arg(long, requires = "fix")]
+    pub dry_run: bool,
+
+    /// With --fix, rewrite config without asking for confirmation
+    #[arg(long, short = 'y', requires = "fix")]
+    pub yes: bool,
+}
+
 /// Arguments for the info command.
 #[derive(Args, Debug, Default, Clone)]
 pub struct InfoArgs {
@@ -559,6 +599,8 @@ pub enum OutputFormat {
     Json,
     /// CSV output with configurable fields
     Csv,
+    /// Tab-separated output with the same fields as CSV
+    Tsv,
     /// TOON format (token-optimized object notation)
     Toon,
 }
@@ -587,6 +629,7 @@ impl OutputFormat {
             "text" | "plain" => Some(Self::Text),
             "json" => Some(Self::Json),
             "csv" => Some(Self::Csv),
+            "tsv" => Some(Self::Tsv),
             "toon" => Some(Self::Toon),
             _ => None,
         }
@@ -615,6 +658,32 @@ impl From<OutputFormatBasic> for OutputFormat {
     }
 }
 
+/// Output format for the ready command.
+#[derive(ValueEnum, Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum ReadyOutputFormat {
+    /// Human-readable text (default)
+    #[default]
+    Text,
+    /// JSON output
+    Json,
+    /// TOON format (token-optimized object notation)
+    Toon,
+    /// Markdown checklist (`- [ ] ID P2 title (assignee)`)
+    Markdown,
+}
+
+impl ReadyOutputFormat {
+    /// Map to the shared basic format; Markdown renders through the text path.
+    #[must_use]
+    pub const fn basic(self) -> OutputFormatBasic {
+        match self {
+            Self::Text | Self::Markdown => OutputFormatBasic::Text,
+            Self::Json => OutputFormatBasic::Json,
+            Self::Toon => OutputFormatBasic::Toon,
+        }
+    }
+}
+
 /// Resolve effective output format with CLI/env precedence.
 #[must_use]
 pub fn resolve_output_format(
@@ -640,7 +709,7 @@ pub fn resolve_output_format_basic(
 ) -> OutputFormat {
     let resolved = resolve_output_format(requested.map(Into::into), json, robot);
     match resolved {
-        OutputFormat::Csv => OutputFormat::Text,
+        OutputFormat::Csv | OutputFormat::Tsv => OutputFormat::Text,
         other => other,
     }
 }
@@ -701,6 +770,11 @@ pub struct ListArgs {
     #[arg(long)]
     pub notes_contains: Option<String>,
 
+    /// Filter by `field<op>value` (e.g. `priority<=1`, `assignee=alice`,
+    /// `status!=closed`); repeatable, ANDed. Ops: = != < <= > >=
+    #[arg(long = "where", value_name = "PREDICATE")]
+    pub where_: Vec<String>,
+
     /// Include closed issues (default excludes closed)
     #[arg(long, short = 'a')]
     pub all: bool,
@@ -709,7 +783,10 @@ pub struct ListArgs {
     #[arg(long)]
     pub limit: Option<usize>,
 
-    /// Sort field (`priority`, `created_at`, `updated_at`, `title`)
+    /// Sort keys as comma-separated `field[:asc|desc]` (e.g. `priority:asc,created:desc`)
+    ///
+    /// Fields: priority, created, updated, id, title. Text and JSON output
+    /// honor the same order.
     #[arg(long)]
     pub sort: Option<String>,
 
@@ -725,6 +802,10 @@ pub struct ListArgs {
     #[arg(long)]
     pub overdue: bool,
 
+    /// Only issues updated within this window (e.g. 30m, 24h, 7d, 2w)
+    #[arg(long, value_name = "DURATION")]
+    pub updated_since: Option<String>,
+
     /// Use long output format
     #[arg(long)]
     pub long: bool,
@@ -754,6 +835,14 @@ pub struct ListArgs {
     /// Default: id, title, status, priority, `issue_type`, assignee, `created_at`, `updated_at`
     #[arg(long, value_name = "FIELDS")]
     pub fields: Option<String>,
+
+    /// Stream a JSON array one issue at a time instead of buffering it (for huge exports)
+    #[arg(long, conflicts_with = "format")]
+    pub json_stream: bool,
+
+    /// Print counts grouped by a field instead of listing issues
+    #[arg(long, value_enum, value_name = "FIELD", conflicts_with = "json_stream")]
+    pub count_by: Option<CountBy>,
 }
 
 /// Arguments for the search command.
@@ -783,6 +872,18 @@ pub struct ShowArgs {
     /// Show token savings stats when using TOON output
     #[arg(long)]
     pub stats: bool,
+
+    /// Only show these fields (comma-separated, e.g. id,status,assignee)
+    #[arg(long, value_name = "FIELDS", value_delimiter = ',')]
+    pub fields: Vec<String>,
+
+    /// Omit the description body
+    #[arg(long)]
+    pub no_body: bool,
+
+    /// Also list blockers, blocked issues, and related links with their statuses
+    #[arg(long, conflicts_with = "fields")]
+    pub related: bool,
 }
 
 #[derive(Subcommand, Debug)]
@@ -798,6 +899,12 @@ pub enum DepCommands {
     Tree(DepTreeArgs),
     /// Detect and report dependency cycles
     Cycles(DepCyclesArgs),
+    /// Explain how <issue> depends on <target> by printing the dependency path
+    Why(DepWhyArgs),
+    /// Import dependencies from an edge list file (`from -> to [type]` per line)
+    Import(DepImportArgs),
+    /// Emit the dependency graph in Graphviz DOT format (pipe to `dot -Tsvg`)
+    Graphviz(DepGraphvizArgs),
 }
 
 /// Subcommands for the epic command.
@@ -906,6 +1013,57 @@ pub struct DepCyclesArgs {
     pub blocking_only: bool,
 }
 
+#[derive(Args, Debug)]
+pub struct DepWhyArgs {
+    /// Issue ID (start of the path)
+    pub issue: String,
+
+    /// Target issue ID (or external:<project>:<capability>)
+    pub target: String,
+
+    /// Enumerate every path instead of only the shortest
+    #[arg(long)]
+    pub all_paths: bool,
+
+    /// Maximum path length in edges (default: 10)
+    #[arg(long, default_value_t = 10)]
+    pub max_depth: usize,
+}
+
+#[derive(Args, Debug)]
+pub struct DepGraphvizArgs {
+    /// Root issue ID (default: the whole workspace)
+    pub issue: Option<String>,
+
+    /// Maximum depth from the root issue (default: 10)
+    #[arg(long, default_value_t = 10)]
+    pub max_depth: usize,
+}
+
+#[derive(Args, Debug)]
+pub struct DepImportArgs {
+    /// Edge list file ('-' for stdin)
+    pub file: PathBuf,
+
+    /// How to handle edges that already exist
+    #[arg(long, value_enum, default_value_t = DepConflictPolicy::Skip)]
+    pub on_conflict: DepConflictPolicy,
+
+    /// Preview the edges without writing them
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+/// How `dep import` treats edges that already exist.
+#[derive(ValueEnum, Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum DepConflictPolicy {
+    /// Leave the existing edge in place
+    #[default]
+    Skip,
+    /// Abort the import without writing anything
+    Error,
+}
+
 #[derive(Subcommand, Debug)]
 pub enum LabelCommands {
     /// Add label(s) to issue(s)
@@ -1152,6 +1310,10 @@ pub enum CountBy {
     Type,
     Assignee,
     Label,
+    /// Overdue vs on-time (due date passed on an open issue)
+    Overdue,
+    /// Labeled vs unlabeled
+    Labeled,
 }
 
 #[derive(Args, Debug, Clone)]
@@ -1182,13 +1344,20 @@ pub struct LintArgs {
 /// Arguments for the defer command.
 #[derive(Args, Debug, Clone, Default)]
 pub struct DeferArgs {
-    /// Issue IDs to defer
+    /// Issue IDs to defer (or select issues with filters instead)
     pub ids: Vec<String>,
 
     /// Defer until date/time (e.g., `+1h`, `tomorrow`, `2025-01-15`)
     #[arg(long)]
     pub until: Option<String>,
 
+    #[command(flatten)]
+    pub filters: DeferFilterArgs,
+
+    /// Show which issues would be deferred without changing them
+    #[arg(long)]
+    pub dry_run: bool,
+
     /// Machine-readable output (alias for --json)
     #[arg(long)]
     pub robot: bool,
@@ -1197,30 +1366,117 @@ pub struct DeferArgs {
 /// Arguments for the undefer command.
 #[derive(Args, Debug, Clone, Default)]
 pub struct UndeferArgs {
-    /// Issue IDs to undefer
+    /// Issue IDs to undefer (or select issues with filters instead)
     pub ids: Vec<String>,
 
+    #[command(flatten)]
+    pub filters: DeferFilterArgs,
+
+    /// Show which issues would be undeferred without changing them
+    #[arg(long)]
+    pub dry_run: bool,
+
     /// Machine-readable output (alias for --json)
     #[arg(long)]
     pub robot: bool,
 }
 
+/// Arguments for the reassign command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct ReassignArgs {
+    /// Issue IDs to reassign (or select issues with filters instead)
+    pub ids: Vec<String>,
+
+    /// New assignee
+    #[arg(long, value_name = "ACTOR")]
+    pub to: String,
+
+    #[command(flatten)]
+    pub filters: DeferFilterArgs,
+
+    /// Also reassign issues another actor has in progress (default: skip them)
+    #[arg(long)]
+    pub steal: bool,
+
+    /// Show which issues would be reassigned without changing them
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Machine-readable output (alias for --json)
+    #[arg(long)]
+    pub robot: bool,
+}
+
+/// Filters selecting issues for bulk defer/undefer/reassign.
+#[derive(Args, Debug, Clone, Default)]
+pub struct DeferFilterArgs {
+    /// Select issues with this label (AND logic, can be repeated)
+    #[arg(long, short = 'l')]
+    pub label: Vec<String>,
+
+    /// Select issues of this type (can be repeated)
+    #[arg(long = "type", short = 't')]
+    pub type_: Vec<String>,
+
+    /// Select issues with this priority (can be repeated, 0-4 or P0-P4)
+    #[arg(long, short = 'p')]
+    pub priority: Vec<String>,
+
+    /// Select issues assigned to this person
+    #[arg(long)]
+    pub assignee: Option<String>,
+}
+
+impl DeferFilterArgs {
+    /// Whether any filter was given.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.label.is_empty()
+            && self.type_.is_empty()
+            && self.priority.is_empty()
+            && self.assignee.is_none()
+    }
+}
+
 /// Arguments for the ready command.
 #[derive(Args, Debug, Clone, Default)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct ReadyArgs {
-    /// Maximum number of issues to return (default: 20, 0 = unlimited)
-    #[arg(long, default_value_t = 20)]
+    /// Maximum number of issues to return (default: 50, 0 = unlimited, same as --all)
+    #[arg(
+        long,
+        default_value_t = 50,
+        allow_hyphen_values = true,
+        value_parser = parse_ready_limit
+    )]
     pub limit: usize,
 
+    /// Return every ready issue (same as --limit 0)
+    #[arg(long, conflicts_with = "limit")]
+    pub all: bool,
+
     /// Filter by assignee (no value = current actor)
     #[arg(long)]
     pub assignee: Option<String>,
 
-    /// Show only unassigned issues
-    #[arg(long)]
+    /// Show only unassigned issues (cannot be combined with --assignee)
+    #[arg(long, conflicts_with = "assignee")]
     pub unassigned: bool,
 
+    /// Show issues assigned to this actor plus unassigned issues
+    /// (cannot be combined with --assignee or --unassigned)
+    #[arg(long, value_name = "ASSIGNEE", conflicts_with_all = ["assignee", "unassigned"])]
+    pub assignee_or_unassigned: Option<String>,
+
+    /// Show issues assigned to anyone listed in FILE (one identity per line,
+    /// `#` comments allowed), unioned with --assignee
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["unassigned", "assignee_or_unassigned", "only_mine_or_free"]
+    )]
+    pub assignee_group: Option<PathBuf>,
+
     /// Filter by label (AND logic, can be repeated)
     #[arg(long, short = 'l')]
     pub label: Vec<String>,
@@ -1229,6 +1485,10 @@ pub struct ReadyArgs {
     #[arg(long)]
     pub label_any: Vec<String>,
 
+    /// Exclude issues with this label (can be repeated, wins over inclusion)
+    #[arg(long)]
+    pub exclude_label: Vec<String>,
+
     /// Filter by issue type (can be repeated)
     #[arg(long = "type", short = 't')]
     pub type_: Vec<String>,
@@ -1241,27 +1501,114 @@ pub struct ReadyArgs {
     #[arg(long, default_value = "hybrid", value_enum)]
     pub sort: SortPolicy,
 
+    /// Order by a score expression, highest first, e.g. `urgency*10 + age_days`
+    /// (fields: priority = raw P value, urgency = 4-P so P0 scores highest, age_days,
+    /// dependent_count; ties keep --sort order)
+    #[arg(long, value_name = "EXPR", conflicts_with = "jsonl")]
+    pub weighted_sort: Option<String>,
+
     /// Include deferred issues
     #[arg(long)]
     pub include_deferred: bool,
 
+    /// Include pinned issues
+    #[arg(long)]
+    pub include_pinned: bool,
+
+    /// Include ephemeral (wisp) issues
+    #[arg(long)]
+    pub include_ephemeral: bool,
+
     /// Wrap long lines instead of truncating in text output
     #[arg(long)]
     pub wrap: bool,
 
-    /// Output format (text, json, toon). Env: BR_OUTPUT_FORMAT, TOON_DEFAULT_FORMAT.
+    /// Output format (text, json, toon, markdown). Env: BR_OUTPUT_FORMAT, TOON_DEFAULT_FORMAT.
     #[arg(long, value_enum)]
-    pub format: Option<OutputFormatBasic>,
+    pub format: Option<ReadyOutputFormat>,
+
+    /// Base URL for issue links in markdown output (e.g. `https://tracker/issues`)
+    #[arg(long, value_name = "BASE_URL")]
+    pub links: Option<String>,
 
     /// Show token savings stats when using TOON output
     #[arg(long)]
     pub stats: bool,
 
+    /// Redraw the ready list every --interval seconds until Ctrl-C
+    /// (non-TTY and JSON output emit a single snapshot)
+    #[arg(long)]
+    pub watch: bool,
+
+    /// Refresh interval in seconds for --watch
+    #[arg(long, default_value_t = 2, requires = "watch")]
+    pub interval: u64,
+
+    /// Restrict to issues assigned to the current actor or unassigned
+    #[arg(long, conflicts_with_all = ["assignee", "unassigned", "assignee_or_unassigned"])]
+    pub only_mine_or_free: bool,
+
+    /// Claim the top ready issue (assignee=actor + `status=in_progress`) and print it
+    #[arg(long, conflicts_with_all = ["watch", "jsonl"])]
+    pub take: bool,
+
+    /// With --take, return an issue the actor already has in progress instead of claiming
+    #[arg(long, requires = "take")]
+    pub resume: bool,
+
+    /// Emit one compact JSON object per line (NDJSON) for polling agents
+    #[arg(long, conflicts_with_all = ["format", "watch"])]
+    pub jsonl: bool,
+
+    /// With --jsonl, only emit these fields and query only their columns
+    /// (comma-separated, e.g. id,priority)
+    #[arg(long, value_name = "FIELDS", value_delimiter = ',', requires = "jsonl")]
+    pub fields: Vec<String>,
+
+    /// Re-query external projects instead of reusing cached statuses
+    /// (see `external-cache-ttl`)
+    #[arg(long)]
+    pub refresh_external: bool,
+
+    /// Show at most N issues per assignee (and N unassigned), reporting how many were hidden
+    #[arg(long, value_name = "N", conflicts_with_all = ["take", "jsonl"])]
+    pub capacity_per_assignee: Option<usize>,
+
+    /// With --json/--format toon, emit full issues with labels, compaction info,
+    /// and dependency_count/dependent_count instead of the bd-parity shape
+    #[arg(long, conflicts_with_all = ["take", "jsonl"])]
+    pub include_counts: bool,
+
+    /// Also write the JSON array to this file while printing the normal output
+    /// (parent directories are created; write failures only warn)
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["take", "jsonl"])]
+    pub output_json_to: Option<PathBuf>,
+
     /// Machine-readable output (alias for --json)
     #[arg(long)]
     pub robot: bool,
 }
 
+impl ReadyArgs {
+    /// Maximum number of issues to show, or `None` when unlimited
+    /// (`--all` or `--limit 0`).
+    #[must_use]
+    pub const fn limit_cap(&self) -> Option<usize> {
+        if self.all || self.limit == 0 {
+            None
+        } else {
+            Some(self.limit)
+        }
+    }
+}
+
+fn parse_ready_limit(value: &str) -> std::result::Result<usize, String> {
+    if value.trim().starts_with('-') {
+        return Err("limit cannot be negative (use 0 or --all for unlimited)".to_string());
+    }
+    value.trim().parse::<usize>().map_err(|err| err.to_string())
+}
+
 /// Arguments for the blocked command.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Args, Debug, Clone, Default)]
@@ -1439,8 +1786,16 @@ pub enum ConfigCommands {
 
     /// Get a specific config value
     Get {
-        /// Config key
+        /// Config key (a key prefix with --all)
         key: String,
+
+        /// Return every effective key starting with the given prefix
+        #[arg(long)]
+        all: bool,
+
+        /// Value to print (exit 0) when the key is unset in every layer
+        #[arg(long, value_name = "VAL", conflicts_with = "all")]
+        default: Option<String>,
     },
 
     /// Set a config value
@@ -1462,6 +1817,45 @@ pub enum ConfigCommands {
 
     /// Show config file paths
     Path,
+
+    /// Export config as YAML for transfer to another workspace
+    Export {
+        /// Export only the project layer instead of the merged config
+        #[arg(long)]
+        project: bool,
+    },
+
+    /// Import config keys from a YAML file
+    Import {
+        /// YAML file produced by `br config export`
+        file: PathBuf,
+
+        /// Write to user config instead of project config
+        #[arg(long)]
+        user: bool,
+
+        /// What to do when a key already exists in the target layer
+        #[arg(long, value_enum, default_value_t = ConfigConflictPolicy::Skip)]
+        on_conflict: ConfigConflictPolicy,
+    },
+
+    /// Compare effective config with another workspace
+    Diff {
+        /// Other workspace root or its `.beads` directory
+        other: PathBuf,
+    },
+}
+
+/// Conflict policy for `br config import`.
+#[derive(ValueEnum, Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum ConfigConflictPolicy {
+    /// Keep the existing value
+    #[default]
+    Skip,
+    /// Replace the existing value
+    Overwrite,
+    /// Abort the import without writing anything
+    Error,
 }
 
 /// Arguments for the stats command.
@@ -1576,6 +1970,23 @@ pub struct UpgradeArgs {
     pub dry_run: bool,
 }
 
+/// Arguments for the gc command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct GcArgs {
+    /// Prune tombstones deleted more than N days ago
+    /// (default: deletions_retention_days from metadata.json, else 30)
+    #[arg(long, value_name = "DAYS")]
+    pub older_than: Option<u64>,
+
+    /// Also prune audit events older than N days (kept by default)
+    #[arg(long, value_name = "DAYS")]
+    pub events_older_than: Option<u64>,
+
+    /// Report what would be pruned without changing anything
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
 /// Arguments for the orphans command.
 #[derive(Args, Debug, Clone, Default)]
 pub struct OrphansArgs {
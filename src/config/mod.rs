@@ -12,19 +12,22 @@
 pub mod routing;
 
 use crate::error::{BeadsError, Result};
+use crate::format::TimeFormat;
 use crate::model::{IssueType, Priority};
+use crate::output::stdout_is_terminal;
 use crate::storage::SqliteStorage;
 use crate::sync::{
     ExportConfig, ImportConfig, export_to_jsonl_with_policy, finalize_export, import_from_jsonl,
 };
-use crate::util::id::IdConfig;
+use crate::util::id::{IdConfig, ResolverConfig};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::env;
 use std::fs;
-use std::io::{BufRead, IsTerminal};
+use std::io::BufRead;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::Duration;
 use tracing::warn;
 
 /// Default database filename used when metadata is missing.
@@ -663,6 +666,7 @@ pub struct CliOverrides {
     pub no_auto_flush: Option<bool>,
     pub no_auto_import: Option<bool>,
     pub lock_timeout: Option<u64>,
+    pub time_format: Option<String>,
 }
 
 impl CliOverrides {
@@ -700,6 +704,9 @@ impl CliOverrides {
         if let Some(lock_timeout) = self.lock_timeout {
             insert_key_value(&mut layer, "lock-timeout", lock_timeout.to_string());
         }
+        if let Some(time_format) = &self.time_format {
+            insert_key_value(&mut layer, "display.time-format", time_format.clone());
+        }
 
         layer
     }
@@ -827,6 +834,32 @@ pub fn id_config_from_layer(layer: &ConfigLayer) -> IdConfig {
     }
 }
 
+/// Build the ID resolver config from a merged config layer.
+///
+/// `strict-prefix` (bool) rejects IDs whose prefix differs from `issue_prefix`;
+/// `allowed-prefixes` (comma-separated) lists extra prefixes that are accepted.
+#[must_use]
+pub fn resolver_config_from_layer(layer: &ConfigLayer) -> ResolverConfig {
+    let id_config = id_config_from_layer(layer);
+    let mut config = ResolverConfig::with_prefix(id_config.prefix);
+    config.strict_prefix = get_value(
+        layer,
+        &["strict-prefix", "strict_prefix", "id.strict-prefix"],
+    )
+    .and_then(|value| parse_bool(value))
+    .unwrap_or(false);
+    config.allowed_prefixes = get_value(layer, &["allowed-prefixes", "allowed_prefixes"])
+        .map(|value| {
+            value
+                .split(',')
+                .map(|p| p.trim().to_lowercase())
+                .filter(|p| !p.is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+    config
+}
+
 /// Resolve default priority for new issues from config.
 ///
 /// # Errors
@@ -856,12 +889,34 @@ pub fn display_color_from_layer(layer: &ConfigLayer) -> Option<bool> {
         .and_then(|value| parse_bool(value))
 }
 
+/// Resolve the timestamp format for human-readable output.
+///
+/// Accepts keys: `display.time-format`, `display-time-format`, `display_time_format`.
+/// Returns `None` when unset so callers keep their default rendering.
+///
+/// # Errors
+///
+/// Returns an error if the configured value is not a known format.
+pub fn time_format_from_layer(layer: &ConfigLayer) -> Result<Option<TimeFormat>> {
+    get_value(
+        layer,
+        &[
+            "display.time-format",
+            "display.time_format",
+            "display-time-format",
+            "display_time_format",
+        ],
+    )
+    .map(|value| TimeFormat::from_str(value))
+    .transpose()
+}
+
 /// Determine whether human-readable output should use ANSI color.
 ///
 /// Precedence:
 /// 1) Config `display.color` (if set)
 /// 2) `NO_COLOR` environment variable (standard)
-/// 3) stdout is a terminal
+/// 3) stdout is a terminal (or the pager's pipe)
 #[must_use]
 pub fn should_use_color(layer: &ConfigLayer) -> bool {
     if let Some(value) = display_color_from_layer(layer) {
@@ -870,20 +925,35 @@ pub fn should_use_color(layer: &ConfigLayer) -> bool {
     if env::var_os("NO_COLOR").is_some() {
         return false;
     }
-    std::io::stdout().is_terminal()
+    stdout_is_terminal()
 }
 
-/// Resolve external project mappings from config.
+/// Expand `${workspace}` and `${home}` variables in a configured path.
 ///
-/// Supports `external_projects.<name>` or `external-projects.<name>` keys.
-/// Relative paths are resolved against the project root (parent of `.beads`).
+/// `${workspace}` is the project root (parent of `.beads`) and `${home}` is
+/// `$HOME`. Returns `None` if `${home}` is used but `HOME` is not set.
 #[must_use]
-pub fn external_projects_from_layer(
-    layer: &ConfigLayer,
-    beads_dir: &Path,
-) -> HashMap<String, PathBuf> {
+pub fn expand_path_variables(raw: &str, workspace: &Path) -> Option<String> {
+    let mut expanded = raw.replace("${workspace}", &workspace.to_string_lossy());
+    if expanded.contains("${home}") {
+        let home = env::var("HOME").ok()?;
+        expanded = expanded.replace("${home}", &home);
+    }
+    Some(expanded)
+}
+
+/// An `external_projects.<name>` entry with its configured and resolved path.
+struct ExternalProject {
+    name: String,
+    raw: String,
+    path: PathBuf,
+    /// Whether `raw` contained variables that were expanded.
+    expanded: bool,
+}
+
+fn external_project_entries(layer: &ConfigLayer, beads_dir: &Path) -> Vec<ExternalProject> {
     let base_dir = beads_dir.parent().unwrap_or(beads_dir);
-    let mut map = HashMap::new();
+    let mut entries = Vec::new();
     let iter = layer.runtime.iter().chain(layer.startup.iter());
 
     for (key, value) in iter {
@@ -899,64 +969,174 @@ pub fn external_projects_from_layer(
             continue;
         };
 
-        let path = PathBuf::from(value.trim());
+        let raw = value.trim();
+        let Some(expanded) = expand_path_variables(raw, base_dir) else {
+            warn!(
+                project = %project.trim(),
+                path = %raw,
+                "External project path uses ${{home}} but HOME is not set"
+            );
+            continue;
+        };
+        let was_expanded = expanded != raw;
+        let path = PathBuf::from(expanded);
         let resolved = if path.is_absolute() {
             path
         } else {
             base_dir.join(path)
         };
-        map.insert(project.trim().to_string(), resolved);
+        entries.push(ExternalProject {
+            name: project.trim().to_string(),
+            raw: raw.to_string(),
+            path: resolved,
+            expanded: was_expanded,
+        });
     }
 
-    map
+    entries
 }
 
-/// Resolve external project DB paths from config.
+/// Resolve external project mappings from config.
 ///
-/// Projects are expected to be either a `.beads` directory or a project root
-/// containing `.beads/`.
+/// Supports `external_projects.<name>` or `external-projects.<name>` keys.
+/// Paths may use `${workspace}` and `${home}` (see [`expand_path_variables`]).
+/// Relative paths are resolved against the project root (parent of `.beads`).
 #[must_use]
-pub fn external_project_db_paths(
+pub fn external_projects_from_layer(
     layer: &ConfigLayer,
     beads_dir: &Path,
 ) -> HashMap<String, PathBuf> {
-    let projects = external_projects_from_layer(layer, beads_dir);
-    let mut db_paths = HashMap::new();
+    external_project_entries(layer, beads_dir)
+        .into_iter()
+        .map(|project| (project.name, project.path))
+        .collect()
+}
 
-    for (name, path) in projects {
+/// An external project whose configured path does not lead to a beads database.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnresolvedExternalProject {
+    /// Project name (the `<name>` in `external_projects.<name>`).
+    pub name: String,
+    /// Path as written in config, before variable expansion.
+    pub raw: String,
+    /// The `.beads` directory the path resolved to.
+    pub path: PathBuf,
+    /// Why the project could not be resolved.
+    pub reason: String,
+}
+
+impl std::fmt::Display for UnresolvedExternalProject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "external_projects.{} ('{}') resolves to {}: {}",
+            self.name,
+            self.raw,
+            self.path.display(),
+            self.reason
+        )
+    }
+}
+
+/// External project DB paths resolved from config.
+#[derive(Debug, Clone, Default)]
+pub struct ExternalProjectDbPaths {
+    /// Project name to database path.
+    pub resolved: HashMap<String, PathBuf>,
+    /// Projects whose path is missing or has no database, sorted by name.
+    pub unresolved: Vec<UnresolvedExternalProject>,
+}
+
+impl ExternalProjectDbPaths {
+    /// Print an error to stderr for every unresolved project.
+    pub fn report_unresolved(&self) {
+        for project in &self.unresolved {
+            eprintln!("Error: {project}");
+        }
+    }
+}
+
+/// Resolve external project DB paths from config.
+///
+/// Projects are expected to be either a `.beads` directory or a project root
+/// containing `.beads/`. Projects that do not resolve are returned in
+/// [`ExternalProjectDbPaths::unresolved`] so callers can report them.
+#[must_use]
+pub fn external_project_db_paths(layer: &ConfigLayer, beads_dir: &Path) -> ExternalProjectDbPaths {
+    let mut db_paths = ExternalProjectDbPaths::default();
+
+    for project in external_project_entries(layer, beads_dir) {
+        let path = &project.path;
         let beads_path = if path.file_name().is_some_and(|name| name == ".beads") {
             path.clone()
         } else {
             path.join(".beads")
         };
 
-        if !beads_path.is_dir() {
-            warn!(
-                project = %name,
-                path = %beads_path.display(),
-                "External project .beads directory not found"
-            );
-            continue;
-        }
-
-        match ConfigPaths::resolve(&beads_path, None) {
-            Ok(paths) => {
-                db_paths.insert(name, paths.db_path);
-            }
-            Err(err) => {
-                warn!(
-                    project = %name,
-                    path = %beads_path.display(),
-                    error = %err,
-                    "Failed to resolve external project DB path"
-                );
+        let reason = if beads_path.is_dir() {
+            match ConfigPaths::resolve(&beads_path, None) {
+                Ok(paths) => {
+                    db_paths.resolved.insert(project.name, paths.db_path);
+                    continue;
+                }
+                Err(err) => format!("failed to resolve database path: {err}"),
             }
-        }
+        } else if project.expanded {
+            "directory does not exist (after variable expansion)".to_string()
+        } else {
+            "directory does not exist".to_string()
+        };
+        db_paths.unresolved.push(UnresolvedExternalProject {
+            name: project.name,
+            raw: project.raw,
+            path: beads_path,
+            reason,
+        });
     }
 
+    db_paths.unresolved.sort_by(|a, b| a.name.cmp(&b.name));
     db_paths
 }
 
+/// Resolve how long resolved external dependency statuses may be reused.
+///
+/// Accepts keys: `external-cache-ttl`, `external_cache_ttl` (seconds).
+/// Returns `None` when unset, zero, or invalid, which disables the cache.
+#[must_use]
+pub fn external_cache_ttl_from_layer(layer: &ConfigLayer) -> Option<Duration> {
+    get_value(layer, &["external-cache-ttl", "external_cache_ttl"])
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .filter(|secs| *secs > 0)
+        .map(Duration::from_secs)
+}
+
+/// Find keys that shadow each other after normalization.
+///
+/// Keys like `issue_prefix` and `issue-prefix` normalize to the same key, so
+/// only one of them takes effect. Returns `(normalized, spellings)` pairs
+/// sorted by normalized key.
+#[must_use]
+pub fn shadowed_keys(layer: &ConfigLayer) -> Vec<(String, Vec<String>)> {
+    let mut groups: HashMap<String, Vec<String>> = HashMap::new();
+    for key in layer.startup.keys().chain(layer.runtime.keys()) {
+        groups
+            .entry(normalize_key(key))
+            .or_default()
+            .push(key.clone());
+    }
+
+    let mut shadowed: Vec<(String, Vec<String>)> = groups
+        .into_iter()
+        .filter(|(_, spellings)| spellings.len() > 1)
+        .map(|(normalized, mut spellings)| {
+            spellings.sort();
+            (normalized, spellings)
+        })
+        .collect();
+    shadowed.sort_by(|a, b| a.0.cmp(&b.0));
+    shadowed
+}
+
 /// Resolve actor from a merged config layer.
 #[must_use]
 pub fn actor_from_layer(layer: &ConfigLayer) -> Option<String> {
@@ -1027,7 +1207,8 @@ fn insert_key_value(layer: &mut ConfigLayer, key: &str, value: String) {
     }
 }
 
-fn normalize_key(key: &str) -> String {
+/// Normalize a config key so hyphen/underscore and case variants compare equal.
+pub(crate) fn normalize_key(key: &str) -> String {
     key.trim().to_lowercase().replace('_', "-")
 }
 
@@ -1258,6 +1439,27 @@ labels:
         assert!((config.max_collision_prob - 0.5).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn resolver_config_reads_strict_prefix() {
+        let mut layer = ConfigLayer::default();
+        assert!(!resolver_config_from_layer(&layer).strict_prefix);
+
+        layer
+            .runtime
+            .insert("issue_prefix".to_string(), "br".to_string());
+        layer
+            .runtime
+            .insert("strict-prefix".to_string(), "true".to_string());
+        layer
+            .runtime
+            .insert("allowed-prefixes".to_string(), "Ops, legacy".to_string());
+
+        let config = resolver_config_from_layer(&layer);
+        assert_eq!(config.default_prefix, "br");
+        assert!(config.strict_prefix);
+        assert_eq!(config.allowed_prefixes, vec!["ops", "legacy"]);
+    }
+
     #[test]
     fn default_priority_from_layer_uses_config_value() {
         let mut layer = ConfigLayer::default();
@@ -1571,6 +1773,118 @@ labels:
         assert!(variants.contains(&"no-auto-flush".to_string()));
     }
 
+    #[test]
+    fn external_projects_expand_workspace_variable() {
+        let temp = TempDir::new().expect("tempdir");
+        let beads_dir = temp.path().join("repo").join(".beads");
+        fs::create_dir_all(&beads_dir).expect("create beads dir");
+
+        let mut layer = ConfigLayer::default();
+        layer.startup.insert(
+            "external_projects.other".to_string(),
+            "${workspace}/../other".to_string(),
+        );
+
+        let projects = external_projects_from_layer(&layer, &beads_dir);
+        assert_eq!(
+            projects.get("other"),
+            Some(&temp.path().join("repo").join("../other"))
+        );
+    }
+
+    #[test]
+    fn external_project_db_paths_report_missing_expanded_path() {
+        let temp = TempDir::new().expect("tempdir");
+        let beads_dir = temp.path().join("repo").join(".beads");
+        fs::create_dir_all(&beads_dir).expect("create beads dir");
+        let other_beads = temp.path().join("other").join(".beads");
+        fs::create_dir_all(&other_beads).expect("create other beads dir");
+
+        let mut layer = ConfigLayer::default();
+        layer.startup.insert(
+            "external_projects.other".to_string(),
+            "${workspace}/../other".to_string(),
+        );
+        layer.startup.insert(
+            "external_projects.missing".to_string(),
+            "${workspace}/../missing".to_string(),
+        );
+
+        let db_paths = external_project_db_paths(&layer, &beads_dir);
+        assert!(db_paths.resolved.contains_key("other"));
+        assert!(!db_paths.resolved.contains_key("missing"));
+        assert_eq!(db_paths.unresolved.len(), 1);
+        let missing = &db_paths.unresolved[0];
+        assert_eq!(missing.name, "missing");
+        assert_eq!(missing.raw, "${workspace}/../missing");
+        assert_eq!(
+            missing.path,
+            temp.path().join("repo").join("../missing").join(".beads")
+        );
+        let message = missing.to_string();
+        assert!(message.contains("external_projects.missing"));
+        assert!(message.contains(&missing.path.display().to_string()));
+        assert!(message.contains("after variable expansion"));
+    }
+
+    #[test]
+    fn external_project_entries_track_expansion() {
+        let temp = TempDir::new().expect("tempdir");
+        let beads_dir = temp.path().join("repo").join(".beads");
+
+        let mut layer = ConfigLayer::default();
+        layer.startup.insert(
+            "external_projects.plain".to_string(),
+            "../plain".to_string(),
+        );
+        layer.startup.insert(
+            "external_projects.vars".to_string(),
+            "${workspace}/../vars".to_string(),
+        );
+
+        let mut entries = external_project_entries(&layer, &beads_dir);
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+        assert_eq!(entries[0].name, "plain");
+        assert!(!entries[0].expanded);
+        assert_eq!(entries[1].raw, "${workspace}/../vars");
+        assert!(entries[1].expanded);
+    }
+
+    #[test]
+    fn expand_path_variables_leaves_plain_paths() {
+        let workspace = Path::new("/work/repo");
+        assert_eq!(
+            expand_path_variables("../other", workspace).as_deref(),
+            Some("../other")
+        );
+        assert_eq!(
+            expand_path_variables("${workspace}/sub", workspace).as_deref(),
+            Some("/work/repo/sub")
+        );
+    }
+
+    #[test]
+    fn shadowed_keys_groups_normalized_spellings() {
+        let mut layer = ConfigLayer::default();
+        layer
+            .runtime
+            .insert("issue_prefix".to_string(), "bd".to_string());
+        layer
+            .runtime
+            .insert("issue-prefix".to_string(), "br".to_string());
+        layer
+            .runtime
+            .insert("default-priority".to_string(), "2".to_string());
+
+        let shadowed = shadowed_keys(&layer);
+        assert_eq!(shadowed.len(), 1);
+        assert_eq!(shadowed[0].0, "issue-prefix");
+        assert_eq!(
+            shadowed[0].1,
+            vec!["issue-prefix".to_string(), "issue_prefix".to_string()]
+        );
+    }
+
     #[test]
     fn normalize_key_handles_various_formats() {
         assert_eq!(normalize_key("ISSUE_PREFIX"), "issue-prefix");
@@ -1741,6 +2055,7 @@ labels:
             no_auto_import: Some(true),
             lock_timeout: Some(5000),
             identity: None,
+            time_format: None,
         };
 
         let layer = cli.as_layer();
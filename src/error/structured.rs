@@ -299,8 +299,9 @@ impl StructuredError {
     /// Create a structured error for ambiguous ID.
     #[must_use]
     pub fn ambiguous_id(partial: &str, matches: &[String]) -> Self {
+        let recommended_len = crate::util::id::min_unique_hash_len(matches);
         let hint = Some(format!(
-            "Provide more characters to disambiguate. Matches: {}",
+            "Provide at least {recommended_len} hash characters to disambiguate. Matches: {}",
             matches.join(", ")
         ));
 
@@ -308,6 +309,7 @@ impl StructuredError {
             "partial_id": partial,
             "matches": matches,
             "match_count": matches.len(),
+            "recommended_hash_len": recommended_len,
         });
 
         Self {
@@ -504,7 +506,11 @@ impl StructuredError {
             }
             BeadsError::AmbiguousId { partial, matches } => (
                 ErrorCode::AmbiguousId,
-                Some(json!({"partial_id": partial, "matches": matches})),
+                Some(json!({
+                    "partial_id": partial,
+                    "matches": matches,
+                    "recommended_hash_len": crate::util::id::min_unique_hash_len(matches),
+                })),
             ),
             BeadsError::IdCollision { id } => (ErrorCode::IdCollision, Some(json!({"id": id}))),
             BeadsError::InvalidId { id } => (ErrorCode::InvalidId, Some(json!({"id": id}))),
@@ -1027,6 +1033,8 @@ mod tests {
         assert_eq!(err.code, ErrorCode::AmbiguousId);
         assert!(err.retryable);
         assert!(err.context.as_ref().unwrap()["matches"].is_array());
+        assert_eq!(err.context.as_ref().unwrap()["recommended_hash_len"], 3);
+        assert!(err.hint.as_ref().unwrap().contains("at least 3"));
     }
 
     #[test]
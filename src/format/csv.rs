@@ -1,7 +1,8 @@
-//! CSV formatting for `beads_rust`.
+//! CSV and TSV formatting for `beads_rust`.
 //!
 //! Provides CSV output for list/export commands. Handles proper escaping
-//! of fields containing commas, quotes, or newlines.
+//! of fields containing commas, quotes, or newlines. TSV output shares the
+//! same field definitions and replaces tabs and newlines inside values.
 
 use crate::model::Issue;
 use std::io::{self, Write};
@@ -143,6 +144,37 @@ pub fn format_csv(issues: &[Issue], fields: &[&str]) -> String {
     String::from_utf8_lossy(&output).into_owned()
 }
 
+/// Sanitize a TSV field value.
+///
+/// Tabs, carriage returns and newlines are replaced with spaces so each
+/// issue stays on a single row.
+#[must_use]
+pub fn escape_tsv_field(value: &str) -> String {
+    value.replace(['\t', '\r', '\n'], " ")
+}
+
+/// Format a single issue as a TSV row.
+#[must_use]
+pub fn format_issue_tsv_row(issue: &Issue, fields: &[&str]) -> String {
+    fields
+        .iter()
+        .map(|&field| escape_tsv_field(&get_field_value(issue, field)))
+        .collect::<Vec<_>>()
+        .join("\t")
+}
+
+/// Format issues as a complete TSV string with a header row.
+#[must_use]
+pub fn format_tsv(issues: &[Issue], fields: &[&str]) -> String {
+    let mut output = fields.join("\t");
+    output.push('\n');
+    for issue in issues {
+        output.push_str(&format_issue_tsv_row(issue, fields));
+        output.push('\n');
+    }
+    output
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -285,6 +317,17 @@ mod tests {
         assert_eq!(lines[2], "bd-2,Second");
     }
 
+    #[test]
+    fn test_format_tsv_strips_tabs_and_newlines() {
+        let mut issue = make_test_issue("bd-1", "Tab\there");
+        issue.assignee = Some("line1\nline2".to_string());
+        let tsv = format_tsv(&[issue], &["id", "title", "assignee"]);
+        let lines: Vec<&str> = tsv.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], "id\ttitle\tassignee");
+        assert_eq!(lines[1], "bd-1\tTab here\tline1 line2");
+    }
+
     #[test]
     fn test_write_header() {
         let mut output = Vec::new();
@@ -17,6 +17,7 @@
 //! The [`csv`] module provides CSV formatting with:
 //! - Configurable field selection via `--fields`
 //! - Proper escaping of commas, quotes, and newlines
+//! - TSV output sharing the same fields (`--format tsv`)
 //!
 //! # Rich Output
 //!
@@ -43,14 +44,14 @@ pub mod theme;
 
 pub use output::{
     BlockedIssue, BlockedIssueOutput, Breakdown, BreakdownEntry, IssueDetails, IssueWithCounts,
-    IssueWithDependencyMetadata, ReadyIssue, RecentActivity, StaleIssue, Statistics, StatsSummary,
-    TreeNode,
+    IssueWithDependencyMetadata, OldestOpenIssue, ReadyIssue, RecentActivity, StaleIssue,
+    Statistics, StatsSummary, TreeNode,
 };
 pub use text::{
-    TextFormatOptions, format_issue_line, format_issue_line_with, format_priority,
+    TextFormatOptions, TimeFormat, format_issue_line, format_issue_line_with, format_priority,
     format_priority_badge, format_priority_label, format_status_icon, format_status_icon_colored,
-    format_status_label, format_type_badge, format_type_badge_colored, terminal_width,
-    truncate_title,
+    format_status_label, format_timestamp, format_timestamp_or, format_type_badge,
+    format_type_badge_colored, terminal_width, truncate_title,
 };
 
 // Rich output support
@@ -173,6 +173,16 @@ pub struct StatsSummary {
     pub epics_eligible_for_closure: usize,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub average_lead_time_hours: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oldest_open_issue: Option<OldestOpenIssue>,
+}
+
+/// The longest-standing non-closed issue in the project.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct OldestOpenIssue {
+    pub id: String,
+    pub title: String,
+    pub created_at: DateTime<Utc>,
 }
 
 /// Breakdown statistics by a dimension.
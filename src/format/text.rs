@@ -6,7 +6,9 @@
 //! - Type badges ([bug], [feature], etc.)
 //! - Issue line formatting
 
+use crate::error::{BeadsError, Result};
 use crate::model::{Issue, IssueType, Priority, Status};
+use chrono::{DateTime, Local, Utc};
 use crossterm::style::Stylize;
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
@@ -281,10 +283,88 @@ pub fn format_issue_line(issue: &Issue) -> String {
     format_issue_line_with(issue, TextFormatOptions::plain())
 }
 
+/// How timestamps are rendered in human-readable output.
+///
+/// Machine formats (JSON/TOON) always use RFC3339 regardless of this setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeFormat {
+    /// RFC3339 in UTC, e.g. `2026-01-05T14:03:00Z`.
+    Rfc3339,
+    /// Relative to now, e.g. `3d ago` or `in 2h`.
+    Relative,
+    /// Local timezone, e.g. `2026-01-05 15:03`.
+    Local,
+}
+
+impl std::str::FromStr for TimeFormat {
+    type Err = BeadsError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "rfc3339" | "iso" | "iso8601" => Ok(Self::Rfc3339),
+            "relative" | "rel" => Ok(Self::Relative),
+            "local" => Ok(Self::Local),
+            other => Err(BeadsError::validation(
+                "time-format",
+                format!("unknown time format '{other}' (expected rfc3339, relative, or local)"),
+            )),
+        }
+    }
+}
+
+/// Format a timestamp according to `format`, using `now` as the reference for
+/// relative output.
+#[must_use]
+pub fn format_timestamp(dt: &DateTime<Utc>, format: TimeFormat, now: DateTime<Utc>) -> String {
+    match format {
+        TimeFormat::Rfc3339 => dt.to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+        TimeFormat::Local => dt
+            .with_timezone(&Local)
+            .format("%Y-%m-%d %H:%M")
+            .to_string(),
+        TimeFormat::Relative => {
+            let delta = now.signed_duration_since(*dt);
+            let secs = delta.num_seconds().abs();
+            if secs < 60 {
+                return "just now".to_string();
+            }
+            let span = if secs < 3600 {
+                format!("{}m", secs / 60)
+            } else if secs < 86_400 {
+                format!("{}h", secs / 3600)
+            } else if secs < 86_400 * 60 {
+                format!("{}d", secs / 86_400)
+            } else if secs < 86_400 * 365 {
+                format!("{}mo", secs / (86_400 * 30))
+            } else {
+                format!("{}y", secs / (86_400 * 365))
+            };
+            if delta.num_seconds() < 0 {
+                format!("in {span}")
+            } else {
+                format!("{span} ago")
+            }
+        }
+    }
+}
+
+/// Format a timestamp with the `--time-format` in effect, falling back to the
+/// command's own `default` (a `chrono` format string) when none is set.
+#[must_use]
+pub fn format_timestamp_or(
+    dt: &DateTime<Utc>,
+    format: Option<TimeFormat>,
+    default: &str,
+) -> String {
+    format.map_or_else(
+        || dt.format(default).to_string(),
+        |fmt| format_timestamp(dt, fmt, Utc::now()),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::Utc;
 
     fn make_test_issue() -> Issue {
         Issue {
@@ -464,4 +544,50 @@ mod tests {
         assert!(!line.contains("..."));
         assert!(line.contains("A very long issue title"));
     }
+
+    #[test]
+    fn test_format_timestamp_modes() {
+        let now = DateTime::parse_from_rfc3339("2026-01-10T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let past = DateTime::parse_from_rfc3339("2026-01-07T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let future = now + chrono::Duration::minutes(28);
+
+        assert_eq!(
+            format_timestamp(&past, TimeFormat::Rfc3339, now),
+            "2026-01-07T12:00:00Z"
+        );
+        assert_eq!(format_timestamp(&past, TimeFormat::Relative, now), "3d ago");
+        assert_eq!(
+            format_timestamp(&future, TimeFormat::Relative, now),
+            "in 28m"
+        );
+        assert_eq!(
+            format_timestamp(&now, TimeFormat::Relative, now),
+            "just now"
+        );
+        assert_eq!(
+            "relative".parse::<TimeFormat>().unwrap(),
+            TimeFormat::Relative
+        );
+        assert!("fancy".parse::<TimeFormat>().is_err());
+    }
+
+    #[test]
+    fn test_format_timestamp_or_falls_back_to_default() {
+        let dt = DateTime::parse_from_rfc3339("2026-01-07T12:34:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        assert_eq!(
+            format_timestamp_or(&dt, None, "%Y-%m-%d %H:%M UTC"),
+            "2026-01-07 12:34 UTC"
+        );
+        assert_eq!(
+            format_timestamp_or(&dt, Some(TimeFormat::Rfc3339), "%Y-%m-%d"),
+            "2026-01-07T12:34:00Z"
+        );
+    }
 }
@@ -1,5 +1,8 @@
 use beads_rust::cli::commands;
-use beads_rust::cli::{Cli, Commands};
+use beads_rust::cli::{
+    Cli, Commands, OutputFormat, ReadyOutputFormat, resolve_output_format,
+    resolve_output_format_basic,
+};
 use beads_rust::config;
 use beads_rust::logging::init_logging;
 use beads_rust::output::OutputContext;
@@ -13,6 +16,12 @@ use tracing::debug;
 #[allow(clippy::too_many_lines)]
 fn main() {
     let cli = Cli::parse();
+    if is_pageable_command(&cli.command) {
+        if let Some(code) = OutputContext::run_paged(&cli) {
+            std::process::exit(code);
+        }
+    }
+    OutputContext::exit_quietly_on_closed_pager();
     let output_ctx = OutputContext::from_args(&cli);
 
     // Initialize logging
@@ -21,6 +30,10 @@ fn main() {
         // Don't exit, just continue without logging or with basic stderr
     }
 
+    if cli.no_last_touched {
+        beads_rust::util::disable_last_touched_writes();
+    }
+
     let overrides = build_cli_overrides(&cli);
 
     // Track if this command potentially mutates data (for auto-flush)
@@ -37,7 +50,14 @@ fn main() {
             prefix,
             force,
             backend: _,
-        } => commands::init::execute(prefix, force, None, &output_ctx),
+            template,
+        } => commands::init::execute_with_template(
+            prefix,
+            force,
+            None,
+            template.as_deref(),
+            &output_ctx,
+        ),
         Commands::Create(args) => commands::create::execute(&args, &overrides, &output_ctx),
         Commands::Update(args) => commands::update::execute(&args, &overrides, &output_ctx),
         Commands::Delete(args) => {
@@ -75,7 +95,7 @@ fn main() {
             commands::blocked::execute(&args, cli.json || args.robot, &overrides, &output_ctx)
         }
         Commands::Sync(args) => commands::sync::execute(&args, cli.json, &overrides, &output_ctx),
-        Commands::Doctor => commands::doctor::execute(&overrides, &output_ctx),
+        Commands::Doctor(args) => commands::doctor::execute(&args, &overrides, &output_ctx),
         Commands::Info(args) => commands::info::execute(&args, &overrides, &output_ctx),
         Commands::Schema(args) => commands::schema::execute(&args, &overrides, &output_ctx),
         Commands::Where => commands::r#where::execute(&overrides, &output_ctx),
@@ -94,12 +114,16 @@ fn main() {
             commands::config::execute(&command, cli.json, &overrides, &output_ctx)
         }
         Commands::History(args) => commands::history::execute(args, &overrides, &output_ctx),
+        Commands::Gc(args) => commands::gc::execute(&args, &overrides, &output_ctx),
         Commands::Defer(args) => {
             commands::defer::execute_defer(&args, cli.json || args.robot, &overrides, &output_ctx)
         }
         Commands::Undefer(args) => {
             commands::defer::execute_undefer(&args, cli.json || args.robot, &overrides, &output_ctx)
         }
+        Commands::Reassign(args) => {
+            commands::reassign::execute(&args, cli.json || args.robot, &overrides, &output_ctx)
+        }
         Commands::Orphans(args) => {
             commands::orphans::execute(&args, cli.json || args.robot, &overrides, &output_ctx)
         }
@@ -123,6 +147,9 @@ fn main() {
 
     // Handle command result
     if let Err(e) = result {
+        if matches!(&e, BeadsError::Io(err) if OutputContext::is_closed_pager(err)) {
+            std::process::exit(0);
+        }
         handle_error(&e, cli.json);
     }
 
@@ -145,7 +172,8 @@ const fn is_mutating_command(cmd: &Commands) -> bool {
         | Commands::Label { .. }
         | Commands::Comments(_)
         | Commands::Defer(_)
-        | Commands::Undefer(_) => true,
+        | Commands::Undefer(_)
+        | Commands::Reassign(_) => true,
         Commands::Epic { command } => matches!(
             command,
             beads_rust::cli::EpicCommands::CloseEligible(args) if !args.dry_run
@@ -181,16 +209,18 @@ const fn should_auto_import(cmd: &Commands) -> bool {
         | Commands::Q(_)
         | Commands::Defer(_)
         | Commands::Undefer(_)
+        | Commands::Reassign(_)
         | Commands::Comments(_)
         | Commands::Dep { .. }
         | Commands::Label { .. }
         | Commands::Epic { .. }
-        | Commands::Query { .. } => true,
+        | Commands::Query { .. }
+        | Commands::Gc(_) => true,
 
         // Explicitly excluded: init, sync, diagnostic, and config commands
         Commands::Init { .. }
         | Commands::Sync(_)
-        | Commands::Doctor
+        | Commands::Doctor(_)
         | Commands::Info(_)
         | Commands::Schema(_)
         | Commands::Where
@@ -206,6 +236,40 @@ const fn should_auto_import(cmd: &Commands) -> bool {
     }
 }
 
+/// Commands whose text output can run long enough to benefit from a pager.
+///
+/// Machine-readable output (`--format json|toon|csv|tsv`, `--robot`,
+/// `--json-stream`, `--jsonl`, or `BR_OUTPUT_FORMAT`) is never paged.
+fn is_pageable_command(cmd: &Commands) -> bool {
+    let text = |format: OutputFormat| format == OutputFormat::Text;
+    match cmd {
+        Commands::List(args) => {
+            !args.json_stream && text(resolve_output_format(args.format, false, false))
+        }
+        Commands::Search(args) => {
+            !args.filters.json_stream
+                && text(resolve_output_format(args.filters.format, false, false))
+        }
+        Commands::Show(args) => text(resolve_output_format_basic(args.format, false, false)),
+        Commands::Blocked(args) => {
+            text(resolve_output_format_basic(args.format, false, args.robot))
+        }
+        Commands::Orphans(args) => !args.robot,
+        Commands::Changelog(args) => !args.robot,
+        Commands::Ready(args) => {
+            !args.watch
+                && !args.jsonl
+                && text(resolve_output_format_basic(
+                    args.format.map(ReadyOutputFormat::basic),
+                    false,
+                    args.robot,
+                ))
+        }
+        Commands::Stale(_) | Commands::Graph(_) | Commands::Lint(_) => true,
+        _ => false,
+    }
+}
+
 /// Run auto-import before read-only commands when JSONL is newer.
 fn run_auto_import(
     overrides: &config::CliOverrides,
@@ -334,6 +398,7 @@ fn build_cli_overrides(cli: &Cli) -> config::CliOverrides {
         no_auto_flush: Some(cli.no_auto_flush),
         no_auto_import: Some(cli.no_auto_import),
         lock_timeout: cli.lock_timeout,
+        time_format: cli.time_format.clone(),
     }
 }
 
@@ -427,4 +492,51 @@ mod tests {
         assert!(is_mutating_command(&create_cmd));
         assert!(!is_mutating_command(&list_cmd));
     }
+
+    #[test]
+    fn pageable_commands_exclude_machine_output() {
+        let pageable = |args: &[&str]| is_pageable_command(&Cli::parse_from(args).command);
+        assert!(pageable(&["br", "show", "bd-1"]));
+        assert!(!pageable(&["br", "list", "--format", "json"]));
+        assert!(!pageable(&["br", "list", "--format", "csv"]));
+        assert!(!pageable(&["br", "list", "--json-stream"]));
+        assert!(!pageable(&["br", "ready", "--robot"]));
+        assert!(!pageable(&["br", "ready", "--jsonl"]));
+        assert!(!pageable(&["br", "blocked", "--robot"]));
+        assert!(!pageable(&["br", "create", "x"]));
+    }
+
+    #[test]
+    fn pager_disabled_when_stdout_is_not_a_tty() {
+        let cli = Cli::parse_from(["br", "list"]);
+        assert_eq!(OutputContext::pager_for(&cli, false, None, None), None);
+        assert_eq!(
+            OutputContext::pager_for(&cli, true, None, None).as_deref(),
+            Some("less")
+        );
+    }
+
+    #[test]
+    fn pager_disabled_for_json_quiet_and_no_pager() {
+        for flag in ["--json", "--quiet", "--no-pager"] {
+            let cli = Cli::parse_from(["br", flag, "list"]);
+            assert_eq!(
+                OutputContext::pager_for(&cli, true, Some("less"), Some("more")),
+                None,
+                "{flag} should disable paging"
+            );
+        }
+    }
+
+    #[test]
+    fn pager_prefers_beads_pager_then_pager() {
+        let cli = Cli::parse_from(["br", "list"]);
+        let pager = |beads: Option<&str>, pager: Option<&str>| {
+            OutputContext::pager_for(&cli, true, beads, pager)
+        };
+        assert_eq!(pager(Some("most"), Some("more")).as_deref(), Some("most"));
+        assert_eq!(pager(None, Some("more -s")).as_deref(), Some("more -s"));
+        assert_eq!(pager(Some(""), Some("more")), None);
+        assert_eq!(pager(None, Some("cat")), None);
+    }
 }
@@ -1,4 +1,4 @@
-use crate::format::{IssueDetails, IssueWithDependencyMetadata};
+use crate::format::{IssueDetails, IssueWithDependencyMetadata, TimeFormat, format_timestamp_or};
 use crate::model::{Comment, Dependency, Issue};
 use crate::output::{OutputContext, Theme};
 use rich_rust::prelude::*;
@@ -11,6 +11,7 @@ pub struct IssuePanel<'a> {
     show_dependencies: bool,
     show_dependents: bool,
     show_comments: bool,
+    time_format: Option<TimeFormat>,
 }
 
 impl<'a> IssuePanel<'a> {
@@ -23,6 +24,7 @@ impl<'a> IssuePanel<'a> {
             show_dependencies: true,
             show_dependents: true,
             show_comments: true,
+            time_format: None,
         }
     }
 
@@ -35,6 +37,7 @@ impl<'a> IssuePanel<'a> {
             show_dependencies: true,
             show_dependents: true,
             show_comments: true,
+            time_format: None,
         }
     }
 
@@ -56,6 +59,17 @@ impl<'a> IssuePanel<'a> {
         self
     }
 
+    /// Override how the created/updated timestamps are rendered.
+    #[must_use]
+    pub fn time_format(mut self, format: Option<TimeFormat>) -> Self {
+        self.time_format = format;
+        self
+    }
+
+    fn format_time(&self, dt: &chrono::DateTime<chrono::Utc>) -> String {
+        format_timestamp_or(dt, self.time_format, "%Y-%m-%d %H:%M")
+    }
+
     pub fn print(&self, ctx: &OutputContext, wrap: bool) {
         let mut content = Text::new("");
 
@@ -115,13 +129,13 @@ impl<'a> IssuePanel<'a> {
         // Timestamps
         content.append_styled("Created:  ", self.theme.dimmed.clone());
         content.append_styled(
-            &format!("{}\n", self.issue.created_at.format("%Y-%m-%d %H:%M")),
+            &format!("{}\n", self.format_time(&self.issue.created_at)),
             self.theme.timestamp.clone(),
         );
 
         content.append_styled("Updated:  ", self.theme.dimmed.clone());
         content.append_styled(
-            &format!("{}\n", self.issue.updated_at.format("%Y-%m-%d %H:%M")),
+            &format!("{}\n", self.format_time(&self.issue.updated_at)),
             self.theme.timestamp.clone(),
         );
 
@@ -154,7 +168,11 @@ impl<'a> IssuePanel<'a> {
             for comment in comments {
                 content.append("  ");
                 content.append_styled(
-                    &comment.created_at.format("%Y-%m-%d %H:%M UTC").to_string(),
+                    &format_timestamp_or(
+                        &comment.created_at,
+                        self.time_format,
+                        "%Y-%m-%d %H:%M UTC",
+                    ),
                     self.theme.timestamp.clone(),
                 );
                 content.append(" ");
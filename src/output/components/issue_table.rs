@@ -1,4 +1,4 @@
-use crate::format::truncate_title;
+use crate::format::{TimeFormat, format_timestamp_or, truncate_title};
 use crate::model::Issue;
 use crate::output::Theme;
 use regex::{Regex, RegexBuilder};
@@ -16,6 +16,7 @@ pub struct IssueTable<'a> {
     context_snippets: Option<HashMap<String, String>>,
     width: Option<usize>,
     wrap: bool,
+    time_format: Option<TimeFormat>,
 }
 
 #[derive(Default, Clone)]
@@ -87,6 +88,7 @@ impl<'a> IssueTable<'a> {
             context_snippets: None,
             width: None,
             wrap: false,
+            time_format: None,
         }
     }
 
@@ -102,6 +104,13 @@ impl<'a> IssueTable<'a> {
         self
     }
 
+    /// Override how the created/updated columns are rendered.
+    #[must_use]
+    pub fn time_format(mut self, format: Option<TimeFormat>) -> Self {
+        self.time_format = format;
+        self
+    }
+
     #[must_use]
     pub fn columns(mut self, columns: IssueTableColumns) -> Self {
         self.columns = columns;
@@ -234,14 +243,22 @@ impl<'a> IssueTable<'a> {
             }
             if self.columns.created {
                 cells.push(
-                    Cell::new(Text::new(issue.created_at.format("%Y-%m-%d").to_string()))
-                        .style(self.theme.timestamp.clone()),
+                    Cell::new(Text::new(format_timestamp_or(
+                        &issue.created_at,
+                        self.time_format,
+                        "%Y-%m-%d",
+                    )))
+                    .style(self.theme.timestamp.clone()),
                 );
             }
             if self.columns.updated {
                 cells.push(
-                    Cell::new(Text::new(issue.updated_at.format("%Y-%m-%d").to_string()))
-                        .style(self.theme.timestamp.clone()),
+                    Cell::new(Text::new(format_timestamp_or(
+                        &issue.updated_at,
+                        self.time_format,
+                        "%Y-%m-%d",
+                    )))
+                    .style(self.theme.timestamp.clone()),
                 );
             }
             if self.columns.context {
@@ -3,10 +3,29 @@ use crate::cli::{Cli, OutputFormat};
 use rich_rust::prelude::*;
 use rich_rust::renderables::Renderable;
 use std::io::{self, IsTerminal, Write};
+use std::process::{Command, Stdio};
 use std::sync::OnceLock;
 use toon_rust::options::KeyFoldingMode;
 use toon_rust::{EncodeOptions, JsonValue, encode};
 
+/// Set on the process re-run under a pager; its stdout is the pager's stdin.
+const PAGED_ENV: &str = "BR_PAGED";
+
+/// Whether stdout is a terminal, counting a pipe into our own pager as one.
+pub(crate) fn stdout_is_terminal() -> bool {
+    io::stdout().is_terminal() || std::env::var_os(PAGED_ENV).is_some()
+}
+
+/// Pick `BEADS_PAGER`, then `PAGER`, then `less`; empty or `cat` disables paging.
+fn resolve_pager(beads_pager: Option<&str>, pager: Option<&str>) -> Option<String> {
+    let pager = beads_pager.or(pager).unwrap_or("less").trim();
+    if pager.is_empty() || pager == "cat" {
+        None
+    } else {
+        Some(pager.to_string())
+    }
+}
+
 /// Central output coordinator that respects robot/json/quiet modes.
 ///
 /// Uses lazy initialization for console and theme to ensure zero overhead
@@ -61,8 +80,7 @@ impl OutputContext {
             OutputMode::Json
         } else if quiet {
             OutputMode::Quiet
-        } else if no_color || std::env::var("NO_COLOR").is_ok() || !std::io::stdout().is_terminal()
-        {
+        } else if no_color || std::env::var("NO_COLOR").is_ok() || !stdout_is_terminal() {
             OutputMode::Plain
         } else {
             OutputMode::Rich
@@ -82,13 +100,10 @@ impl OutputContext {
         let mode = match format {
             OutputFormat::Json => OutputMode::Json,
             OutputFormat::Toon => OutputMode::Toon,
-            OutputFormat::Text | OutputFormat::Csv => {
+            OutputFormat::Text | OutputFormat::Csv | OutputFormat::Tsv => {
                 if quiet {
                     OutputMode::Quiet
-                } else if no_color
-                    || std::env::var("NO_COLOR").is_ok()
-                    || !std::io::stdout().is_terminal()
-                {
+                } else if no_color || std::env::var("NO_COLOR").is_ok() || !stdout_is_terminal() {
                     OutputMode::Plain
                 } else {
                     OutputMode::Rich
@@ -114,15 +129,127 @@ impl OutputContext {
         if args.no_color || std::env::var("NO_COLOR").is_ok() {
             return OutputMode::Plain;
         }
-        if !std::io::stdout().is_terminal() {
+        if !stdout_is_terminal() {
             return OutputMode::Plain;
         }
         OutputMode::Rich
     }
 
+    /// Resolve the pager command from `BEADS_PAGER`, then `PAGER`, then `less`.
+    ///
+    /// Returns `None` when the configured pager is empty or `cat`.
+    #[must_use]
+    pub fn pager_command() -> Option<String> {
+        resolve_pager(
+            std::env::var("BEADS_PAGER").ok().as_deref(),
+            std::env::var("PAGER").ok().as_deref(),
+        )
+    }
+
+    /// Decide which pager, if any, should wrap the output of this invocation.
+    ///
+    /// Paging only applies when stdout is a TTY and neither `--json`, `--quiet`
+    /// nor `--no-pager` is set. `beads_pager` and `pager` are the values of
+    /// `BEADS_PAGER` and `PAGER`.
+    #[must_use]
+    pub fn pager_for(
+        args: &Cli,
+        stdout_is_tty: bool,
+        beads_pager: Option<&str>,
+        pager: Option<&str>,
+    ) -> Option<String> {
+        if args.json || args.quiet || args.no_pager || !stdout_is_tty {
+            return None;
+        }
+        resolve_pager(beads_pager, pager)
+    }
+
+    /// Re-run the current invocation with stdout piped through the pager.
+    ///
+    /// See [`Self::pager_for`] for when paging applies. `LESS` defaults to
+    /// `FRX`, so output that fits on one screen is printed directly. The
+    /// re-run process gets `BR_PAGED` and the terminal width in `COLUMNS`, so
+    /// it keeps rich output and does not page again.
+    ///
+    /// Returns the exit code of the re-run process, or `None` if paging was
+    /// skipped or could not be set up and the caller should produce output
+    /// itself.
+    #[must_use]
+    pub fn run_paged(args: &Cli) -> Option<i32> {
+        if std::env::var_os(PAGED_ENV).is_some() {
+            return None;
+        }
+        let pager = Self::pager_for(
+            args,
+            io::stdout().is_terminal(),
+            std::env::var("BEADS_PAGER").ok().as_deref(),
+            std::env::var("PAGER").ok().as_deref(),
+        )?;
+        let exe = std::env::current_exe().ok()?;
+        let mut parts = pager.split_whitespace();
+        let program = parts.next()?;
+        let width = Console::new().width();
+
+        let mut pager_cmd = Command::new(program);
+        pager_cmd.args(parts).stdin(Stdio::piped());
+        if std::env::var_os("LESS").is_none() {
+            pager_cmd.env("LESS", "FRX");
+        }
+        let mut pager_child = pager_cmd.spawn().ok()?;
+        let Some(pager_stdin) = pager_child.stdin.take() else {
+            let _ = pager_child.kill();
+            let _ = pager_child.wait();
+            return None;
+        };
+
+        let status = Command::new(exe)
+            .args(std::env::args_os().skip(1))
+            .env(PAGED_ENV, "1")
+            .env("COLUMNS", width.to_string())
+            .stdout(Stdio::from(pager_stdin))
+            .status();
+        // The pager sees EOF once the re-run process exits (or fails to start).
+        let _ = pager_child.wait();
+
+        status.ok().map(|status| status.code().unwrap_or(1))
+    }
+
+    /// In a process re-run under the pager, exit cleanly once the pager quits.
+    ///
+    /// Quitting the pager early closes our stdout, after which `println!`
+    /// panics with a broken pipe; exit 0 quietly instead, as with a default
+    /// `SIGPIPE` disposition.
+    pub fn exit_quietly_on_closed_pager() {
+        if std::env::var_os(PAGED_ENV).is_none() {
+            return;
+        }
+        let default_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            let payload = info.payload();
+            let message = payload
+                .downcast_ref::<String>()
+                .map(String::as_str)
+                .or_else(|| payload.downcast_ref::<&str>().copied())
+                .unwrap_or_default();
+            if message.to_ascii_lowercase().contains("broken pipe") {
+                std::process::exit(0);
+            }
+            default_hook(info);
+        }));
+    }
+
+    /// Whether `err` is a write to a pager that has already exited.
+    #[must_use]
+    pub fn is_closed_pager(err: &io::Error) -> bool {
+        err.kind() == io::ErrorKind::BrokenPipe && std::env::var_os(PAGED_ENV).is_some()
+    }
+
     /// Lazily create console based on mode.
     fn console(&self) -> &Console {
         self.console.get_or_init(|| match self.mode {
+            OutputMode::Rich if std::env::var_os(PAGED_ENV).is_some() => {
+                Console::builder().force_terminal(true).build()
+            }
             OutputMode::Rich => Console::new(),
             OutputMode::Plain | OutputMode::Quiet | OutputMode::Json | OutputMode::Toon => {
                 Console::builder().no_color().force_terminal(false).build()
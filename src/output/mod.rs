@@ -53,4 +53,5 @@ pub mod theme;
 
 pub use components::*;
 pub use context::{OutputContext, OutputMode};
+pub(crate) use context::stdout_is_terminal;
 pub use theme::Theme;
@@ -16,4 +16,9 @@ pub mod events;
 pub mod schema;
 pub mod sqlite;
 
-pub use sqlite::{IssueUpdate, ListFilters, ReadyFilters, ReadySortPolicy, SqliteStorage};
+pub use sqlite::{
+    ClaimOutcome, CompareOp, ExternalCachePolicy, GcPruneReport, IssueCountField, IssueUpdate,
+    LIST_SORT_FIELDS, LIST_WHERE_FIELDS, ListFilters, ListPredicate, ListSortKey, PredicateValue,
+    READY_PROJECTION_COLUMNS, ReadyFilters, ReadySortPolicy, SqliteStorage, parse_list_predicate,
+    parse_list_sort,
+};
@@ -5,6 +5,7 @@ use crate::format::{IssueDetails, IssueWithDependencyMetadata};
 use crate::model::{Comment, DependencyType, Event, EventType, Issue, IssueType, Priority, Status};
 use crate::storage::events::get_events;
 use crate::storage::schema::apply_schema;
+use crate::util::time::parse_flexible_timestamp;
 use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
 use rusqlite::{Connection, OpenFlags, OptionalExtension, Transaction};
 use std::collections::{HashMap, HashSet};
@@ -191,6 +192,217 @@ impl SqliteStorage {
         Ok(result)
     }
 
+    /// Atomically claim an issue for `actor` (assignee = actor, status = `in_progress`).
+    ///
+    /// Succeeds only if the issue is still `open`/`in_progress` and unassigned or
+    /// already assigned to `actor`. The previous assignee and status are read in
+    /// the same transaction, so the recorded events reflect what was replaced.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub fn claim_issue(&mut self, id: &str, actor: &str) -> Result<ClaimOutcome> {
+        self.mutate("claim_issue", actor, |tx, ctx| {
+            let current: Option<(String, Option<String>)> = tx
+                .query_row(
+                    "SELECT status, assignee FROM issues WHERE id = ?",
+                    [id],
+                    |row| Ok((row.get(0)?, row.get(1)?)),
+                )
+                .optional()?;
+            let Some((old_status, old_assignee)) = current else {
+                return Ok(ClaimOutcome::Unavailable);
+            };
+            let old_assignee = old_assignee.filter(|assignee| !assignee.is_empty());
+            if old_status == "in_progress" && old_assignee.as_deref() == Some(actor) {
+                return Ok(ClaimOutcome::AlreadyHeld);
+            }
+
+            let changed = tx.execute(
+                "UPDATE issues SET assignee = ?1, status = 'in_progress', updated_at = ?2
+                 WHERE id = ?3 AND status IN ('open', 'in_progress')
+                   AND (assignee IS NULL OR assignee = '' OR assignee = ?1)",
+                rusqlite::params![actor, Utc::now().to_rfc3339(), id],
+            )?;
+            if changed == 0 {
+                return Ok(ClaimOutcome::Unavailable);
+            }
+            if old_assignee.as_deref() != Some(actor) {
+                ctx.record_field_change(
+                    EventType::AssigneeChanged,
+                    id,
+                    old_assignee,
+                    Some(actor.to_string()),
+                    Some("Claimed".to_string()),
+                );
+            }
+            if old_status != "in_progress" {
+                ctx.record_field_change(
+                    EventType::StatusChanged,
+                    id,
+                    Some(old_status),
+                    Some("in_progress".to_string()),
+                    None,
+                );
+                ctx.invalidate_cache();
+            }
+            ctx.mark_dirty(id);
+            Ok(ClaimOutcome::Claimed)
+        })
+    }
+
+    /// Defer several issues in one transaction.
+    ///
+    /// Sets `status = deferred` and `defer_until = until` (`None` = indefinitely).
+    /// Returns the updated issues.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any issue is missing or the database update fails;
+    /// in that case no issue is changed.
+    pub fn defer_issues(
+        &mut self,
+        ids: &[String],
+        until: Option<DateTime<Utc>>,
+        actor: &str,
+    ) -> Result<Vec<Issue>> {
+        self.set_deferral("defer_issues", ids, &Status::Deferred, until, actor)
+    }
+
+    /// Undefer several issues in one transaction: reopen them and clear `defer_until`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any issue is missing or the database update fails;
+    /// in that case no issue is changed.
+    pub fn undefer_issues(&mut self, ids: &[String], actor: &str) -> Result<Vec<Issue>> {
+        self.set_deferral("undefer_issues", ids, &Status::Open, None, actor)
+    }
+
+    fn set_deferral(
+        &mut self,
+        op: &str,
+        ids: &[String],
+        status: &Status,
+        until: Option<DateTime<Utc>>,
+        actor: &str,
+    ) -> Result<Vec<Issue>> {
+        let mut issues = Vec::with_capacity(ids.len());
+        for id in ids {
+            let issue = self
+                .get_issue(id)?
+                .ok_or_else(|| BeadsError::IssueNotFound { id: id.clone() })?;
+            issues.push(issue);
+        }
+
+        self.mutate(op, actor, |tx, ctx| {
+            let now = Utc::now();
+            for issue in &mut issues {
+                let old_status = issue.status.as_str().to_string();
+                issue.status.clone_from(status);
+                issue.defer_until = until;
+                issue.updated_at = now;
+                tx.execute(
+                    "UPDATE issues SET status = ?, defer_until = ?, updated_at = ?, content_hash = ?
+                     WHERE id = ?",
+                    rusqlite::params![
+                        status.as_str(),
+                        until.map(|d| d.to_rfc3339()),
+                        now.to_rfc3339(),
+                        issue.compute_content_hash(),
+                        issue.id
+                    ],
+                )?;
+                if old_status != status.as_str() {
+                    ctx.record_field_change(
+                        EventType::StatusChanged,
+                        &issue.id,
+                        Some(old_status),
+                        Some(status.as_str().to_string()),
+                        None,
+                    );
+                }
+                ctx.mark_dirty(&issue.id);
+            }
+            if !issues.is_empty() {
+                ctx.invalidate_cache();
+            }
+            Ok(())
+        })?;
+
+        Ok(issues)
+    }
+
+    /// Reassign several issues to `to` in one transaction.
+    ///
+    /// An issue another actor has claimed (`in_progress` with a different
+    /// assignee) is left alone unless `steal` is set; the check is repeated
+    /// inside the transaction so a concurrent claim is never overwritten.
+    /// Returns the reassigned issues and the IDs skipped because of an
+    /// active claim.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any issue is missing or the database update fails;
+    /// in that case no issue is changed.
+    pub fn reassign_issues(
+        &mut self,
+        ids: &[String],
+        to: &str,
+        steal: bool,
+        actor: &str,
+    ) -> Result<(Vec<Issue>, Vec<String>)> {
+        let mut issues = Vec::with_capacity(ids.len());
+        for id in ids {
+            let issue = self
+                .get_issue(id)?
+                .ok_or_else(|| BeadsError::IssueNotFound { id: id.clone() })?;
+            issues.push(issue);
+        }
+
+        self.mutate("reassign_issues", actor, |tx, ctx| {
+            let now = Utc::now();
+            let mut reassigned = Vec::with_capacity(issues.len());
+            let mut skipped = Vec::new();
+            for mut issue in issues {
+                let old_assignee: Option<String> = tx.query_row(
+                    "SELECT assignee FROM issues WHERE id = ?",
+                    [&issue.id],
+                    |row| row.get(0),
+                )?;
+                issue.assignee = Some(to.to_string());
+                issue.updated_at = now;
+                let changed = tx.execute(
+                    "UPDATE issues SET assignee = ?1, updated_at = ?2, content_hash = ?3
+                     WHERE id = ?4
+                       AND (?5 OR status != 'in_progress'
+                            OR assignee IS NULL OR assignee = '' OR assignee = ?1)",
+                    rusqlite::params![
+                        to,
+                        now.to_rfc3339(),
+                        issue.compute_content_hash(),
+                        issue.id,
+                        steal
+                    ],
+                )?;
+                if changed == 0 {
+                    skipped.push(issue.id);
+                    continue;
+                }
+                ctx.record_field_change(
+                    EventType::AssigneeChanged,
+                    &issue.id,
+                    old_assignee,
+                    Some(to.to_string()),
+                    None,
+                );
+                ctx.mark_dirty(&issue.id);
+                reassigned.push(issue);
+            }
+            Ok((reassigned, skipped))
+        })
+    }
+
     /// Create a new issue.
     ///
     /// # Errors
@@ -659,6 +871,101 @@ impl SqliteStorage {
             .ok_or_else(|| BeadsError::IssueNotFound { id: id.to_string() })
     }
 
+    /// Permanently remove tombstones deleted before `tombstones_before` and,
+    /// optionally, audit events created before `events_before`.
+    ///
+    /// Rows are counted but left in place when `dry_run` is set. Removing a
+    /// tombstone cascades to its labels, comments, events, and dependencies;
+    /// dependencies from live issues onto a pruned id are removed as well.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query or delete fails.
+    pub fn gc_prune(
+        &mut self,
+        tombstones_before: DateTime<Utc>,
+        events_before: Option<DateTime<Utc>>,
+        dry_run: bool,
+        actor: &str,
+    ) -> Result<GcPruneReport> {
+        self.mutate("gc", actor, |tx, ctx| {
+            let mut stmt = tx.prepare(
+                "SELECT id FROM issues
+                 WHERE status = 'tombstone' AND deleted_at IS NOT NULL
+                   AND datetime(deleted_at) < datetime(?)
+                 ORDER BY id",
+            )?;
+            let tombstones = stmt
+                .query_map([tombstones_before.to_rfc3339()], |row| row.get(0))?
+                .collect::<std::result::Result<Vec<String>, _>>()?;
+            drop(stmt);
+
+            let mut report = GcPruneReport {
+                tombstones,
+                events: 0,
+            };
+
+            if let Some(cutoff) = events_before {
+                let count: i64 = tx.query_row(
+                    "SELECT COUNT(*) FROM events WHERE datetime(created_at) < datetime(?)",
+                    [cutoff.to_rfc3339()],
+                    |row| row.get(0),
+                )?;
+                report.events = usize::try_from(count).unwrap_or(0);
+                if !dry_run {
+                    tx.execute(
+                        "DELETE FROM events WHERE datetime(created_at) < datetime(?)",
+                        [cutoff.to_rfc3339()],
+                    )?;
+                }
+            }
+
+            if dry_run || report.tombstones.is_empty() {
+                return Ok(report);
+            }
+
+            for id in &report.tombstones {
+                tx.execute("DELETE FROM dependencies WHERE depends_on_id = ?", [id])?;
+                tx.execute("DELETE FROM issues WHERE id = ?", [id])?;
+            }
+            ctx.invalidate_cache();
+
+            Ok(report)
+        })
+    }
+
+    /// Size of the database file in bytes and the portion held by free pages.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pragma queries fail.
+    pub fn database_size(&self) -> Result<(u64, u64)> {
+        let page_size: i64 = self
+            .conn
+            .query_row("PRAGMA page_size", [], |row| row.get(0))?;
+        let page_count: i64 = self
+            .conn
+            .query_row("PRAGMA page_count", [], |row| row.get(0))?;
+        let freelist: i64 = self
+            .conn
+            .query_row("PRAGMA freelist_count", [], |row| row.get(0))?;
+        let bytes = |pages: i64| u64::try_from(pages.saturating_mul(page_size)).unwrap_or(0);
+        Ok((bytes(page_count), bytes(freelist)))
+    }
+
+    /// Rebuild the database file, releasing free pages back to the filesystem.
+    ///
+    /// `VACUUM` needs an exclusive lock; concurrent writers wait on the busy
+    /// timeout rather than failing outright.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the vacuum fails (e.g. the database stays locked).
+    pub fn vacuum(&self) -> Result<()> {
+        self.conn.execute_batch("VACUUM")?;
+        Ok(())
+    }
+
     /// Get an issue by ID.
     ///
     /// # Errors
@@ -732,8 +1039,27 @@ impl SqliteStorage {
     /// # Errors
     ///
     /// Returns an error if the database query fails.
-    #[allow(clippy::too_many_lines)]
     pub fn list_issues(&self, filters: &ListFilters) -> Result<Vec<Issue>> {
+        let mut issues = Vec::new();
+        self.for_each_issue(filters, |issue| {
+            issues.push(issue);
+            Ok(true)
+        })?;
+        Ok(issues)
+    }
+
+    /// Visit issues matching `filters` in list order, one row at a time,
+    /// without collecting them.
+    ///
+    /// `visit` returns `Ok(false)` to stop early.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or `visit` returns one.
+    pub fn for_each_issue<F>(&self, filters: &ListFilters, mut visit: F) -> Result<()>
+    where
+        F: FnMut(Issue) -> Result<bool>,
+    {
         let mut sql = String::from(
             r"SELECT id, content_hash, title, description, design, acceptance_criteria, notes,
                      status, priority, issue_type, assignee, owner, estimated_minutes,
@@ -745,6 +1071,100 @@ impl SqliteStorage {
             FROM issues WHERE 1=1",
         );
 
+        let mut params = Self::push_list_filter_clauses(&mut sql, filters);
+
+        // Legacy single fields (`priority`, `created`, ...) are one-key specs, so
+        // every sort, including the default, goes through the same parser.
+        let keys = parse_list_sort(filters.sort.as_deref().unwrap_or("priority"))?;
+        sql.push_str(&list_sort_order_by(&keys, filters.reverse));
+
+        if let Some(limit) = filters.limit {
+            if limit > 0 {
+                sql.push_str(" LIMIT ?");
+                params.push(Box::new(limit));
+            }
+        }
+
+        let mut stmt = self.conn.prepare(&sql)?;
+        let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(AsRef::as_ref).collect();
+        let mut rows = stmt.query(params_refs.as_slice())?;
+        while let Some(row) = rows.next()? {
+            if !visit(self.issue_from_row(row)?)? {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Count issues matching `filters`, grouped by `field`, with a single
+    /// `GROUP BY` query. Sort and limit on the filters are ignored.
+    ///
+    /// Returns `(group, count)` pairs ordered by group. Missing assignees and
+    /// labels are reported as `(unassigned)` and `(no labels)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn count_issues_grouped(
+        &self,
+        filters: &ListFilters,
+        field: IssueCountField,
+    ) -> Result<Vec<(String, usize)>> {
+        let mut filtered = String::from("SELECT id FROM issues WHERE 1=1");
+        let params = Self::push_list_filter_clauses(&mut filtered, filters);
+
+        // Group by an aliased expression so derived predicates (CASE/EXISTS) work
+        // the same as raw columns; the aggregate itself is only ever COUNT(*).
+        let sql = match field {
+            IssueCountField::Label => format!(
+                "SELECT l.label AS grp, COUNT(*) FROM ({filtered}) AS f
+                 LEFT JOIN labels l ON l.issue_id = f.id
+                 GROUP BY grp ORDER BY grp"
+            ),
+            _ => format!(
+                "SELECT {} AS grp, COUNT(*) FROM issues i
+                 WHERE i.id IN ({filtered})
+                 GROUP BY grp ORDER BY grp",
+                field.group_expr()
+            ),
+        };
+
+        let mut stmt = self.conn.prepare(&sql)?;
+        let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(AsRef::as_ref).collect();
+        let rows = stmt.query_map(params_refs.as_slice(), |row| {
+            let group = match row.get_ref(0)? {
+                rusqlite::types::ValueRef::Null => None,
+                rusqlite::types::ValueRef::Integer(n) => Some(n.to_string()),
+                rusqlite::types::ValueRef::Real(f) => Some(f.to_string()),
+                rusqlite::types::ValueRef::Text(t) | rusqlite::types::ValueRef::Blob(t) => {
+                    Some(String::from_utf8_lossy(t).into_owned())
+                }
+            };
+            let count: i64 = row.get(1)?;
+            Ok((group, usize::try_from(count).unwrap_or(0)))
+        })?;
+
+        let mut groups: Vec<(String, usize)> = Vec::new();
+        for row in rows {
+            let (group, count) = row?;
+            let label = field.group_label(group.as_deref());
+            // Empty strings and NULLs can both map to the same placeholder group.
+            if let Some(existing) = groups.iter_mut().find(|(g, _)| *g == label) {
+                existing.1 += count;
+            } else {
+                groups.push((label, count));
+            }
+        }
+        groups.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(groups)
+    }
+
+    /// Append the `ListFilters` WHERE clauses (no ORDER BY/LIMIT) to `sql`.
+    fn push_list_filter_clauses(
+        sql: &mut String,
+        filters: &ListFilters,
+    ) -> Vec<Box<dyn rusqlite::ToSql>> {
         let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
 
         if let Some(ref statuses) = filters.statuses {
@@ -836,56 +1256,11 @@ impl SqliteStorage {
             params.push(Box::new(ts.to_rfc3339()));
         }
 
-        // Apply custom sort if provided
-        if let Some(ref sort_field) = filters.sort {
-            let order = if filters.reverse { "DESC" } else { "ASC" };
-            // Simple validation to prevent injection (though params should handle it,
-            // column names can't be parameterized)
-            match sort_field.as_str() {
-                "priority" => {
-                    let secondary_order = if filters.reverse { "ASC" } else { "DESC" };
-                    let _ = write!(
-                        sql,
-                        " ORDER BY priority {order}, created_at {secondary_order}"
-                    );
-                }
-                "created_at" | "created" => {
-                    let order = if filters.reverse { "ASC" } else { "DESC" };
-                    let _ = write!(sql, " ORDER BY created_at {order}");
-                }
-                "updated_at" | "updated" => {
-                    let order = if filters.reverse { "ASC" } else { "DESC" };
-                    let _ = write!(sql, " ORDER BY updated_at {order}");
-                }
-                "title" => {
-                    // Case-insensitive sort for title
-                    let _ = write!(sql, " ORDER BY title COLLATE NOCASE {order}");
-                }
-                _ => {
-                    // Default fallback
-                    sql.push_str(" ORDER BY priority ASC, created_at DESC");
-                }
-            }
-        } else if filters.reverse {
-            sql.push_str(" ORDER BY priority DESC, created_at ASC");
-        } else {
-            sql.push_str(" ORDER BY priority ASC, created_at DESC");
-        }
-
-        if let Some(limit) = filters.limit {
-            if limit > 0 {
-                sql.push_str(" LIMIT ?");
-                params.push(Box::new(limit));
-            }
+        for predicate in &filters.predicates {
+            predicate.push_sql(sql, &mut params);
         }
 
-        let mut stmt = self.conn.prepare(&sql)?;
-        let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(AsRef::as_ref).collect();
-        let issues = stmt
-            .query_map(params_refs.as_slice(), |row| self.issue_from_row(row))?
-            .collect::<std::result::Result<Vec<_>, _>>()?;
-
-        Ok(issues)
+        params
     }
 
     /// Search issues by query with optional filters.
@@ -998,6 +1373,15 @@ impl SqliteStorage {
             params.push(Box::new(format!("%{escaped}%")));
         }
 
+        if let Some(ts) = filters.updated_after {
+            sql.push_str(" AND updated_at >= ?");
+            params.push(Box::new(ts.to_rfc3339()));
+        }
+
+        for predicate in &filters.predicates {
+            predicate.push_sql(&mut sql, &mut params);
+        }
+
         sql.push_str(" ORDER BY priority ASC, created_at DESC");
 
         if let Some(limit) = filters.limit {
@@ -1028,7 +1412,6 @@ impl SqliteStorage {
     /// # Errors
     ///
     /// Returns an error if the database query fails.
-    #[allow(clippy::too_many_lines)]
     pub fn get_ready_issues(
         &self,
         filters: &ReadyFilters,
@@ -1044,7 +1427,77 @@ impl SqliteStorage {
                      sender, ephemeral, pinned, is_template
               FROM issues WHERE 1=1",
         );
+        let params = Self::push_ready_clauses(&mut sql, filters, sort);
+
+        let mut stmt = self.conn.prepare(&sql)?;
+        let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(AsRef::as_ref).collect();
+        let issues: Vec<Issue> = stmt
+            .query_map(params_refs.as_slice(), |row| self.issue_from_row(row))?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(issues)
+    }
+
+    /// Get ready issues as rows of only the requested columns.
+    ///
+    /// Uses the same ready definition and ordering as [`Self::get_ready_issues`]
+    /// but skips hydrating full `Issue` values. `id` is always selected so callers
+    /// can post-filter; columns must come from [`READY_PROJECTION_COLUMNS`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a column is not projectable or the query fails.
+    pub fn get_ready_issue_columns(
+        &self,
+        filters: &ReadyFilters,
+        sort: ReadySortPolicy,
+        columns: &[String],
+    ) -> Result<Vec<serde_json::Map<String, serde_json::Value>>> {
+        if let Some(bad) = columns
+            .iter()
+            .find(|c| !READY_PROJECTION_COLUMNS.contains(&c.as_str()))
+        {
+            return Err(BeadsError::validation(
+                "fields",
+                format!(
+                    "unknown field '{bad}'; valid: {}",
+                    READY_PROJECTION_COLUMNS.join(", ")
+                ),
+            ));
+        }
+        let mut selected: Vec<&str> = vec!["id"];
+        selected.extend(columns.iter().map(String::as_str).filter(|c| *c != "id"));
 
+        let mut sql = format!("SELECT {} FROM issues WHERE 1=1", selected.join(", "));
+        let params = Self::push_ready_clauses(&mut sql, filters, sort);
+
+        let mut stmt = self.conn.prepare(&sql)?;
+        let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(AsRef::as_ref).collect();
+        let rows = stmt.query_map(params_refs.as_slice(), |row| {
+            let mut object = serde_json::Map::with_capacity(selected.len());
+            for (i, column) in selected.iter().enumerate() {
+                let value = match row.get_ref(i)? {
+                    rusqlite::types::ValueRef::Null => serde_json::Value::Null,
+                    rusqlite::types::ValueRef::Integer(n) => n.into(),
+                    rusqlite::types::ValueRef::Real(f) => f.into(),
+                    rusqlite::types::ValueRef::Text(t) | rusqlite::types::ValueRef::Blob(t) => {
+                        String::from_utf8_lossy(t).into_owned().into()
+                    }
+                };
+                object.insert((*column).to_string(), value);
+            }
+            Ok(object)
+        })?;
+        Ok(rows.collect::<std::result::Result<Vec<_>, _>>()?)
+    }
+
+    /// Append the ready-queue WHERE, ORDER BY, and LIMIT clauses to `sql`.
+    #[allow(clippy::too_many_lines)]
+    fn push_ready_clauses(
+        sql: &mut String,
+        filters: &ReadyFilters,
+        sort: ReadySortPolicy,
+    ) -> Vec<Box<dyn rusqlite::ToSql>> {
         let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
 
         // Ready condition 1: status is `open` OR `in_progress`
@@ -1063,12 +1516,16 @@ impl SqliteStorage {
             sql.push_str(" AND (defer_until IS NULL OR datetime(defer_until) <= datetime('now'))");
         }
 
-        // Ready condition 4: not pinned
-        sql.push_str(" AND (pinned = 0 OR pinned IS NULL)");
+        // Ready condition 4: not pinned (unless `include_pinned`)
+        if !filters.include_pinned {
+            sql.push_str(" AND (pinned = 0 OR pinned IS NULL)");
+        }
 
-        // Ready condition 5: not ephemeral and not wisp
-        sql.push_str(" AND (ephemeral = 0 OR ephemeral IS NULL)");
-        sql.push_str(" AND id NOT LIKE '%-wisp-%'");
+        // Ready condition 5: not ephemeral and not wisp (unless `include_ephemeral`)
+        if !filters.include_ephemeral {
+            sql.push_str(" AND (ephemeral = 0 OR ephemeral IS NULL)");
+            sql.push_str(" AND id NOT LIKE '%-wisp-%'");
+        }
 
         // Exclude templates
         sql.push_str(" AND (is_template = 0 OR is_template IS NULL)");
@@ -1102,11 +1559,26 @@ impl SqliteStorage {
             params.push(Box::new(assignee.clone()));
         }
 
+        // Filter by a set of assignees (team roster)
+        if !filters.assignees.is_empty() {
+            let placeholders: Vec<&str> = filters.assignees.iter().map(|_| "?").collect();
+            let _ = write!(sql, " AND assignee IN ({})", placeholders.join(","));
+            for assignee in &filters.assignees {
+                params.push(Box::new(assignee.clone()));
+            }
+        }
+
         // Filter for unassigned
         if filters.unassigned {
             sql.push_str(" AND assignee IS NULL");
         }
 
+        // Filter for own-or-free pool
+        if let Some(ref assignee) = filters.assignee_or_unassigned {
+            sql.push_str(" AND (assignee = ? OR assignee IS NULL)");
+            params.push(Box::new(assignee.clone()));
+        }
+
         // Filter by labels (AND logic)
         for label in &filters.labels_and {
             sql.push_str(" AND EXISTS (SELECT 1 FROM labels WHERE labels.issue_id = issues.id AND labels.label = ?)");
@@ -1127,6 +1599,20 @@ impl SqliteStorage {
             }
         }
 
+        // Exclude issues carrying any of the given labels (wins over inclusion)
+        if !filters.labels_not.is_empty() {
+            let placeholders: Vec<String> =
+                filters.labels_not.iter().map(|_| "?".to_string()).collect();
+            let _ = write!(
+                sql,
+                " AND id NOT IN (SELECT issue_id FROM labels WHERE label IN ({}))",
+                placeholders.join(",")
+            );
+            for l in &filters.labels_not {
+                params.push(Box::new(l.clone()));
+            }
+        }
+
         // Sorting
         match sort {
             ReadySortPolicy::Hybrid => {
@@ -1150,13 +1636,7 @@ impl SqliteStorage {
             }
         }
 
-        let mut stmt = self.conn.prepare(&sql)?;
-        let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(AsRef::as_ref).collect();
-        let issues: Vec<Issue> = stmt
-            .query_map(params_refs.as_slice(), |row| self.issue_from_row(row))?
-            .collect::<std::result::Result<Vec<_>, _>>()?;
-
-        Ok(issues)
+        params
     }
 
     /// Get IDs of blocked issues from cache.
@@ -1405,21 +1885,50 @@ impl SqliteStorage {
         Ok(blocked_issues)
     }
 
-    /// Resolve external dependency satisfaction for dependencies of this project.
+    /// Resolve external dependency satisfaction for dependencies of this project.
+    ///
+    /// Returns a map of external dependency IDs to whether they are satisfied.
+    /// Missing projects or query failures are treated as unsatisfied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if querying local dependencies fails.
+    pub fn resolve_external_dependency_statuses(
+        &self,
+        external_db_paths: &HashMap<String, PathBuf>,
+        blocking_only: bool,
+    ) -> Result<HashMap<String, bool>> {
+        self.resolve_external_dependency_statuses_cached(
+            external_db_paths,
+            blocking_only,
+            ExternalCachePolicy::default(),
+        )
+    }
+
+    /// Resolve external dependency satisfaction, reusing cached results per project.
     ///
-    /// Returns a map of external dependency IDs to whether they are satisfied.
-    /// Missing projects or query failures are treated as unsatisfied.
+    /// With a `ttl` set, each project's resolved capabilities are stored in the
+    /// metadata table and reused while the external DB (and its WAL) keep the same
+    /// modification time and the entry is younger than the TTL. `refresh` skips
+    /// cached entries but still records the fresh result. Expired entries and
+    /// entries for projects that are no longer referenced are pruned.
     ///
     /// # Errors
     ///
     /// Returns an error if querying local dependencies fails.
-    pub fn resolve_external_dependency_statuses(
+    pub fn resolve_external_dependency_statuses_cached(
         &self,
         external_db_paths: &HashMap<String, PathBuf>,
         blocking_only: bool,
+        cache: ExternalCachePolicy,
     ) -> Result<HashMap<String, bool>> {
         let external_ids = self.list_external_dependency_ids(blocking_only)?;
         if external_ids.is_empty() {
+            if let Some(ttl) = cache.ttl {
+                self.write_external_cache("prune", |conn| {
+                    Self::prune_external_cache(conn, &HashSet::new(), ttl).map(|_| ())
+                });
+            }
             return Ok(HashMap::new());
         }
 
@@ -1446,7 +1955,17 @@ impl SqliteStorage {
                 continue;
             };
 
-            match query_external_project_capabilities(db_path, caps) {
+            let result = match cache.ttl {
+                Some(ttl) => self.query_external_capabilities_cached(
+                    project,
+                    db_path,
+                    caps,
+                    ttl,
+                    cache.refresh,
+                ),
+                None => query_external_project_capabilities(db_path, caps),
+            };
+            match result {
                 Ok(found) => {
                     satisfied.insert(project.clone(), found);
                 }
@@ -1461,6 +1980,13 @@ impl SqliteStorage {
             }
         }
 
+        if let Some(ttl) = cache.ttl {
+            let active: HashSet<&str> = project_caps.keys().map(String::as_str).collect();
+            self.write_external_cache("prune", |conn| {
+                Self::prune_external_cache(conn, &active, ttl).map(|_| ())
+            });
+        }
+
         let mut statuses = HashMap::new();
         for dep_id in external_ids {
             let is_satisfied = parsed.get(&dep_id).is_some_and(|(project, capability)| {
@@ -1474,6 +2000,123 @@ impl SqliteStorage {
         Ok(statuses)
     }
 
+    /// Query an external project, going through the metadata-table cache.
+    fn query_external_capabilities_cached(
+        &self,
+        project: &str,
+        db_path: &Path,
+        capabilities: &HashSet<String>,
+        ttl: Duration,
+        refresh: bool,
+    ) -> Result<HashSet<String>> {
+        let key = format!("{EXTERNAL_CACHE_KEY_PREFIX}{project}");
+        let db_path_str = db_path.to_string_lossy().to_string();
+        let modified = external_db_modified(db_path);
+
+        if !refresh && modified.is_some() {
+            let cached = self
+                .get_metadata(&key)?
+                .and_then(|raw| serde_json::from_str::<ExternalCacheEntry>(&raw).ok());
+            if let Some(entry) = cached {
+                let age = Utc::now().signed_duration_since(entry.resolved_at).to_std();
+                let fresh = entry.db_path == db_path_str
+                    && entry.modified == modified
+                    && age.is_ok_and(|age| age <= ttl)
+                    && capabilities.is_subset(&entry.queried);
+                if fresh {
+                    tracing::debug!(project, "Using cached external dependency statuses");
+                    return Ok(capabilities
+                        .intersection(&entry.satisfied)
+                        .cloned()
+                        .collect());
+                }
+            }
+        }
+
+        let satisfied = query_external_project_capabilities(db_path, capabilities)?;
+        let entry = ExternalCacheEntry {
+            db_path: db_path_str,
+            modified,
+            resolved_at: Utc::now(),
+            queried: capabilities.clone(),
+            satisfied: satisfied.clone(),
+        };
+        match serde_json::to_string(&entry) {
+            Ok(value) => self
+                .write_external_cache("store", |conn| Self::set_metadata_in_tx(conn, &key, &value)),
+            Err(err) => {
+                warn!(project, error = %err, "Failed to serialize external dependency cache");
+            }
+        }
+        Ok(satisfied)
+    }
+
+    /// Apply a write to the external dependency cache, best effort.
+    ///
+    /// The cache only saves work, so it must not fail read commands such as
+    /// `ready`: the write runs in its own transaction and a locked or read-only
+    /// database is skipped with a debug log; other failures are warned about.
+    fn write_external_cache<F>(&self, action: &str, write: F)
+    where
+        F: FnOnce(&Connection) -> Result<()>,
+    {
+        let result = self
+            .conn
+            .unchecked_transaction()
+            .map_err(BeadsError::from)
+            .and_then(|tx| {
+                write(&tx)?;
+                tx.commit()?;
+                Ok(())
+            });
+        match result {
+            Ok(()) => {}
+            Err(err) if is_busy_or_read_only(&err) => {
+                tracing::debug!(action, error = %err, "Skipped external dependency cache write");
+            }
+            Err(err) => {
+                warn!(action, error = %err, "Failed to update external dependency cache");
+            }
+        }
+    }
+
+    /// Remove cached external resolutions that are older than `ttl` or belong to
+    /// projects outside `active`, so the metadata table does not keep growing.
+    ///
+    /// Returns the number of removed entries.
+    fn prune_external_cache(
+        conn: &Connection,
+        active: &HashSet<&str>,
+        ttl: Duration,
+    ) -> Result<usize> {
+        let mut stmt = conn.prepare("SELECT key, value FROM metadata WHERE key GLOB ?")?;
+        let entries = stmt
+            .query_map([format!("{EXTERNAL_CACHE_KEY_PREFIX}*")], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        let now = Utc::now();
+        let mut pruned = 0;
+        for (key, value) in entries {
+            let project = &key[EXTERNAL_CACHE_KEY_PREFIX.len()..];
+            let expired = serde_json::from_str::<ExternalCacheEntry>(&value)
+                .ok()
+                .is_none_or(|entry| {
+                    now.signed_duration_since(entry.resolved_at)
+                        .to_std()
+                        .is_ok_and(|age| age > ttl)
+                });
+            if expired || !active.contains(project) {
+                pruned += conn.execute("DELETE FROM metadata WHERE key = ?", [&key])?;
+            }
+        }
+        if pruned > 0 {
+            tracing::debug!(pruned, "Pruned external dependency cache entries");
+        }
+        Ok(pruned)
+    }
+
     /// Compute blockers caused by unsatisfied external dependencies.
     ///
     /// This excludes external dependencies from the blocked cache and evaluates
@@ -1738,6 +2381,56 @@ impl SqliteStorage {
         })
     }
 
+    /// Add several dependencies in a single transaction.
+    ///
+    /// Each entry is `(issue_id, depends_on_id, dep_type)`. Edges that already
+    /// exist are left untouched. Callers are responsible for cycle validation;
+    /// if any insert fails, none of the edges are written.
+    ///
+    /// Returns the number of edges inserted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub fn add_dependencies(
+        &mut self,
+        edges: &[(String, String, String)],
+        actor: &str,
+    ) -> Result<usize> {
+        self.mutate("add_dependencies", actor, |tx, ctx| {
+            let now = Utc::now().to_rfc3339();
+            let mut added = 0;
+            for (issue_id, depends_on_id, dep_type) in edges {
+                let inserted = tx.execute(
+                    "INSERT OR IGNORE INTO dependencies (issue_id, depends_on_id, type, created_at, created_by)
+                     VALUES (?, ?, ?, ?, ?)",
+                    rusqlite::params![issue_id, depends_on_id, dep_type, now, actor],
+                )?;
+                if inserted == 0 {
+                    continue;
+                }
+
+                tx.execute(
+                    "UPDATE issues SET updated_at = ? WHERE id = ?",
+                    rusqlite::params![now, issue_id],
+                )?;
+
+                ctx.record_event(
+                    EventType::DependencyAdded,
+                    issue_id,
+                    Some(format!("Added dependency on {depends_on_id} ({dep_type})")),
+                );
+                ctx.mark_dirty(issue_id);
+                added += 1;
+            }
+
+            if added > 0 {
+                ctx.invalidate_cache();
+            }
+            Ok(added)
+        })
+    }
+
     /// Remove a dependency link.
     ///
     /// # Errors
@@ -3079,6 +3772,404 @@ pub struct ListFilters {
     pub updated_before: Option<DateTime<Utc>>,
     /// Filter by `updated_at` >= timestamp
     pub updated_after: Option<DateTime<Utc>>,
+    /// `--where` predicates (ANDed)
+    pub predicates: Vec<ListPredicate>,
+}
+
+/// Caching behaviour for [`SqliteStorage::resolve_external_dependency_statuses_cached`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ExternalCachePolicy {
+    /// How long a cached project resolution stays valid; `None` disables the cache.
+    pub ttl: Option<Duration>,
+    /// Ignore cached entries and re-query every external project.
+    pub refresh: bool,
+}
+
+/// Rows removed (or, on a dry run, that would be removed) by [`SqliteStorage::gc_prune`].
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct GcPruneReport {
+    /// IDs of pruned tombstones.
+    pub tombstones: Vec<String>,
+    /// Number of pruned audit events.
+    pub events: usize,
+}
+
+/// Metadata key prefix for cached external project resolutions.
+const EXTERNAL_CACHE_KEY_PREFIX: &str = "external_status_cache:";
+
+/// Whether `err` means the database is locked by another writer or read-only.
+fn is_busy_or_read_only(err: &BeadsError) -> bool {
+    matches!(
+        err,
+        BeadsError::Database(rusqlite::Error::SqliteFailure(failure, _))
+            if matches!(
+                failure.code,
+                rusqlite::ErrorCode::DatabaseBusy
+                    | rusqlite::ErrorCode::DatabaseLocked
+                    | rusqlite::ErrorCode::ReadOnly
+            )
+    )
+}
+
+/// Cached capabilities resolved from one external project.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct ExternalCacheEntry {
+    db_path: String,
+    modified: Option<DateTime<Utc>>,
+    resolved_at: DateTime<Utc>,
+    queried: HashSet<String>,
+    satisfied: HashSet<String>,
+}
+
+/// Latest modification time of an external DB and its WAL file.
+fn external_db_modified(db_path: &Path) -> Option<DateTime<Utc>> {
+    let mut wal = db_path.as_os_str().to_owned();
+    wal.push("-wal");
+    [db_path.to_path_buf(), PathBuf::from(wal)]
+        .iter()
+        .filter_map(|path| {
+            std::fs::metadata(path)
+                .and_then(|meta| meta.modified())
+                .ok()
+        })
+        .map(DateTime::<Utc>::from)
+        .max()
+}
+
+/// Field to group by in [`SqliteStorage::count_issues_grouped`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueCountField {
+    Status,
+    Priority,
+    Type,
+    Assignee,
+    Label,
+    /// Derived: `overdue` vs `on-time` (due date passed on a non-terminal issue).
+    Overdue,
+    /// Derived: `labeled` vs `unlabeled`.
+    Labeled,
+}
+
+impl IssueCountField {
+    /// SQL expression over `issues i` producing the group value
+    /// (labels are joined separately).
+    const fn group_expr(self) -> &'static str {
+        match self {
+            Self::Status => "i.status",
+            Self::Priority => "i.priority",
+            Self::Type => "i.issue_type",
+            Self::Assignee => "i.assignee",
+            Self::Label => "NULL",
+            Self::Overdue => {
+                "CASE WHEN i.due_at IS NOT NULL AND datetime(i.due_at) < datetime('now')
+                      AND i.status NOT IN ('closed', 'tombstone')
+                 THEN 'overdue' ELSE 'on-time' END"
+            }
+            Self::Labeled => {
+                "CASE WHEN EXISTS (SELECT 1 FROM labels l WHERE l.issue_id = i.id)
+                 THEN 'labeled' ELSE 'unlabeled' END"
+            }
+        }
+    }
+
+    /// Display label for a raw grouped value.
+    fn group_label(self, raw: Option<&str>) -> String {
+        let raw = raw.filter(|v| !v.is_empty());
+        match self {
+            Self::Priority => raw.map_or_else(String::new, |p| format!("P{p}")),
+            Self::Assignee => raw.unwrap_or("(unassigned)").to_string(),
+            Self::Label => raw.unwrap_or("(no labels)").to_string(),
+            Self::Status | Self::Type | Self::Overdue | Self::Labeled => {
+                raw.unwrap_or_default().to_string()
+            }
+        }
+    }
+}
+
+/// Valid field names for multi-key list sorting (`field[:asc|desc]`).
+pub const LIST_SORT_FIELDS: &[&str] = &["priority", "created", "updated", "id", "title"];
+
+/// One key of a multi-key list sort such as `priority:asc,created:desc`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListSortKey {
+    /// SQL column expression to order by.
+    pub column: &'static str,
+    /// Whether this key sorts descending.
+    pub descending: bool,
+}
+
+/// Parse a comma-separated `field[:asc|desc]` sort spec.
+///
+/// Fields without an explicit direction use their natural default: ascending
+/// for `priority`, `id` and `title`, descending (newest first) for `created`
+/// and `updated`. `created_at`/`updated_at` are accepted as aliases.
+///
+/// # Errors
+///
+/// Returns a validation error naming the valid fields if a field or
+/// direction is not recognized.
+pub fn parse_list_sort(spec: &str) -> Result<Vec<ListSortKey>> {
+    let invalid = |reason: String| BeadsError::Validation {
+        field: "sort".to_string(),
+        reason,
+    };
+
+    let mut keys = Vec::new();
+    for token in spec.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+        let (field, direction) = match token.split_once(':') {
+            Some((field, direction)) => (field.trim(), Some(direction.trim())),
+            None => (token, None),
+        };
+        let (column, default_desc) = match field.to_ascii_lowercase().as_str() {
+            "priority" => ("priority", false),
+            "created" | "created_at" => ("created_at", true),
+            "updated" | "updated_at" => ("updated_at", true),
+            "id" => ("id", false),
+            "title" => ("title COLLATE NOCASE", false),
+            _ => {
+                return Err(invalid(format!(
+                    "invalid sort field '{field}' (valid: {})",
+                    LIST_SORT_FIELDS.join(", ")
+                )));
+            }
+        };
+        let descending = match direction.map(str::to_ascii_lowercase).as_deref() {
+            None => default_desc,
+            Some("asc") => false,
+            Some("desc") => true,
+            Some(other) => {
+                return Err(invalid(format!(
+                    "invalid sort direction '{other}' for '{field}' (valid: asc, desc)"
+                )));
+            }
+        };
+        keys.push(ListSortKey { column, descending });
+    }
+
+    if keys.is_empty() {
+        return Err(invalid("empty sort spec".to_string()));
+    }
+    Ok(keys)
+}
+
+fn list_sort_order_by(keys: &[ListSortKey], reverse: bool) -> String {
+    let mut parts: Vec<String> = keys
+        .iter()
+        .map(|key| {
+            let descending = key.descending != reverse;
+            format!("{} {}", key.column, if descending { "DESC" } else { "ASC" })
+        })
+        .collect();
+    // Ties break newest first (the historical `priority` ordering), then by id
+    // so equal keys don't reorder between runs
+    if !keys.iter().any(|key| key.column == "created_at") {
+        let order = if reverse { "ASC" } else { "DESC" };
+        parts.push(format!("created_at {order}"));
+    }
+    if !keys.iter().any(|key| key.column == "id") {
+        parts.push("id ASC".to_string());
+    }
+    format!(" ORDER BY {}", parts.join(", "))
+}
+
+/// Valid field names for `list --where` predicates.
+pub const LIST_WHERE_FIELDS: &[&str] = &[
+    "id",
+    "title",
+    "status",
+    "priority",
+    "type",
+    "assignee",
+    "owner",
+    "created_by",
+    "external_ref",
+    "estimate",
+    "created_at",
+    "updated_at",
+    "closed_at",
+    "due_at",
+];
+
+/// Comparison operator in a [`ListPredicate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl CompareOp {
+    /// Operators in match order: two-character forms first.
+    const ALL: [(&'static str, Self); 6] = [
+        ("!=", Self::Ne),
+        ("<=", Self::Le),
+        (">=", Self::Ge),
+        ("=", Self::Eq),
+        ("<", Self::Lt),
+        (">", Self::Gt),
+    ];
+
+    /// SQL operator; `!=` is null-safe so unset fields still match.
+    const fn sql(self) -> &'static str {
+        match self {
+            Self::Eq => "=",
+            Self::Ne => "IS NOT",
+            Self::Lt => "<",
+            Self::Le => "<=",
+            Self::Gt => ">",
+            Self::Ge => ">=",
+        }
+    }
+
+    const fn is_equality(self) -> bool {
+        matches!(self, Self::Eq | Self::Ne)
+    }
+}
+
+/// Typed right-hand side of a [`ListPredicate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PredicateValue {
+    Text(String),
+    Integer(i64),
+    Timestamp(DateTime<Utc>),
+    /// Empty value: `field=` matches unset fields, `field!=` set ones.
+    Unset,
+}
+
+/// One `field<op>value` predicate from `list --where`, validated against
+/// [`LIST_WHERE_FIELDS`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListPredicate {
+    /// SQL column the predicate applies to.
+    pub column: &'static str,
+    pub op: CompareOp,
+    pub value: PredicateValue,
+}
+
+impl ListPredicate {
+    fn push_sql(&self, sql: &mut String, params: &mut Vec<Box<dyn rusqlite::ToSql>>) {
+        let column = self.column;
+        match &self.value {
+            PredicateValue::Unset if self.op == CompareOp::Eq => {
+                let _ = write!(sql, " AND ({column} IS NULL OR {column} = '')");
+            }
+            PredicateValue::Unset => {
+                let _ = write!(sql, " AND ({column} IS NOT NULL AND {column} != '')");
+            }
+            PredicateValue::Text(text) => {
+                let _ = write!(sql, " AND {column} {} ?", self.op.sql());
+                params.push(Box::new(text.clone()));
+            }
+            PredicateValue::Integer(value) => {
+                let _ = write!(sql, " AND {column} {} ?", self.op.sql());
+                params.push(Box::new(*value));
+            }
+            PredicateValue::Timestamp(ts) => {
+                let _ = write!(sql, " AND datetime({column}) {} datetime(?)", self.op.sql());
+                params.push(Box::new(ts.to_rfc3339()));
+            }
+        }
+    }
+}
+
+/// Whether a `--where` field is compared as text, a number, or a timestamp.
+#[derive(Clone, Copy)]
+enum PredicateKind {
+    Text,
+    Status,
+    IssueType,
+    Priority,
+    Integer,
+    Timestamp,
+}
+
+/// Parse a `field<op>value` predicate such as `priority<=1` or `status!=closed`.
+///
+/// Operators are `=`, `!=`, `<`, `<=`, `>`, `>=`; text, status and type fields
+/// accept only `=`/`!=`. Priorities accept `1` or `P1`, timestamps accept
+/// RFC3339, `YYYY-MM-DD`, or relative offsets like `-7d`.
+///
+/// # Errors
+///
+/// Returns a validation error for unknown fields, missing or unsupported
+/// operators, and values that don't parse for the field's type.
+pub fn parse_list_predicate(spec: &str) -> Result<ListPredicate> {
+    let invalid = |reason: String| BeadsError::validation("where", reason);
+
+    let (start, token, op) = CompareOp::ALL
+        .iter()
+        .filter_map(|(token, op)| spec.find(token).map(|start| (start, *token, *op)))
+        .min_by_key(|(start, token, _)| (*start, std::cmp::Reverse(token.len())))
+        .ok_or_else(|| {
+            invalid(format!(
+                "'{spec}' has no operator (expected field=value, !=, <, <=, >, >=)"
+            ))
+        })?;
+    let field = spec[..start].trim().to_ascii_lowercase();
+    let raw = spec[start + token.len()..].trim();
+
+    let (column, kind) = match field.as_str() {
+        "id" => ("id", PredicateKind::Text),
+        "title" => ("title", PredicateKind::Text),
+        "status" => ("status", PredicateKind::Status),
+        "priority" => ("priority", PredicateKind::Priority),
+        "type" | "issue_type" => ("issue_type", PredicateKind::IssueType),
+        "assignee" => ("assignee", PredicateKind::Text),
+        "owner" => ("owner", PredicateKind::Text),
+        "created_by" => ("created_by", PredicateKind::Text),
+        "external_ref" => ("external_ref", PredicateKind::Text),
+        "estimate" | "estimated_minutes" => ("estimated_minutes", PredicateKind::Integer),
+        "created_at" | "created" => ("created_at", PredicateKind::Timestamp),
+        "updated_at" | "updated" => ("updated_at", PredicateKind::Timestamp),
+        "closed_at" | "closed" => ("closed_at", PredicateKind::Timestamp),
+        "due_at" | "due" => ("due_at", PredicateKind::Timestamp),
+        _ => {
+            return Err(invalid(format!(
+                "unknown field '{field}' (valid: {})",
+                LIST_WHERE_FIELDS.join(", ")
+            )));
+        }
+    };
+
+    let ordered = matches!(
+        kind,
+        PredicateKind::Priority | PredicateKind::Integer | PredicateKind::Timestamp
+    );
+    if !ordered && !op.is_equality() {
+        return Err(invalid(format!("field '{field}' only supports = and !=")));
+    }
+
+    let value = if raw.is_empty() {
+        if !op.is_equality() {
+            return Err(invalid(format!("'{spec}' needs a value after '{token}'")));
+        }
+        PredicateValue::Unset
+    } else {
+        match kind {
+            PredicateKind::Text => PredicateValue::Text(raw.to_string()),
+            PredicateKind::Status => {
+                PredicateValue::Text(raw.parse::<Status>()?.as_str().to_string())
+            }
+            PredicateKind::IssueType => {
+                PredicateValue::Text(raw.parse::<IssueType>()?.as_str().to_string())
+            }
+            PredicateKind::Priority => {
+                PredicateValue::Integer(i64::from(raw.parse::<Priority>()?.0))
+            }
+            PredicateKind::Integer => PredicateValue::Integer(
+                raw.parse()
+                    .map_err(|_| invalid(format!("'{raw}' is not a whole number for '{field}'")))?,
+            ),
+            PredicateKind::Timestamp => {
+                PredicateValue::Timestamp(parse_flexible_timestamp(raw, "where")?)
+            }
+        }
+    };
+
+    Ok(ListPredicate { column, op, value })
 }
 
 /// Fields to update on an issue.
@@ -3139,15 +4230,38 @@ impl IssueUpdate {
 #[derive(Debug, Clone, Default)]
 pub struct ReadyFilters {
     pub assignee: Option<String>,
+    /// Issues assigned to any of these actors (empty = no filter).
+    pub assignees: Vec<String>,
     pub unassigned: bool,
+    /// Issues assigned to this actor OR unassigned.
+    pub assignee_or_unassigned: Option<String>,
     pub labels_and: Vec<String>,
     pub labels_or: Vec<String>,
+    pub labels_not: Vec<String>,
     pub types: Option<Vec<IssueType>>,
     pub priorities: Option<Vec<Priority>>,
     pub include_deferred: bool,
+    pub include_pinned: bool,
+    pub include_ephemeral: bool,
     pub limit: Option<usize>,
 }
 
+/// Columns that `get_ready_issue_columns` can project (`br ready --jsonl --fields`).
+pub const READY_PROJECTION_COLUMNS: &[&str] = &[
+    "id",
+    "title",
+    "status",
+    "priority",
+    "issue_type",
+    "assignee",
+    "owner",
+    "estimated_minutes",
+    "created_at",
+    "updated_at",
+    "due_at",
+    "external_ref",
+];
+
 /// Sort policy for ready issues.
 #[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
 pub enum ReadySortPolicy {
@@ -3160,6 +4274,17 @@ pub enum ReadySortPolicy {
     Oldest,
 }
 
+/// Result of [`SqliteStorage::claim_issue`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ClaimOutcome {
+    /// The issue is now assigned to the actor and `in_progress`
+    Claimed,
+    /// The actor already had the issue `in_progress`; nothing changed
+    AlreadyHeld,
+    /// Missing, closed, or held by another actor
+    Unavailable,
+}
+
 fn parse_status(s: Option<&str>) -> Status {
     s.map_or_else(Status::default, |val| {
         val.parse()
@@ -3988,6 +5113,117 @@ mod tests {
         assert!(child_blockers.iter().any(|b| b == "bd-p1:parent-blocked"));
     }
 
+    #[test]
+    fn test_external_dependency_status_cache_ttl_and_refresh() {
+        let temp = TempDir::new().unwrap();
+        let beads_dir = temp.path().join("extproj").join(".beads");
+        fs::create_dir_all(&beads_dir).unwrap();
+        let db_path = beads_dir.join("beads.db");
+        let _external_storage = SqliteStorage::open(&db_path).unwrap();
+
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 3, 3, 0, 0, 0).unwrap();
+        let issue = make_issue("bd-e1", "Needs cap", Status::Open, 2, None, t1, None);
+        storage.create_issue(&issue, "tester").unwrap();
+        storage
+            .add_dependency("bd-e1", "external:extproj:cap", "blocks", "tester")
+            .unwrap();
+
+        let mut external_db_paths = HashMap::new();
+        external_db_paths.insert("extproj".to_string(), db_path);
+        let cached = ExternalCachePolicy {
+            ttl: Some(Duration::from_secs(3600)),
+            refresh: false,
+        };
+
+        let statuses = storage
+            .resolve_external_dependency_statuses_cached(&external_db_paths, true, cached)
+            .unwrap();
+        assert_eq!(statuses.get("external:extproj:cap"), Some(&false));
+
+        // Tamper with the cached entry: a cache hit must return it unchanged.
+        let key = format!("{EXTERNAL_CACHE_KEY_PREFIX}extproj");
+        let raw = storage.get_metadata(&key).unwrap().expect("cache entry");
+        let mut entry: ExternalCacheEntry = serde_json::from_str(&raw).unwrap();
+        entry.satisfied.insert("cap".to_string());
+        storage
+            .set_metadata(&key, &serde_json::to_string(&entry).unwrap())
+            .unwrap();
+
+        let statuses = storage
+            .resolve_external_dependency_statuses_cached(&external_db_paths, true, cached)
+            .unwrap();
+        assert_eq!(statuses.get("external:extproj:cap"), Some(&true));
+
+        let refreshed = ExternalCachePolicy {
+            refresh: true,
+            ..cached
+        };
+        let statuses = storage
+            .resolve_external_dependency_statuses_cached(&external_db_paths, true, refreshed)
+            .unwrap();
+        assert_eq!(statuses.get("external:extproj:cap"), Some(&false));
+
+        // Entries for unreferenced projects and expired entries are pruned.
+        let gone_key = format!("{EXTERNAL_CACHE_KEY_PREFIX}gone");
+        storage.set_metadata(&gone_key, &raw).unwrap();
+        let mut expired: ExternalCacheEntry = serde_json::from_str(&raw).unwrap();
+        expired.resolved_at = Utc::now() - chrono::Duration::hours(2);
+        expired.satisfied.insert("cap".to_string());
+        storage
+            .set_metadata(&key, &serde_json::to_string(&expired).unwrap())
+            .unwrap();
+
+        let statuses = storage
+            .resolve_external_dependency_statuses_cached(&external_db_paths, true, cached)
+            .unwrap();
+        assert_eq!(statuses.get("external:extproj:cap"), Some(&false));
+        assert!(storage.get_metadata(&gone_key).unwrap().is_none());
+        assert!(storage.get_metadata(&key).unwrap().is_some());
+
+        storage
+            .remove_dependency("bd-e1", "external:extproj:cap", "tester")
+            .unwrap();
+        storage
+            .resolve_external_dependency_statuses_cached(&external_db_paths, true, cached)
+            .unwrap();
+        assert!(storage.get_metadata(&key).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_external_dependency_cache_skips_writes_on_read_only_db() {
+        let temp = TempDir::new().unwrap();
+        let beads_dir = temp.path().join("extproj").join(".beads");
+        fs::create_dir_all(&beads_dir).unwrap();
+        let db_path = beads_dir.join("beads.db");
+        let _external_storage = SqliteStorage::open(&db_path).unwrap();
+
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 3, 3, 0, 0, 0).unwrap();
+        let issue = make_issue("bd-e1", "Needs cap", Status::Open, 2, None, t1, None);
+        storage.create_issue(&issue, "tester").unwrap();
+        storage
+            .add_dependency("bd-e1", "external:extproj:cap", "blocks", "tester")
+            .unwrap();
+        storage
+            .conn
+            .execute_batch("PRAGMA query_only = ON")
+            .unwrap();
+
+        let mut external_db_paths = HashMap::new();
+        external_db_paths.insert("extproj".to_string(), db_path);
+        let cached = ExternalCachePolicy {
+            ttl: Some(Duration::from_secs(3600)),
+            refresh: false,
+        };
+        let statuses = storage
+            .resolve_external_dependency_statuses_cached(&external_db_paths, true, cached)
+            .expect("cache writes are best effort");
+        assert_eq!(statuses.get("external:extproj:cap"), Some(&false));
+        let key = format!("{EXTERNAL_CACHE_KEY_PREFIX}extproj");
+        assert!(storage.get_metadata(&key).unwrap().is_none());
+    }
+
     #[test]
     fn test_update_issue_changes_fields() {
         let mut storage = SqliteStorage::open_memory().unwrap();
@@ -4746,6 +5982,12 @@ mod tests {
         assert!(ids.contains(&"bd-old"));
         assert!(ids.contains(&"bd-new"));
         assert!(!ids.contains(&"bd-older"));
+
+        // search honors updated_after as well
+        let found = storage.search_issues("issue", &filters).unwrap();
+        let ids: Vec<_> = found.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids.len(), 2);
+        assert!(!ids.contains(&"bd-older"));
     }
 
     #[test]
@@ -4780,6 +6022,68 @@ mod tests {
         assert_eq!(issues[0].id, "bd-l1");
     }
 
+    #[test]
+    fn test_parse_list_predicate() {
+        let pred = parse_list_predicate("priority<=1").unwrap();
+        assert_eq!(pred.column, "priority");
+        assert_eq!(pred.op, CompareOp::Le);
+        assert_eq!(pred.value, PredicateValue::Integer(1));
+
+        let pred = parse_list_predicate("assignee != alice").unwrap();
+        assert_eq!(pred.op, CompareOp::Ne);
+        assert_eq!(pred.value, PredicateValue::Text("alice".to_string()));
+
+        let pred = parse_list_predicate("title=a=b").unwrap();
+        assert_eq!(pred.value, PredicateValue::Text("a=b".to_string()));
+
+        let pred = parse_list_predicate("assignee=").unwrap();
+        assert_eq!(pred.value, PredicateValue::Unset);
+
+        assert!(parse_list_predicate("priority").is_err());
+        assert!(parse_list_predicate("color=red").is_err());
+        assert!(parse_list_predicate("title<abc").is_err());
+        assert!(parse_list_predicate("priority>high").is_err());
+        assert!(parse_list_predicate("estimate>").is_err());
+    }
+
+    #[test]
+    fn test_list_issues_filter_by_predicates() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc::now();
+
+        let urgent = make_issue("bd-w1", "Urgent", Status::Open, 0, Some("alice"), t1, None);
+        let normal = make_issue("bd-w2", "Normal", Status::Open, 2, Some("bob"), t1, None);
+        let idle = make_issue("bd-w3", "Idle", Status::Open, 1, None, t1, None);
+        storage.create_issue(&urgent, "tester").unwrap();
+        storage.create_issue(&normal, "tester").unwrap();
+        storage.create_issue(&idle, "tester").unwrap();
+
+        let list = |storage: &SqliteStorage, specs: &[&str]| -> Vec<String> {
+            let filters = ListFilters {
+                predicates: specs
+                    .iter()
+                    .map(|spec| parse_list_predicate(spec).unwrap())
+                    .collect(),
+                ..Default::default()
+            };
+            let mut ids: Vec<String> = storage
+                .list_issues(&filters)
+                .unwrap()
+                .into_iter()
+                .map(|issue| issue.id)
+                .collect();
+            ids.sort();
+            ids
+        };
+
+        assert_eq!(list(&storage, &["priority<=1"]), ["bd-w1", "bd-w3"]);
+        assert_eq!(list(&storage, &["assignee=alice"]), ["bd-w1"]);
+        assert_eq!(list(&storage, &["assignee!=alice"]), ["bd-w2", "bd-w3"]);
+        assert_eq!(list(&storage, &["assignee="]), ["bd-w3"]);
+        assert_eq!(list(&storage, &["priority<2", "assignee!="]), ["bd-w1"]);
+        assert!(list(&storage, &["created_at>2100-01-01"]).is_empty());
+    }
+
     #[test]
     fn test_blocked_cache_handles_quotes_in_ids() {
         let mut storage = SqliteStorage::open_memory().unwrap();
@@ -5,6 +5,7 @@
 
 use chrono::{DateTime, Utc};
 use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
 
 /// Default ID generation configuration.
 #[derive(Debug, Clone)]
@@ -499,6 +500,10 @@ pub struct ResolverConfig {
     pub allowed_prefixes: Vec<String>,
     /// Whether to allow substring matching on hash portion.
     pub allow_substring_match: bool,
+    /// Reject inputs carrying a prefix other than `default_prefix` or one of
+    /// `allowed_prefixes` with `PrefixMismatch` instead of falling through to
+    /// hash matching (catches IDs pasted from another project).
+    pub strict_prefix: bool,
 }
 
 impl Default for ResolverConfig {
@@ -507,6 +512,7 @@ impl Default for ResolverConfig {
             default_prefix: "bd".to_string(),
             allowed_prefixes: Vec::new(),
             allow_substring_match: true,
+            strict_prefix: false,
         }
     }
 }
@@ -626,6 +632,24 @@ impl IdResolver {
             });
         }
 
+        // Step 1b: With strict prefixes, a foreign prefix is an error, not a hash hint
+        if self.config.strict_prefix {
+            if let Some((prefix, _)) = split_prefix_remainder(&normalized) {
+                if !prefix.eq_ignore_ascii_case(&self.config.default_prefix)
+                    && !self
+                        .config
+                        .allowed_prefixes
+                        .iter()
+                        .any(|p| prefix.eq_ignore_ascii_case(p))
+                {
+                    return Err(BeadsError::PrefixMismatch {
+                        expected: self.config.default_prefix.clone(),
+                        found: prefix.to_string(),
+                    });
+                }
+            }
+        }
+
         // Step 2: If no dash (missing prefix), prepend default prefix and retry
         if !normalized.contains('-') {
             let with_prefix = format!("{}-{}", self.config.default_prefix, normalized);
@@ -719,6 +743,60 @@ pub fn find_matching_ids(all_ids: &[String], hash_substring: &str) -> Vec<String
         .collect()
 }
 
+/// Compute the minimum hash-prefix length that uniquely identifies every issue.
+///
+/// At the returned length, the leading characters of each issue's hash resolve
+/// to that issue alone under substring resolution: no other hash *contains*
+/// them (not merely starts with them). A prefix that is the whole hash always
+/// counts as unique, since it resolves as an exact ID. Child IDs share their
+/// parent's base hash and are ignored; hashes are compared across all
+/// prefixes. If two issues share an identical base hash, the longest hash
+/// length is returned. Returns 0 when there are no IDs.
+#[must_use]
+pub fn min_unique_hash_len(all_ids: &[String]) -> usize {
+    let issues: HashSet<(&str, &str)> = all_ids
+        .iter()
+        .filter_map(|id| split_prefix_remainder(id))
+        .map(|(prefix, remainder)| (prefix, remainder.split('.').next().unwrap_or(remainder)))
+        .filter(|(_, hash)| !hash.is_empty())
+        .collect();
+    let hashes: Vec<&str> = issues.iter().map(|(_, hash)| *hash).collect();
+
+    let max_len = hashes
+        .iter()
+        .map(|hash| hash.chars().count())
+        .max()
+        .unwrap_or(0);
+    for len in 1..=max_len {
+        // Number of hashes containing each `len`-character substring
+        let mut containing: HashMap<&str, usize> = HashMap::new();
+        for &hash in &hashes {
+            let windows: HashSet<&str> = char_windows(hash, len).collect();
+            for window in windows {
+                *containing.entry(window).or_default() += 1;
+            }
+        }
+        let unique = hashes.iter().all(|&hash| {
+            let prefix = char_windows(hash, len).next().unwrap_or(hash);
+            prefix == hash || containing.get(prefix) == Some(&1)
+        });
+        if unique {
+            return len;
+        }
+    }
+    max_len
+}
+
+/// Iterate over every `len`-character substring of `s`, in order.
+fn char_windows(s: &str, len: usize) -> impl Iterator<Item = &str> {
+    let bounds: Vec<usize> = s
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(s.len()))
+        .collect();
+    (0..bounds.len().saturating_sub(len)).map(move |start| &s[bounds[start]..bounds[start + len]])
+}
+
 /// Quick helper to resolve a single ID with default settings.
 ///
 /// This is useful for simple cases where you just need to resolve one ID.
@@ -854,6 +932,61 @@ mod tests {
         assert_eq!(result.match_type, MatchType::PrefixNormalized);
     }
 
+    #[test]
+    fn test_resolve_strict_prefix() {
+        let mut config = ResolverConfig::with_prefix("bd");
+        config.strict_prefix = true;
+        config.allowed_prefixes = vec!["ops".to_string()];
+        let resolver = IdResolver::new(config);
+
+        // Matching prefix and bare hashes still resolve
+        let result = resolver
+            .resolve("bd-xyz789", exists_in_mock, substring_in_mock)
+            .unwrap();
+        assert_eq!(result.id, "bd-xyz789");
+        let result = resolver
+            .resolve("xyz", exists_in_mock, substring_in_mock)
+            .unwrap();
+        assert_eq!(result.id, "bd-xyz789");
+
+        // Foreign prefix is rejected instead of hash-matching bd-xyz789
+        let result = resolver.resolve("other-xyz789", exists_in_mock, substring_in_mock);
+        match result {
+            Err(BeadsError::PrefixMismatch { expected, found }) => {
+                assert_eq!(expected, "bd");
+                assert_eq!(found, "other");
+            }
+            other => panic!("Expected PrefixMismatch, got {other:?}"),
+        }
+
+        // Allowed extra prefixes fall through to normal resolution
+        let result = resolver.resolve("ops-xyz789", exists_in_mock, substring_in_mock);
+        assert_eq!(result.unwrap().id, "bd-xyz789");
+
+        // Prefixes compare case-insensitively on both sides
+        let mut config = ResolverConfig::with_prefix("BD");
+        config.strict_prefix = true;
+        config.allowed_prefixes = vec!["Ops".to_string()];
+        let mixed_case = IdResolver::new(config);
+        for input in ["BD-xyz789", "bd-xyz789", "OPS-xyz789"] {
+            let result = mixed_case
+                .resolve(input, exists_in_mock, substring_in_mock)
+                .unwrap();
+            assert_eq!(result.id, "bd-xyz789", "input {input}");
+        }
+        assert!(matches!(
+            mixed_case.resolve("OTHER-xyz789", exists_in_mock, substring_in_mock),
+            Err(BeadsError::PrefixMismatch { .. })
+        ));
+
+        // Without strict mode the foreign prefix hash-matches as before
+        let lenient = IdResolver::with_defaults();
+        let result = lenient
+            .resolve("other-xyz789", exists_in_mock, substring_in_mock)
+            .unwrap();
+        assert_eq!(result.id, "bd-xyz789");
+    }
+
     #[test]
     fn test_resolve_empty_input() {
         let resolver = IdResolver::with_defaults();
@@ -886,6 +1019,44 @@ mod tests {
         assert!(matches.is_empty());
     }
 
+    #[test]
+    fn test_min_unique_hash_len_with_colliding_prefixes() {
+        let ids = vec![
+            "bd-abc123".to_string(),
+            "bd-abd456".to_string(),
+            "bd-xyz789".to_string(),
+            "bd-abc123.1".to_string(),
+        ];
+        // "abc" and "abd" share two characters, so three are needed.
+        assert_eq!(min_unique_hash_len(&ids), 3);
+
+        let ids = vec!["bd-abcde1".to_string(), "other-abcde2".to_string()];
+        assert_eq!(min_unique_hash_len(&ids), 6);
+
+        let ids = vec!["bd-a1".to_string(), "bd-b2".to_string()];
+        assert_eq!(min_unique_hash_len(&ids), 1);
+
+        assert_eq!(min_unique_hash_len(&[]), 0);
+    }
+
+    #[test]
+    fn test_min_unique_hash_len_accounts_for_substring_matches() {
+        // "a1" is a prefix of one hash but a substring of both.
+        let ids = vec!["bd-a1b".to_string(), "bd-xa1".to_string()];
+        assert_eq!(min_unique_hash_len(&ids), 3);
+        assert_eq!(find_matching_ids(&ids, "a1").len(), 2);
+
+        // The full short hash still resolves exactly.
+        let ids = vec!["bd-a1".to_string(), "bd-xa1b".to_string()];
+        assert_eq!(min_unique_hash_len(&ids), 2);
+    }
+
+    #[test]
+    fn test_min_unique_hash_len_identical_hashes() {
+        let ids = vec!["bd-abc".to_string(), "other-abc".to_string()];
+        assert_eq!(min_unique_hash_len(&ids), 3);
+    }
+
     // ========================================================================
     // Original Tests
     // ========================================================================
@@ -12,22 +12,27 @@ mod hash;
 pub mod id;
 pub mod markdown_import;
 pub mod progress;
+pub mod score;
 pub mod time;
 
 pub use hash::{ContentHashable, content_hash, content_hash_from_parts};
 pub use id::{
     IdConfig, IdGenerator, IdResolver, MatchType, ParsedId, ResolvedId, ResolverConfig, child_id,
-    find_matching_ids, generate_id, id_depth, is_child_id, is_valid_id_format, normalize_id,
-    parse_id, resolve_id, validate_prefix,
+    find_matching_ids, generate_id, id_depth, is_child_id, is_valid_id_format, min_unique_hash_len,
+    normalize_id, parse_id, resolve_id, validate_prefix,
 };
 
 use std::env;
 use std::fs::{self, OpenOptions};
 use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 
 const LAST_TOUCHED_FILE: &str = "last-touched";
 
+/// Set by `--no-last-touched`; makes `set_last_touched_id` a no-op for this process.
+static LAST_TOUCHED_WRITES_DISABLED: AtomicBool = AtomicBool::new(false);
+
 /// Environment variable for overriding the cache directory location.
 ///
 /// When set, transient files like `last-touched` will be stored in this
@@ -62,11 +67,23 @@ pub fn last_touched_path(beads_dir: &Path) -> PathBuf {
     resolve_cache_dir(beads_dir).join(LAST_TOUCHED_FILE)
 }
 
+/// Stop writing the last-touched marker for the rest of this process.
+///
+/// Reads via `get_last_touched_id` are unaffected.
+pub fn disable_last_touched_writes() {
+    LAST_TOUCHED_WRITES_DISABLED.store(true, Ordering::Relaxed);
+}
+
 /// Best-effort write of the last-touched issue ID.
 ///
 /// Errors are ignored to match classic bd behavior.
 /// If `BEADS_CACHE_DIR` is set, the cache directory will be created if needed.
+/// Does nothing after `disable_last_touched_writes` has been called.
 pub fn set_last_touched_id(beads_dir: &Path, id: &str) {
+    if LAST_TOUCHED_WRITES_DISABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
     let path = last_touched_path(beads_dir);
 
     // Ensure cache directory exists (best-effort)
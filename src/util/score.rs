@@ -0,0 +1,300 @@
+//! Arithmetic scoring expressions for `br ready --weighted-sort`.
+//!
+//! Expressions are parsed into a small AST and evaluated per issue; only
+//! numbers, a fixed set of fields, parentheses, and `+ - * /` are accepted.
+//!
+//! `priority` is the raw P value (P0 = 0, P4 = 4), matching how priority is
+//! written everywhere else. `urgency` is `4 - P` (P0 = 4, P4 = 0), for
+//! expressions where "higher score first" should mean "more important first".
+
+use crate::error::{BeadsError, Result};
+
+const GRAMMAR: &str = "expected an expression over priority, urgency, age_days, \
+     dependent_count and numbers using + - * / and parentheses (e.g. 'urgency*10 + age_days')";
+
+/// Nesting limit so pathological input cannot exhaust the stack.
+const MAX_DEPTH: usize = 64;
+
+/// Token limit; it also bounds the tree size, so long `1+1+...` chains cannot
+/// recurse deeply in `eval` or `Drop`.
+const MAX_TOKENS: usize = 256;
+
+/// Per-issue values an expression is evaluated against.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ScoreInputs {
+    /// Raw priority `P` (P0 = 0, P4 = 4).
+    pub priority: f64,
+    /// Urgency: `4 - P` (P0 = 4, P4 = 0).
+    pub urgency: f64,
+    pub age_days: f64,
+    pub dependent_count: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Priority,
+    Urgency,
+    AgeDays,
+    DependentCount,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+/// A parsed scoring expression.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoreExpr(Node);
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Number(f64),
+    Field(Field),
+    Neg(Box<Self>),
+    Binary(Box<Self>, BinOp, Box<Self>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Ident(String),
+    Op(char),
+    LParen,
+    RParen,
+}
+
+impl ScoreExpr {
+    /// Parse an expression such as `urgency*10 + age_days`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error naming the allowed grammar if the input has
+    /// unknown fields, stray characters, or unbalanced parentheses.
+    pub fn parse(input: &str) -> Result<Self> {
+        let tokens = tokenize(input)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let expr = parser.expr(0)?;
+        if parser.pos < parser.tokens.len() {
+            return Err(invalid(format!(
+                "unexpected '{}'",
+                parser.tokens[parser.pos].text()
+            )));
+        }
+        Ok(Self(expr))
+    }
+
+    /// Evaluate against one issue's inputs. Division by zero yields 0.
+    #[must_use]
+    pub fn eval(&self, inputs: &ScoreInputs) -> f64 {
+        self.0.eval(inputs)
+    }
+}
+
+impl Node {
+    fn eval(&self, inputs: &ScoreInputs) -> f64 {
+        match self {
+            Self::Number(value) => *value,
+            Self::Field(Field::Priority) => inputs.priority,
+            Self::Field(Field::Urgency) => inputs.urgency,
+            Self::Field(Field::AgeDays) => inputs.age_days,
+            Self::Field(Field::DependentCount) => inputs.dependent_count,
+            Self::Neg(inner) => -inner.eval(inputs),
+            Self::Binary(lhs, op, rhs) => {
+                let (lhs, rhs) = (lhs.eval(inputs), rhs.eval(inputs));
+                match op {
+                    BinOp::Add => lhs + rhs,
+                    BinOp::Sub => lhs - rhs,
+                    BinOp::Mul => lhs * rhs,
+                    BinOp::Div if rhs == 0.0 => 0.0,
+                    BinOp::Div => lhs / rhs,
+                }
+            }
+        }
+    }
+}
+
+impl Token {
+    fn text(&self) -> String {
+        match self {
+            Self::Number(value) => value.to_string(),
+            Self::Ident(name) => name.clone(),
+            Self::Op(op) => op.to_string(),
+            Self::LParen => "(".to_string(),
+            Self::RParen => ")".to_string(),
+        }
+    }
+}
+
+fn invalid(reason: impl std::fmt::Display) -> BeadsError {
+    BeadsError::validation("weighted-sort", format!("{reason}; {GRAMMAR}"))
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c.is_ascii_digit() || c == '.' {
+            let mut literal = String::new();
+            while let Some(&d) = chars.peek().filter(|d| d.is_ascii_digit() || **d == '.') {
+                literal.push(d);
+                chars.next();
+            }
+            let value = literal
+                .parse::<f64>()
+                .map_err(|_| invalid(format!("invalid number '{literal}'")))?;
+            tokens.push(Token::Number(value));
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            let mut ident = String::new();
+            while let Some(&d) = chars
+                .peek()
+                .filter(|d| d.is_ascii_alphanumeric() || **d == '_')
+            {
+                ident.push(d);
+                chars.next();
+            }
+            tokens.push(Token::Ident(ident));
+        } else {
+            tokens.push(match c {
+                '+' | '-' | '*' | '/' => Token::Op(c),
+                '(' => Token::LParen,
+                ')' => Token::RParen,
+                other => return Err(invalid(format!("unexpected character '{other}'"))),
+            });
+            chars.next();
+        }
+    }
+    if tokens.is_empty() {
+        return Err(invalid("empty expression"));
+    }
+    if tokens.len() > MAX_TOKENS {
+        return Err(invalid(format!(
+            "expression too long (more than {MAX_TOKENS} tokens)"
+        )));
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek_op(&self, ops: &[char]) -> Option<char> {
+        match self.tokens.get(self.pos) {
+            Some(Token::Op(op)) if ops.contains(op) => Some(*op),
+            _ => None,
+        }
+    }
+
+    fn expr(&mut self, depth: usize) -> Result<Node> {
+        let mut lhs = self.term(depth)?;
+        while let Some(op) = self.peek_op(&['+', '-']) {
+            self.pos += 1;
+            let rhs = self.term(depth)?;
+            let op = if op == '+' { BinOp::Add } else { BinOp::Sub };
+            lhs = Node::Binary(Box::new(lhs), op, Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn term(&mut self, depth: usize) -> Result<Node> {
+        let mut lhs = self.factor(depth)?;
+        while let Some(op) = self.peek_op(&['*', '/']) {
+            self.pos += 1;
+            let rhs = self.factor(depth)?;
+            let op = if op == '*' { BinOp::Mul } else { BinOp::Div };
+            lhs = Node::Binary(Box::new(lhs), op, Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn factor(&mut self, depth: usize) -> Result<Node> {
+        if depth > MAX_DEPTH {
+            return Err(invalid("expression nested too deeply"));
+        }
+        let token = self
+            .tokens
+            .get(self.pos)
+            .cloned()
+            .ok_or_else(|| invalid("unexpected end of expression"))?;
+        self.pos += 1;
+        match token {
+            Token::Number(value) => Ok(Node::Number(value)),
+            Token::Ident(name) => match name.as_str() {
+                "priority" => Ok(Node::Field(Field::Priority)),
+                "urgency" => Ok(Node::Field(Field::Urgency)),
+                "age_days" => Ok(Node::Field(Field::AgeDays)),
+                "dependent_count" => Ok(Node::Field(Field::DependentCount)),
+                _ => Err(invalid(format!("unknown field '{name}'"))),
+            },
+            Token::Op('-') => Ok(Node::Neg(Box::new(self.factor(depth + 1)?))),
+            Token::LParen => {
+                let inner = self.expr(depth + 1)?;
+                if self.tokens.get(self.pos) != Some(&Token::RParen) {
+                    return Err(invalid("missing ')'"));
+                }
+                self.pos += 1;
+                Ok(inner)
+            }
+            other => Err(invalid(format!("unexpected '{}'", other.text()))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval(expr: &str, inputs: &ScoreInputs) -> f64 {
+        ScoreExpr::parse(expr).expect("parse").eval(inputs)
+    }
+
+    #[test]
+    fn test_score_expr_precedence_and_fields() {
+        let inputs = ScoreInputs {
+            priority: 2.0,
+            urgency: 2.5,
+            age_days: 5.0,
+            dependent_count: 3.0,
+        };
+        assert!((eval("priority*10 + age_days", &inputs) - 25.0).abs() < f64::EPSILON);
+        assert!((eval("(priority + 1) * 2", &inputs) - 6.0).abs() < f64::EPSILON);
+        assert!((eval("-priority + dependent_count / 2", &inputs) + 0.5).abs() < f64::EPSILON);
+        assert!((eval("age_days / 0", &inputs)).abs() < f64::EPSILON);
+        assert!((eval("0.5 * age_days - 1", &inputs) - 1.5).abs() < f64::EPSILON);
+        assert!((eval("urgency * 2 - priority", &inputs) - 3.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_score_expr_rejects_invalid_input() {
+        for bad in [
+            "",
+            "title * 2",
+            "priority +",
+            "(priority",
+            "priority; rm",
+            "1..2",
+            "priority age_days",
+        ] {
+            let err = ScoreExpr::parse(bad).unwrap_err();
+            assert!(
+                err.to_string().contains("priority, urgency, age_days"),
+                "{bad}: {err}"
+            );
+        }
+        assert!(ScoreExpr::parse(&"(".repeat(200)).is_err());
+
+        let long_chain = vec!["1"; 100_000].join("+");
+        let err = ScoreExpr::parse(&long_chain).unwrap_err();
+        assert!(err.to_string().contains("too long"), "{err}");
+        let within_limit = vec!["1"; 100].join("+");
+        assert!((eval(&within_limit, &ScoreInputs::default()) - 100.0).abs() < f64::EPSILON);
+    }
+}
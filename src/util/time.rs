@@ -95,6 +95,41 @@ pub fn parse_flexible_timestamp(s: &str, field_name: &str) -> Result<DateTime<Ut
     }
 }
 
+/// Parse a duration such as `30m`, `24h`, `7d`, or `2w`.
+///
+/// Used for look-back windows like `--updated-since`; a leading `+` is accepted.
+///
+/// # Errors
+///
+/// Returns an error if the amount is not a non-negative integer, the unit
+/// is not one of m, h, d, w, or the duration is out of range.
+pub fn parse_duration_spec(s: &str, field_name: &str) -> Result<Duration> {
+    let s = s.trim();
+    let rest = s.strip_prefix('+').unwrap_or(s);
+    let invalid = || {
+        BeadsError::validation(
+            field_name,
+            format!("invalid duration '{s}' (try: 30m, 24h, 7d, 2w)"),
+        )
+    };
+    let unit_char = rest.chars().last().ok_or_else(invalid)?;
+    let amount = rest[..rest.len() - unit_char.len_utf8()]
+        .parse::<i64>()
+        .ok()
+        .filter(|amount| *amount >= 0)
+        .ok_or_else(invalid)?;
+    let duration = match unit_char.to_ascii_lowercase() {
+        'm' => Duration::try_minutes(amount),
+        'h' => Duration::try_hours(amount),
+        'd' => Duration::try_days(amount),
+        'w' => Duration::try_weeks(amount),
+        _ => return Err(invalid()),
+    };
+    duration.ok_or_else(|| {
+        BeadsError::validation(field_name, format!("duration '{s}' is out of range"))
+    })
+}
+
 /// Parse a relative time expression into a `DateTime<Utc>`.
 ///
 /// Supports:
@@ -198,4 +233,29 @@ mod tests {
         assert!(parse_relative_time("invalid").is_none());
         assert!(parse_relative_time("2025-01-15").is_none());
     }
+
+    #[test]
+    fn test_parse_duration_spec() {
+        assert_eq!(
+            parse_duration_spec("30m", "test").unwrap(),
+            Duration::minutes(30)
+        );
+        assert_eq!(
+            parse_duration_spec("+24h", "test").unwrap(),
+            Duration::hours(24)
+        );
+        assert_eq!(
+            parse_duration_spec("7d", "test").unwrap(),
+            Duration::days(7)
+        );
+        assert_eq!(
+            parse_duration_spec("2W", "test").unwrap(),
+            Duration::weeks(2)
+        );
+        assert!(parse_duration_spec("-1d", "test").is_err());
+        assert!(parse_duration_spec("1y", "test").is_err());
+        assert!(parse_duration_spec("d", "test").is_err());
+        assert!(parse_duration_spec("", "test").is_err());
+        assert!(parse_duration_spec("99999999999999w", "test").is_err());
+    }
 }
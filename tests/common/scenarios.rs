@@ -1,9 +1,10 @@
 //! Scenario DSL for unified E2E, Conformance, and Benchmark testing.
 //!
-//! This module defines a `Scenario` struct that can drive tests in three modes:
+//! This module defines a `Scenario` struct that can drive tests in four modes:
 //! - **E2E**: Run `br` commands only, validate exit codes and JSON shapes
 //! - **Conformance**: Run both `br` and `bd`, compare outputs with normalization
 //! - **Benchmark**: Time commands, capture RSS, produce metrics
+//! - **Stress**: Run opt-in stress scenarios through the E2E path
 //!
 //! Related beads:
 //! - beads_rust-ir0t: Scenario DSL + normalization rules for conformance
@@ -34,6 +35,34 @@ pub enum ExecutionMode {
     Conformance,
     /// Benchmark mode: time execution, capture metrics
     Benchmark,
+    /// Stress mode: run br only against scenarios that opt in via `with_modes`
+    Stress,
+}
+
+impl ExecutionMode {
+    /// Read the mode from `SCENARIO_MODE` (e2e, conformance, benchmark, stress).
+    ///
+    /// Returns `None` when the variable is unset or unrecognized.
+    pub fn from_env() -> Option<Self> {
+        let value = std::env::var("SCENARIO_MODE").ok()?;
+        match value.trim().to_lowercase().as_str() {
+            "e2e" => Some(Self::E2E),
+            "conformance" => Some(Self::Conformance),
+            "benchmark" => Some(Self::Benchmark),
+            "stress" => Some(Self::Stress),
+            _ => None,
+        }
+    }
+
+    /// Name as accepted by `SCENARIO_MODE`.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::E2E => "e2e",
+            Self::Conformance => "conformance",
+            Self::Benchmark => "benchmark",
+            Self::Stress => "stress",
+        }
+    }
 }
 
 /// How to compare JSON outputs in conformance mode.
@@ -1131,11 +1160,13 @@ impl Scenario {
 /// - If `include_tags` is non-empty, only scenarios with matching tags are selected
 /// - If `exclude_tags` is non-empty, scenarios with any excluded tag are skipped
 /// - Exclude takes precedence over include
+/// - If `mode` is set, only scenarios supporting that mode are selected
 ///
 /// Environment variables:
 /// - `HARNESS_TAGS`: comma-separated list of tags to include (e.g., "quick,crud")
 /// - `HARNESS_EXCLUDE_TAGS`: comma-separated list of tags to exclude (e.g., "slow,stress")
 /// - `HARNESS_TAG_MATCH`: "any" (default) or "all" for include matching
+/// - `SCENARIO_MODE`: only select scenarios supporting this mode (e.g., "stress")
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ScenarioFilter {
     /// Tags to include (scenario must have at least one, or all if `match_mode` is All)
@@ -1144,6 +1175,9 @@ pub struct ScenarioFilter {
     pub exclude_tags: Vec<String>,
     /// Match mode for include tags: "any" (default) or "all"
     pub match_mode: TagMatchMode,
+    /// Only select scenarios that support this mode
+    #[serde(default)]
+    pub mode: Option<ExecutionMode>,
 }
 
 /// How to match include tags.
@@ -1169,6 +1203,7 @@ impl ScenarioFilter {
     /// - `HARNESS_TAGS`: comma-separated include tags
     /// - `HARNESS_EXCLUDE_TAGS`: comma-separated exclude tags
     /// - `HARNESS_TAG_MATCH`: "any" or "all"
+    /// - `SCENARIO_MODE`: e2e, conformance, benchmark, or stress
     pub fn from_env() -> Self {
         let include_tags = std::env::var("HARNESS_TAGS")
             .ok()
@@ -1202,6 +1237,7 @@ impl ScenarioFilter {
             include_tags,
             exclude_tags,
             match_mode,
+            mode: ExecutionMode::from_env(),
         }
     }
 
@@ -1231,8 +1267,20 @@ impl ScenarioFilter {
         self
     }
 
+    /// Builder: only select scenarios supporting `mode`.
+    pub const fn with_mode(mut self, mode: ExecutionMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
     /// Check if a scenario matches this filter.
     pub fn matches(&self, scenario: &Scenario) -> bool {
+        if let Some(mode) = self.mode {
+            if !scenario.supports_mode(mode) {
+                return false;
+            }
+        }
+
         // Check exclude tags first (takes precedence)
         for tag in &self.exclude_tags {
             if scenario.has_tag(tag) {
@@ -1259,13 +1307,17 @@ impl ScenarioFilter {
 
     /// Check if filter is empty (matches all scenarios).
     pub fn is_empty(&self) -> bool {
-        self.include_tags.is_empty() && self.exclude_tags.is_empty()
+        self.include_tags.is_empty() && self.exclude_tags.is_empty() && self.mode.is_none()
     }
 
     /// Get a human-readable description of the filter.
     pub fn description(&self) -> String {
         let mut parts = Vec::new();
 
+        if let Some(mode) = self.mode {
+            parts.push(format!("mode {}", mode.as_str()));
+        }
+
         if !self.include_tags.is_empty() {
             let mode = match self.match_mode {
                 TagMatchMode::Any => "any of",
@@ -1295,6 +1347,7 @@ impl ScenarioFilter {
             "include_tags": self.include_tags,
             "exclude_tags": self.exclude_tags,
             "match_mode": self.match_mode,
+            "mode": self.mode.map(ExecutionMode::as_str),
             "description": self.description(),
         })
     }
@@ -1316,6 +1369,19 @@ impl ScenarioRunner {
         }
     }
 
+    /// Create a runner for `SCENARIO_MODE` (default e2e) that only selects
+    /// scenarios supporting that mode, so `SCENARIO_MODE=stress` runs the
+    /// stress scenarios.
+    pub fn from_env() -> Self {
+        let mode = ExecutionMode::from_env().unwrap_or(ExecutionMode::E2E);
+        Self::new(mode).with_filter(ScenarioFilter::from_env().with_mode(mode))
+    }
+
+    /// Get the execution mode.
+    pub const fn mode(&self) -> ExecutionMode {
+        self.mode
+    }
+
     pub const fn with_artifacts(mut self, enabled: bool) -> Self {
         self.artifacts_enabled = enabled;
         self
@@ -1377,7 +1443,8 @@ impl ScenarioRunner {
         }
 
         match self.mode {
-            ExecutionMode::E2E => self.run_e2e(scenario),
+            // Stress scenarios validate br behavior under load via the E2E path
+            ExecutionMode::E2E | ExecutionMode::Stress => self.run_e2e(scenario),
             ExecutionMode::Conformance => self.run_conformance(scenario),
             ExecutionMode::Benchmark => self.run_benchmark(scenario),
         }
@@ -2637,6 +2704,32 @@ mod tests {
         assert_eq!(filtered[1].name, "quick2");
     }
 
+    #[test]
+    fn test_scenario_filter_mode_selects_stress_scenarios() {
+        let filter = ScenarioFilter::new().with_mode(ExecutionMode::Stress);
+
+        let scenarios = vec![
+            Scenario::new("quick", ScenarioCommand::new(["list"])).with_tags(["quick"]),
+            Scenario::new("stress1", ScenarioCommand::new(["list"]))
+                .with_tags(["stress"])
+                .with_modes(vec![ExecutionMode::Stress]),
+            Scenario::new("stress2", ScenarioCommand::new(["ready"]))
+                .with_modes(vec![ExecutionMode::E2E, ExecutionMode::Stress]),
+        ];
+
+        let filtered = filter.filter(&scenarios);
+        let names: Vec<_> = filtered.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["stress1", "stress2"]);
+        assert!(!filter.is_empty());
+        assert_eq!(filter.description(), "mode stress");
+        assert_eq!(filter.to_json()["mode"], serde_json::json!("stress"));
+
+        let runner = ScenarioRunner::new(ExecutionMode::Stress).with_filter(filter);
+        assert_eq!(runner.mode(), ExecutionMode::Stress);
+        assert!(!runner.should_run(&scenarios[0]));
+        assert!(runner.should_run(&scenarios[1]));
+    }
+
     #[test]
     fn test_tag_match_mode_default() {
         let mode = TagMatchMode::default();
@@ -3296,10 +3389,25 @@ mod tests {
     fn test_scenario_default_modes() {
         let scenario = Scenario::new("test", ScenarioCommand::new(["list"]));
 
-        // Default includes E2E and Conformance but not Benchmark
+        // Default includes E2E and Conformance but not Benchmark or Stress
         assert!(scenario.supports_mode(ExecutionMode::E2E));
         assert!(scenario.supports_mode(ExecutionMode::Conformance));
         assert!(!scenario.supports_mode(ExecutionMode::Benchmark));
+        assert!(!scenario.supports_mode(ExecutionMode::Stress));
+    }
+
+    #[test]
+    fn test_scenario_stress_mode_opt_in() {
+        let scenario = Scenario::new("stress", ScenarioCommand::new(["list"]))
+            .with_tags(["stress"])
+            .with_modes(vec![ExecutionMode::Stress]);
+
+        assert!(scenario.supports_mode(ExecutionMode::Stress));
+        assert!(!scenario.supports_mode(ExecutionMode::E2E));
+        assert_eq!(
+            serde_json::to_value(ExecutionMode::Stress).unwrap(),
+            serde_json::json!("stress")
+        );
     }
 
     #[test]
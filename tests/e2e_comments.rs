@@ -60,6 +60,41 @@ fn e2e_comments_add_single_and_list() {
     );
 }
 
+/// Test 1b: --time-format applies to comment timestamps
+#[test]
+fn e2e_comments_list_respects_time_format() {
+    let _log = common::test_log("e2e_comments_list_respects_time_format");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    let create = run_br(&workspace, ["create", "Timestamped"], "create");
+    assert!(create.status.success(), "create failed: {}", create.stderr);
+    let id = parse_created_id(&create.stdout);
+    let add = run_br(&workspace, ["comments", "add", &id, "hello"], "add_comment");
+    assert!(add.status.success(), "add comment failed: {}", add.stderr);
+
+    let default = run_br(&workspace, ["comments", "list", &id], "list_default");
+    assert!(default.status.success(), "list failed: {}", default.stderr);
+    assert!(default.stdout.contains(" UTC"), "{}", default.stdout);
+
+    let rfc3339 = run_br(
+        &workspace,
+        ["comments", "list", &id, "--time-format", "rfc3339"],
+        "list_rfc3339",
+    );
+    assert!(rfc3339.status.success(), "list failed: {}", rfc3339.stderr);
+    let stamp_line = rfc3339
+        .stdout
+        .lines()
+        .find(|line| line.contains(" at "))
+        .expect("timestamp line");
+    assert!(
+        stamp_line.ends_with('Z') && !stamp_line.contains(" UTC"),
+        "expected RFC3339 timestamp: {stamp_line}"
+    );
+}
+
 /// Test 2: Add multiple comments, verify order (newest last)
 #[test]
 fn e2e_comments_add_multiple_verify_order() {
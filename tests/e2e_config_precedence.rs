@@ -278,3 +278,200 @@ fn e2e_config_precedence_legacy_used_when_user_missing() {
     );
     assert_eq!(get_user.stdout.trim(), "2");
 }
+
+#[test]
+fn e2e_config_import_normalizes_keys_and_honors_conflict_policy() {
+    let _log = common::test_log("e2e_config_import_normalizes_keys_and_honors_conflict_policy");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let project_config = workspace.root.join(".beads").join("config.yaml");
+    fs::write(&project_config, "issue-prefix: OLD\n").expect("write project config");
+
+    let import_file = workspace.root.join("shared.yaml");
+    fs::write(&import_file, "issue_prefix: NEW\ndefault_priority: '1'\n")
+        .expect("write import file");
+    let import_path = import_file.to_string_lossy().to_string();
+
+    let skip = run_br(
+        &workspace,
+        ["config", "import", &import_path],
+        "import_skip",
+    );
+    assert!(skip.status.success(), "import failed: {}", skip.stderr);
+    let contents = fs::read_to_string(&project_config).expect("read project config");
+    assert!(contents.contains("issue-prefix: OLD"), "got: {contents}");
+    assert!(!contents.contains("issue_prefix"), "got: {contents}");
+    assert!(contents.contains("default_priority"), "got: {contents}");
+
+    let conflict = run_br(
+        &workspace,
+        ["config", "import", &import_path, "--on-conflict", "error"],
+        "import_error",
+    );
+    assert!(!conflict.status.success(), "expected conflict failure");
+
+    let overwrite = run_br(
+        &workspace,
+        [
+            "config",
+            "import",
+            &import_path,
+            "--on-conflict",
+            "overwrite",
+        ],
+        "import_overwrite",
+    );
+    assert!(
+        overwrite.status.success(),
+        "import failed: {}",
+        overwrite.stderr
+    );
+    let contents = fs::read_to_string(&project_config).expect("read project config");
+    assert!(contents.contains("issue-prefix: NEW"), "got: {contents}");
+    assert!(!contents.contains("issue_prefix"), "got: {contents}");
+
+    let export = run_br(
+        &workspace,
+        ["config", "export", "--project"],
+        "export_project",
+    );
+    assert!(export.status.success(), "export failed: {}", export.stderr);
+    assert!(
+        export.stdout.contains("issue-prefix: NEW"),
+        "got: {}",
+        export.stdout
+    );
+}
+
+#[test]
+fn e2e_config_export_import_round_trip_dedupes_spellings() {
+    let _log = common::test_log("e2e_config_export_import_round_trip_dedupes_spellings");
+    let source = BrWorkspace::new();
+    let init = run_br(&source, ["init"], "init_source");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    fs::write(
+        source.root.join(".beads").join("config.yaml"),
+        "issue_prefix: abc\nissue-prefix: abc\nallowed-prefixes: [abc, xyz]\nmax-hash-length: 6\n",
+    )
+    .expect("write project config");
+
+    let export = run_br(&source, ["config", "export", "--project"], "export_project");
+    assert!(export.status.success(), "export failed: {}", export.stderr);
+    assert_eq!(
+        export.stdout.matches("prefix: abc").count(),
+        1,
+        "got: {}",
+        export.stdout
+    );
+    assert!(
+        !export.stdout.contains("issue_prefix"),
+        "got: {}",
+        export.stdout
+    );
+    assert!(export.stdout.contains("- xyz"), "got: {}", export.stdout);
+    assert!(
+        export.stdout.contains("max-hash-length: 6\n"),
+        "got: {}",
+        export.stdout
+    );
+
+    let merged = run_br(&source, ["config", "export"], "export_merged");
+    assert!(merged.status.success(), "export failed: {}", merged.stderr);
+    assert_eq!(
+        merged.stdout.matches("issue-prefix:").count()
+            + merged.stdout.matches("issue_prefix:").count(),
+        1,
+        "got: {}",
+        merged.stdout
+    );
+
+    let export_file = source.root.join("exported.yaml");
+    fs::write(&export_file, &export.stdout).expect("write export file");
+    let export_path = export_file.to_string_lossy().to_string();
+
+    let target = BrWorkspace::new();
+    let init = run_br(&target, ["init"], "init_target");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    let import = run_br(
+        &target,
+        [
+            "config",
+            "import",
+            &export_path,
+            "--on-conflict",
+            "overwrite",
+        ],
+        "import",
+    );
+    assert!(import.status.success(), "import failed: {}", import.stderr);
+    let contents = fs::read_to_string(target.root.join(".beads").join("config.yaml"))
+        .expect("read project config");
+    assert!(!contents.contains("issue_prefix"), "got: {contents}");
+    assert_eq!(
+        contents.matches("issue-prefix: abc").count(),
+        1,
+        "got: {contents}"
+    );
+    assert!(contents.contains("- xyz"), "got: {contents}");
+    assert!(contents.contains("max-hash-length: 6\n"), "got: {contents}");
+}
+
+#[test]
+fn e2e_config_get_default_flag() {
+    let _log = common::test_log("e2e_config_get_default_flag");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let missing = run_br(
+        &workspace,
+        ["config", "get", "no-such-key", "--default", "fallback"],
+        "get_default",
+    );
+    assert!(
+        missing.status.success(),
+        "config get --default failed: {}",
+        missing.stderr
+    );
+    assert_eq!(missing.stdout.trim(), "fallback");
+
+    let json = run_br(
+        &workspace,
+        [
+            "config",
+            "get",
+            "no-such-key",
+            "--default",
+            "fallback",
+            "--json",
+        ],
+        "get_default_json",
+    );
+    assert!(
+        json.status.success(),
+        "config get --json failed: {}",
+        json.stderr
+    );
+    let payload: serde_json::Value = serde_json::from_str(json.stdout.trim()).expect("parse json");
+    assert_eq!(payload["value"], "fallback");
+    assert_eq!(payload["source"], "default-flag");
+
+    // A set key ignores the fallback
+    let project_config = workspace.root.join(".beads").join("config.yaml");
+    fs::write(&project_config, "issue_prefix: REAL\n").expect("write project config");
+    let present = run_br(
+        &workspace,
+        ["config", "get", "issue_prefix", "--default", "fallback"],
+        "get_present",
+    );
+    assert!(
+        present.status.success(),
+        "config get failed: {}",
+        present.stderr
+    );
+    assert_eq!(present.stdout.trim(), "REAL");
+}
@@ -896,3 +896,64 @@ fn e2e_no_color_across_commands() {
         );
     }
 }
+
+// ============================================================================
+// --no-last-touched flag tests
+// ============================================================================
+
+#[test]
+fn e2e_no_last_touched_preserves_marker() {
+    let _log = common::test_log("e2e_no_last_touched_preserves_marker");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let mut ids = Vec::new();
+    for title in ["Interactive issue", "Scripted issue"] {
+        let create = run_br(&workspace, ["create", title, "--json"], "create");
+        assert!(create.status.success(), "create failed: {}", create.stderr);
+        let payload = extract_json_payload(&create.stdout);
+        let json: Value = serde_json::from_str(&payload).expect("create json");
+        ids.push(json["id"].as_str().expect("id").to_string());
+    }
+
+    let touch = run_br(
+        &workspace,
+        ["update", &ids[0], "--priority", "1"],
+        "update_interactive",
+    );
+    assert!(touch.status.success(), "update failed: {}", touch.stderr);
+
+    let scripted = run_br(
+        &workspace,
+        ["update", &ids[1], "--priority", "1", "--no-last-touched"],
+        "update_scripted",
+    );
+    assert!(
+        scripted.status.success(),
+        "update failed: {}",
+        scripted.stderr
+    );
+
+    let marker = fs::read_to_string(workspace.root.join(".beads").join("last-touched"))
+        .expect("read last-touched");
+    assert_eq!(marker.trim(), ids[0]);
+}
+
+#[test]
+fn e2e_quiet_flag_gc() {
+    let _log = common::test_log("e2e_quiet_flag_gc");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let gc = run_br(&workspace, ["--quiet", "gc", "--dry-run"], "gc_quiet");
+    assert!(gc.status.success(), "gc --quiet failed: {}", gc.stderr);
+    assert!(
+        gc.stdout.trim().is_empty(),
+        "gc --quiet should print nothing: {}",
+        gc.stdout
+    );
+}
@@ -1118,3 +1118,45 @@ fn e2e_list_custom_type() {
         "no issues should match custom type filter"
     );
 }
+
+#[test]
+fn e2e_list_json_stream_matches_json() {
+    let _log = common::test_log("e2e_list_json_stream_matches_json");
+    let (workspace, _ids) = setup_diverse_workspace();
+
+    for extra in [
+        &[][..],
+        &["--limit", "2"][..],
+        &["--priority-min", "1", "--limit", "1"][..],
+    ] {
+        let mut json_args = vec!["list", "--json"];
+        json_args.extend_from_slice(extra);
+        let mut stream_args = vec!["list", "--json-stream"];
+        stream_args.extend_from_slice(extra);
+
+        let json = run_br(&workspace, json_args, "list_json");
+        assert!(json.status.success(), "list --json failed: {}", json.stderr);
+        let stream = run_br(&workspace, stream_args, "list_json_stream");
+        assert!(
+            stream.status.success(),
+            "list --json-stream failed: {}",
+            stream.stderr
+        );
+
+        let expected: Vec<Value> =
+            serde_json::from_str(&extract_json_payload(&json.stdout)).expect("json parse");
+        let streamed: Vec<Value> = serde_json::from_str(&stream.stdout).expect("stream parse");
+        let ids = |issues: &[Value]| -> Vec<String> {
+            issues
+                .iter()
+                .map(|issue| issue["id"].as_str().unwrap_or_default().to_string())
+                .collect()
+        };
+        assert_eq!(ids(&streamed), ids(&expected), "args {extra:?}");
+        assert_eq!(
+            streamed.first().map(|issue| &issue["labels"]),
+            expected.first().map(|issue| &issue["labels"]),
+            "args {extra:?}"
+        );
+    }
+}
@@ -0,0 +1,44 @@
+//! E2E tests for output paging.
+//!
+//! The pager itself needs a TTY, so these tests drive the re-run process
+//! (`BR_PAGED=1`) directly with a pipe standing in for the pager's stdin.
+
+mod common;
+
+use common::cli::{BrWorkspace, run_br};
+use std::process::{Command, Stdio};
+
+#[test]
+fn e2e_paged_list_exits_cleanly_when_pager_quits_early() {
+    let _log = common::test_log("e2e_paged_list_exits_cleanly_when_pager_quits_early");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    for i in 0..30 {
+        let title = format!("Paged issue {i}");
+        let create = run_br(&workspace, ["create", &title], &format!("create_{i}"));
+        assert!(create.status.success(), "create failed: {}", create.stderr);
+    }
+
+    let mut child = Command::new(assert_cmd::cargo::cargo_bin!("br"))
+        .args(["list"])
+        .current_dir(&workspace.root)
+        .env("BR_PAGED", "1")
+        .env("HOME", &workspace.root)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("spawn br");
+    // The user quit the pager before any output was read.
+    drop(child.stdout.take());
+
+    let output = child.wait_with_output().expect("wait for br");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(
+        output.status.code(),
+        Some(0),
+        "closed pager should be a clean exit: {stderr}"
+    );
+    assert!(!stderr.contains("panicked"), "unexpected panic: {stderr}");
+}
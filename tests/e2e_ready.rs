@@ -721,3 +721,182 @@ fn ready_cli_priority_p_format() {
     assert_eq!(issues.len(), 1);
     assert_eq!(issues[0]["priority"].as_u64().unwrap(), 0);
 }
+
+#[test]
+fn ready_cli_format_toon_wins_over_robot() {
+    let _log = common::test_log("ready_cli_format_toon_wins_over_robot");
+    let (workspace, ids) = setup_workspace_with_issues();
+
+    for (label, args) in [
+        ("ready_toon", vec!["ready", "--format", "toon"]),
+        (
+            "ready_toon_robot",
+            vec!["ready", "--robot", "--format", "toon"],
+        ),
+    ] {
+        let result = run_br(&workspace, args, label);
+        assert!(result.status.success(), "ready failed: {}", result.stderr);
+
+        let toon = result.stdout.trim();
+        assert!(
+            serde_json::from_str::<Value>(toon).is_err(),
+            "{label} should not emit JSON: {toon}"
+        );
+        let issues = Value::from(toon_rust::try_decode(toon, None).expect("valid TOON"));
+        let issues = issues.as_array().expect("TOON array");
+        assert!(
+            issues
+                .iter()
+                .filter_map(|issue| issue["id"].as_str())
+                .any(|id| id == ids[0].as_str()),
+            "{label} should list {}",
+            ids[0]
+        );
+    }
+}
+
+#[test]
+fn ready_cli_include_counts_adds_dependency_counts() {
+    let _log = common::test_log("ready_cli_include_counts_adds_dependency_counts");
+    let (workspace, ids) = setup_workspace_with_issues();
+
+    let dep = run_br(&workspace, ["dep", "add", &ids[2], &ids[0]], "add_dep");
+    assert!(dep.status.success(), "dep add failed: {}", dep.stderr);
+
+    let parity = run_br(&workspace, ["ready", "--json"], "ready_parity");
+    assert!(parity.status.success(), "ready failed: {}", parity.stderr);
+    let parity: Value = serde_json::from_str(&extract_json_payload(&parity.stdout)).unwrap();
+    assert!(
+        parity.as_array().unwrap()[0]
+            .get("dependent_count")
+            .is_none(),
+        "default ready JSON keeps bd parity"
+    );
+
+    let counted = run_br(
+        &workspace,
+        ["ready", "--json", "--include-counts"],
+        "ready_counts",
+    );
+    assert!(counted.status.success(), "ready failed: {}", counted.stderr);
+    let counted: Value = serde_json::from_str(&extract_json_payload(&counted.stdout)).unwrap();
+    let blocker = counted
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|issue| issue["id"] == ids[0].as_str())
+        .expect("blocker is ready");
+    assert_eq!(blocker["dependent_count"], 1);
+    assert_eq!(blocker["dependency_count"], 0);
+    assert!(blocker.get("compaction_level").is_some());
+    assert_eq!(blocker["labels"], serde_json::json!(["backend"]));
+}
+
+#[test]
+fn ready_cli_output_json_to_writes_file_alongside_text() {
+    let _log = common::test_log("ready_cli_output_json_to_writes_file_alongside_text");
+    let (workspace, ids) = setup_workspace_with_issues();
+    let target = workspace.root.join("out").join("ready.json");
+    let target_arg = target.to_string_lossy().to_string();
+
+    let result = run_br(
+        &workspace,
+        ["ready", "--output-json-to", &target_arg],
+        "ready_json_to",
+    );
+    assert!(result.status.success(), "ready failed: {}", result.stderr);
+    assert!(
+        result.stdout.contains(&ids[0]),
+        "text listing still printed: {}",
+        result.stdout
+    );
+
+    let written: Value =
+        serde_json::from_str(&fs::read_to_string(&target).expect("json file written")).unwrap();
+    assert!(
+        written
+            .as_array()
+            .expect("JSON array")
+            .iter()
+            .any(|issue| issue["id"] == ids[0].as_str())
+    );
+}
+
+#[test]
+fn ready_cli_take_claims_then_resumes_held_issue() {
+    let _log = common::test_log("ready_cli_take_claims_then_resumes_held_issue");
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let first = run_br(&workspace, ["create", "First", "-p", "1"], "create_first");
+    assert!(first.status.success(), "create failed: {}", first.stderr);
+    let first_id = parse_created_id(&first.stdout);
+    let second = run_br(&workspace, ["create", "Second", "-p", "2"], "create_second");
+    assert!(second.status.success(), "create failed: {}", second.stderr);
+    let second_id = parse_created_id(&second.stdout);
+
+    let take = |actor: &str, extra: &[&str], label: &str| -> Value {
+        let mut args = vec!["--actor", actor, "ready", "--take", "--json"];
+        args.extend_from_slice(extra);
+        let result = run_br(&workspace, args, label);
+        assert!(result.status.success(), "take failed: {}", result.stderr);
+        serde_json::from_str(&extract_json_payload(&result.stdout)).expect("json parse")
+    };
+
+    let claimed = take("carol", &[], "take_carol");
+    assert_eq!(claimed["action"], "claimed");
+    assert_eq!(claimed["issue"]["id"], first_id.as_str());
+
+    let show = run_br(&workspace, ["show", &first_id, "--json"], "show_first");
+    let shown: Value = serde_json::from_str(&extract_json_payload(&show.stdout)).unwrap();
+    let shown = if shown.is_array() { &shown[0] } else { &shown };
+    assert_eq!(shown["assignee"], "carol");
+    assert_eq!(shown["status"], "in_progress");
+
+    // Taking again returns the held issue as resumed rather than re-claiming it
+    let again = take("carol", &[], "take_carol_again");
+    assert_eq!(again["action"], "resumed");
+    assert_eq!(again["issue"]["id"], first_id.as_str());
+
+    let resumed = take("carol", &["--resume"], "take_carol_resume");
+    assert_eq!(resumed["action"], "resumed");
+    assert_eq!(resumed["issue"]["id"], first_id.as_str());
+
+    // Another actor skips carol's issue and claims the next one
+    let other = take("dave", &["--only-mine-or-free"], "take_dave");
+    assert_eq!(other["action"], "claimed");
+    assert_eq!(other["issue"]["id"], second_id.as_str());
+
+    let none = take("erin", &["--only-mine-or-free"], "take_erin");
+    assert!(none.is_null(), "expected nothing to take, got {none}");
+}
+
+#[test]
+fn ready_reports_unresolved_external_project() {
+    let _log = common::test_log("ready_reports_unresolved_external_project");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    fs::write(
+        workspace.root.join(".beads/config.yaml"),
+        "issue_prefix: bd\nexternal_projects:\n  ghost: \"${workspace}/../no-such-project\"\n",
+    )
+    .expect("write config");
+
+    let ready = run_br(&workspace, ["ready", "--json"], "ready");
+    assert!(ready.status.success(), "ready failed: {}", ready.stderr);
+    assert!(
+        ready.stderr.contains("external_projects.ghost"),
+        "missing project should be named: {}",
+        ready.stderr
+    );
+    assert!(
+        ready.stderr.contains("no-such-project"),
+        "expanded path should be shown: {}",
+        ready.stderr
+    );
+    let payload = extract_json_payload(&ready.stdout);
+    let _: Vec<Value> = serde_json::from_str(&payload).expect("ready json");
+}
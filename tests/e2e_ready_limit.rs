@@ -66,3 +66,76 @@ fn test_ready_limit_with_external_blockers() {
         );
     }
 }
+
+#[test]
+fn test_ready_limit_zero_all_and_negative() {
+    let workspace = BrWorkspace::new();
+    run_br(&workspace, ["init"], "init");
+    for i in 1..=3 {
+        run_br(
+            &workspace,
+            ["create", &format!("Issue {i}")],
+            &format!("create_{i}"),
+        );
+    }
+
+    let count = |args: &[&str], label: &str| {
+        let run = run_br(&workspace, args, label);
+        assert!(run.status.success(), "{label} failed: {}", run.stderr);
+        serde_json::from_str::<Vec<serde_json::Value>>(&run.stdout)
+            .unwrap()
+            .len()
+    };
+
+    assert_eq!(count(&["ready", "--json"], "ready_default"), 3);
+    assert_eq!(
+        count(&["ready", "--limit", "2", "--json"], "ready_limit_2"),
+        2
+    );
+    assert_eq!(
+        count(&["ready", "--limit", "0", "--json"], "ready_limit_0"),
+        3
+    );
+    assert_eq!(count(&["ready", "--all", "--json"], "ready_all"), 3);
+
+    let negative = run_br(&workspace, ["ready", "--limit", "-1"], "ready_negative");
+    assert!(!negative.status.success());
+    assert!(negative.stderr.contains("cannot be negative"));
+
+    let conflict = run_br(
+        &workspace,
+        ["ready", "--all", "--limit", "2"],
+        "ready_all_conflict",
+    );
+    assert!(!conflict.status.success());
+}
+
+#[test]
+fn test_ready_default_limit_is_50_and_all_lifts_it() {
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    for i in 1..=55 {
+        let create = run_br(
+            &workspace,
+            ["create", &format!("Issue {i}")],
+            &format!("create_{i}"),
+        );
+        assert!(create.status.success(), "create failed: {}", create.stderr);
+    }
+
+    let count = |args: &[&str], label: &str| {
+        let run = run_br(&workspace, args, label);
+        assert!(run.status.success(), "{label} failed: {}", run.stderr);
+        serde_json::from_str::<Vec<serde_json::Value>>(&run.stdout)
+            .unwrap()
+            .len()
+    };
+
+    assert_eq!(count(&["ready", "--json"], "ready_default"), 50);
+    assert_eq!(count(&["ready", "--all", "--json"], "ready_all"), 55);
+    assert_eq!(
+        count(&["ready", "--limit", "0", "--json"], "ready_limit_0"),
+        55
+    );
+}
@@ -186,6 +186,10 @@ fn e2e_dep_add_list_blocked_remove() {
         "dep remove failed: {}",
         dep_remove.stderr
     );
+    let remove_payload = extract_json_payload(&dep_remove.stdout);
+    let remove_json: Value = serde_json::from_str(&remove_payload).expect("dep remove json");
+    assert_eq!(remove_json["action"], "removed");
+    assert_eq!(remove_json["type"], "blocks");
 
     let blocked_view = run_br(&workspace, ["blocked", "--json"], "blocked_after");
     assert!(
@@ -202,6 +206,54 @@ fn e2e_dep_add_list_blocked_remove() {
     info!("e2e_dep_add_list_blocked_remove: assertions passed");
 }
 
+#[test]
+fn e2e_deps_alias_adds_and_removes() {
+    common::init_test_logging();
+    info!("e2e_deps_alias_adds_and_removes: starting");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let blocker = run_br(&workspace, ["create", "Alias blocker"], "create_blocker");
+    assert!(
+        blocker.status.success(),
+        "create failed: {}",
+        blocker.stderr
+    );
+    let blocker_id = parse_created_id(&blocker.stdout);
+    let blocked = run_br(&workspace, ["create", "Alias blocked"], "create_blocked");
+    assert!(
+        blocked.status.success(),
+        "create failed: {}",
+        blocked.stderr
+    );
+    let blocked_id = parse_created_id(&blocked.stdout);
+
+    let add = run_br(
+        &workspace,
+        ["deps", "add", &blocked_id, &blocker_id, "--json"],
+        "deps_add",
+    );
+    assert!(add.status.success(), "deps add failed: {}", add.stderr);
+
+    let remove = run_br(
+        &workspace,
+        ["deps", "remove", &blocked_id, &blocker_id, "--json"],
+        "deps_remove",
+    );
+    assert!(
+        remove.status.success(),
+        "deps remove failed: {}",
+        remove.stderr
+    );
+    let payload = extract_json_payload(&remove.stdout);
+    let json: Value = serde_json::from_str(&payload).expect("deps remove json");
+    assert_eq!(json["action"], "removed");
+    assert_eq!(json["type"], "blocks");
+    info!("e2e_deps_alias_adds_and_removes: assertions passed");
+}
+
 #[test]
 #[allow(clippy::too_many_lines)]
 fn e2e_dep_tree_external_nodes() {
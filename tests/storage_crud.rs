@@ -1,13 +1,13 @@
 //! Storage CRUD unit tests with real `SQLite` (no mocks).
 //!
-//! Tests `create_issue`, `get_issue`, `update_issue`, `delete_issue` operations.
+//! Tests `create_issue`, `get_issue`, `update_issue`, `delete_issue`, `claim_issue` operations.
 //! Verifies event creation, dirty marking, and transaction behavior.
 #![allow(clippy::similar_names)]
 
 mod common;
 
 use beads_rust::model::{DependencyType, EventType, Issue, IssueType, Priority, Status};
-use beads_rust::storage::{IssueUpdate, SqliteStorage};
+use beads_rust::storage::{ClaimOutcome, IssueUpdate, SqliteStorage};
 use chrono::{Duration, Utc};
 use common::{fixtures, test_db, test_db_with_dir};
 
@@ -556,6 +556,46 @@ fn deleted_issues_excluded_from_list() {
     assert!(!ids.contains(&issue2.id));
 }
 
+#[test]
+fn gc_prune_removes_only_expired_tombstones() {
+    let mut storage = test_db();
+    let live = fixtures::issue("gc-live");
+    let old = fixtures::issue("gc-old");
+    let recent = fixtures::issue("gc-recent");
+    for issue in [&live, &old, &recent] {
+        storage.create_issue(issue, "tester").unwrap();
+    }
+    storage
+        .add_dependency(&live.id, &old.id, "related", "tester")
+        .unwrap();
+    let now = Utc::now();
+    storage
+        .delete_issue(&old.id, "deleter", "old", Some(now - Duration::days(90)))
+        .unwrap();
+    storage
+        .delete_issue(
+            &recent.id,
+            "deleter",
+            "recent",
+            Some(now - Duration::days(2)),
+        )
+        .unwrap();
+
+    let cutoff = now - Duration::days(30);
+    let preview = storage.gc_prune(cutoff, None, true, "tester").unwrap();
+    assert_eq!(preview.tombstones, vec![old.id.clone()]);
+    assert!(storage.get_issue(&old.id).unwrap().is_some());
+
+    let report = storage.gc_prune(cutoff, None, false, "tester").unwrap();
+    assert_eq!(report.tombstones, vec![old.id.clone()]);
+    assert!(storage.get_issue(&old.id).unwrap().is_none());
+    assert!(storage.get_issue(&recent.id).unwrap().is_some());
+    assert!(storage.get_dependencies(&live.id).unwrap().is_empty());
+
+    let events = storage.gc_prune(cutoff, Some(now + Duration::days(1)), false, "tester");
+    assert!(events.unwrap().events > 0);
+}
+
 // ============================================================================
 // DIRTY TRACKING TESTS
 // ============================================================================
@@ -809,3 +849,104 @@ fn data_persists_across_connections() {
         .expect("issue exists");
     assert_eq!(retrieved.title, issue.title);
 }
+
+#[test]
+fn claim_issue_is_exclusive_to_first_actor() {
+    let mut storage = test_db();
+    let issue = fixtures::issue("claim-target");
+    storage.create_issue(&issue, "tester").unwrap();
+
+    assert_eq!(
+        storage.claim_issue(&issue.id, "alice").unwrap(),
+        ClaimOutcome::Claimed
+    );
+    let claimed = storage.get_issue(&issue.id).unwrap().expect("issue exists");
+    assert_eq!(claimed.assignee.as_deref(), Some("alice"));
+    assert_eq!(claimed.status, Status::InProgress);
+
+    // Re-claiming by the holder is reported as already held; another actor loses.
+    assert_eq!(
+        storage.claim_issue(&issue.id, "alice").unwrap(),
+        ClaimOutcome::AlreadyHeld
+    );
+    assert_eq!(
+        storage.claim_issue(&issue.id, "bob").unwrap(),
+        ClaimOutcome::Unavailable
+    );
+    let after = storage.get_issue(&issue.id).unwrap().expect("issue exists");
+    assert_eq!(after.assignee.as_deref(), Some("alice"));
+}
+
+#[test]
+fn reassign_issues_skips_claims_unless_stealing() {
+    let mut storage = test_db();
+    let open = fixtures::issue("reassign-open");
+    let claimed = fixtures::issue("reassign-claimed");
+    storage.create_issue(&open, "tester").unwrap();
+    storage.create_issue(&claimed, "tester").unwrap();
+    assert!(storage.claim_issue(&claimed.id, "alice").unwrap());
+
+    let ids = vec![open.id.clone(), claimed.id.clone()];
+    let (updated, skipped) = storage
+        .reassign_issues(&ids, "carol", false, "lead")
+        .unwrap();
+    assert_eq!(updated.len(), 1);
+    assert_eq!(updated[0].id, open.id);
+    assert_eq!(skipped, vec![claimed.id.clone()]);
+    let kept = storage
+        .get_issue(&claimed.id)
+        .unwrap()
+        .expect("issue exists");
+    assert_eq!(kept.assignee.as_deref(), Some("alice"));
+
+    let (stolen, skipped) = storage
+        .reassign_issues(&[claimed.id.clone()], "carol", true, "lead")
+        .unwrap();
+    assert_eq!(stolen.len(), 1);
+    assert!(skipped.is_empty());
+    let moved = storage
+        .get_issue(&claimed.id)
+        .unwrap()
+        .expect("issue exists");
+    assert_eq!(moved.assignee.as_deref(), Some("carol"));
+    assert_eq!(moved.status, Status::InProgress);
+    assert!(storage.get_dirty_issue_ids().unwrap().contains(&claimed.id));
+}
+
+#[test]
+fn claim_issue_records_previous_assignee() {
+    let mut storage = test_db();
+    let mut issue = fixtures::issue("claim-preassigned");
+    issue.assignee = Some("alice".to_string());
+    storage.create_issue(&issue, "tester").unwrap();
+
+    // Starting work on an issue already assigned to the actor is a claim,
+    // but the assignee did not change.
+    assert_eq!(
+        storage.claim_issue(&issue.id, "alice").unwrap(),
+        ClaimOutcome::Claimed
+    );
+    let events = storage.get_events(&issue.id, 0).unwrap();
+    assert!(
+        !events
+            .iter()
+            .any(|e| e.event_type == EventType::AssigneeChanged)
+    );
+    assert!(events.iter().any(
+        |e| e.event_type == EventType::StatusChanged && e.old_value.as_deref() == Some("open")
+    ));
+
+    let free = fixtures::issue("claim-free");
+    storage.create_issue(&free, "tester").unwrap();
+    assert_eq!(
+        storage.claim_issue(&free.id, "bob").unwrap(),
+        ClaimOutcome::Claimed
+    );
+    let events = storage.get_events(&free.id, 0).unwrap();
+    let assignee_event = events
+        .iter()
+        .find(|e| e.event_type == EventType::AssigneeChanged)
+        .expect("assignee event");
+    assert_eq!(assignee_event.old_value, None);
+    assert_eq!(assignee_event.new_value.as_deref(), Some("bob"));
+}
@@ -151,6 +151,34 @@ fn add_dependency_marks_dirty() {
     assert!(dirty_ids.contains(&blocked.id));
 }
 
+#[test]
+fn add_dependencies_inserts_batch_and_ignores_existing() {
+    let mut storage = test_db();
+
+    let a = fixtures::issue("batch-a");
+    let b = fixtures::issue("batch-b");
+    let c = fixtures::issue("batch-c");
+    for issue in [&a, &b, &c] {
+        storage.create_issue(issue, "tester").unwrap();
+    }
+    let (a, b, c) = (&a.id, &b.id, &c.id);
+
+    storage
+        .add_dependency(a, b, DependencyType::Blocks.as_str(), "tester")
+        .unwrap();
+
+    let edges = vec![
+        (a.clone(), b.clone(), "blocks".to_string()),
+        (b.clone(), c.clone(), "blocks".to_string()),
+        (a.clone(), c.clone(), "related".to_string()),
+    ];
+    let added = storage.add_dependencies(&edges, "tester").unwrap();
+    assert_eq!(added, 2);
+
+    assert_eq!(storage.get_dependencies(b).unwrap(), vec![c.clone()]);
+    assert_eq!(storage.get_dependencies(a).unwrap().len(), 2);
+}
+
 // ============================================================================
 // REMOVE DEPENDENCY TESTS
 // ============================================================================
@@ -10,12 +10,14 @@
 //! - Include closed filter
 //! - Include templates filter
 //! - Combined filter tests
+//! - Multi-key sort specs
+//! - Grouped counts (`count_issues_grouped`)
 #![allow(clippy::similar_names)]
 
 mod common;
 
 use beads_rust::model::{IssueType, Priority, Status};
-use beads_rust::storage::ListFilters;
+use beads_rust::storage::{IssueCountField, ListFilters};
 use common::{fixtures::IssueBuilder, test_db};
 
 // ============================================================================
@@ -859,6 +861,97 @@ fn results_sorted_by_priority_then_created_at() {
     assert_eq!(results[2].priority, Priority::LOW); // P3
 }
 
+#[test]
+fn multi_key_sort_priority_asc_then_id_desc() {
+    let mut storage = test_db();
+
+    let a = IssueBuilder::new("multi-a")
+        .with_id("bd-a")
+        .with_priority(Priority::MEDIUM)
+        .build();
+    let b = IssueBuilder::new("multi-b")
+        .with_id("bd-b")
+        .with_priority(Priority::MEDIUM)
+        .build();
+    let c = IssueBuilder::new("multi-c")
+        .with_id("bd-c")
+        .with_priority(Priority::CRITICAL)
+        .build();
+
+    storage.create_issue(&a, "tester").unwrap();
+    storage.create_issue(&b, "tester").unwrap();
+    storage.create_issue(&c, "tester").unwrap();
+
+    let filters = ListFilters {
+        sort: Some("priority:asc,id:desc".to_string()),
+        ..Default::default()
+    };
+    let ids: Vec<String> = storage
+        .list_issues(&filters)
+        .unwrap()
+        .into_iter()
+        .map(|i| i.id)
+        .collect();
+    assert_eq!(ids, vec!["bd-c", "bd-b", "bd-a"]);
+
+    // --reverse flips every key
+    let filters = ListFilters {
+        sort: Some("priority:asc,id:desc".to_string()),
+        reverse: true,
+        ..Default::default()
+    };
+    let ids: Vec<String> = storage
+        .list_issues(&filters)
+        .unwrap()
+        .into_iter()
+        .map(|i| i.id)
+        .collect();
+    assert_eq!(ids, vec!["bd-a", "bd-b", "bd-c"]);
+}
+
+#[test]
+fn sort_field_is_case_insensitive() {
+    let mut storage = test_db();
+
+    let low = IssueBuilder::new("case-low")
+        .with_id("bd-low")
+        .with_priority(Priority::LOW)
+        .build();
+    let critical = IssueBuilder::new("case-critical")
+        .with_id("bd-critical")
+        .with_priority(Priority::CRITICAL)
+        .build();
+    storage.create_issue(&low, "tester").unwrap();
+    storage.create_issue(&critical, "tester").unwrap();
+
+    for spec in ["priority", "Priority", "PRIORITY:ASC"] {
+        let filters = ListFilters {
+            sort: Some(spec.to_string()),
+            reverse: true,
+            ..Default::default()
+        };
+        let ids: Vec<String> = storage
+            .list_issues(&filters)
+            .unwrap()
+            .into_iter()
+            .map(|i| i.id)
+            .collect();
+        assert_eq!(ids, vec!["bd-low", "bd-critical"], "sort spec {spec}");
+    }
+}
+
+#[test]
+fn multi_key_sort_rejects_unknown_field() {
+    let storage = test_db();
+
+    let filters = ListFilters {
+        sort: Some("priority,owner:asc".to_string()),
+        ..Default::default()
+    };
+    let err = storage.list_issues(&filters).unwrap_err();
+    assert!(err.to_string().contains("invalid sort field 'owner'"));
+}
+
 // ============================================================================
 // EDGE CASE TESTS
 // ============================================================================
@@ -937,3 +1030,94 @@ fn filter_with_special_characters_in_title() {
     // Should match because title contains literal %
     assert_eq!(results.len(), 1);
 }
+
+#[test]
+fn count_issues_grouped_honors_filters() {
+    let mut storage = test_db();
+
+    let bug = IssueBuilder::new("Bug one")
+        .with_type(IssueType::Bug)
+        .with_assignee("alice")
+        .build();
+    let bug2 = IssueBuilder::new("Bug two")
+        .with_type(IssueType::Bug)
+        .build();
+    let task = IssueBuilder::new("Task").with_type(IssueType::Task).build();
+    let closed = IssueBuilder::new("Closed task")
+        .with_type(IssueType::Task)
+        .with_status(Status::Closed)
+        .build();
+    for issue in [&bug, &bug2, &task, &closed] {
+        storage.create_issue(issue, "tester").unwrap();
+    }
+    storage.add_label(&bug.id, "backend", "tester").unwrap();
+    storage.add_label(&task.id, "backend", "tester").unwrap();
+
+    // Default filters exclude closed issues
+    let by_type = storage
+        .count_issues_grouped(&ListFilters::default(), IssueCountField::Type)
+        .unwrap();
+    assert_eq!(
+        by_type,
+        vec![("bug".to_string(), 2), ("task".to_string(), 1)]
+    );
+
+    let all = ListFilters {
+        include_closed: true,
+        ..Default::default()
+    };
+    let by_status = storage
+        .count_issues_grouped(&all, IssueCountField::Status)
+        .unwrap();
+    assert!(by_status.contains(&("closed".to_string(), 1)));
+    assert!(by_status.contains(&("open".to_string(), 3)));
+
+    let by_assignee = storage
+        .count_issues_grouped(&ListFilters::default(), IssueCountField::Assignee)
+        .unwrap();
+    assert!(by_assignee.contains(&("alice".to_string(), 1)));
+    assert!(by_assignee.contains(&("(unassigned)".to_string(), 2)));
+
+    let by_label = storage
+        .count_issues_grouped(&ListFilters::default(), IssueCountField::Label)
+        .unwrap();
+    assert!(by_label.contains(&("backend".to_string(), 2)));
+    assert!(by_label.contains(&("(no labels)".to_string(), 1)));
+
+    let by_priority = storage
+        .count_issues_grouped(&ListFilters::default(), IssueCountField::Priority)
+        .unwrap();
+    assert_eq!(by_priority.iter().map(|(_, n)| n).sum::<usize>(), 3);
+    assert!(by_priority.iter().all(|(p, _)| p.starts_with('P')));
+}
+
+#[test]
+fn count_issues_grouped_by_derived_predicates() {
+    let mut storage = test_db();
+
+    let mut overdue = IssueBuilder::new("Overdue").build();
+    overdue.due_at = Some(chrono::Utc::now() - chrono::Duration::days(2));
+    let mut future = IssueBuilder::new("Due later").build();
+    future.due_at = Some(chrono::Utc::now() + chrono::Duration::days(2));
+    let undated = IssueBuilder::new("No due date").build();
+    for issue in [&overdue, &future, &undated] {
+        storage.create_issue(issue, "tester").unwrap();
+    }
+    storage.add_label(&undated.id, "triage", "tester").unwrap();
+
+    let by_overdue = storage
+        .count_issues_grouped(&ListFilters::default(), IssueCountField::Overdue)
+        .unwrap();
+    assert_eq!(
+        by_overdue,
+        vec![("on-time".to_string(), 2), ("overdue".to_string(), 1)]
+    );
+
+    let by_labeled = storage
+        .count_issues_grouped(&ListFilters::default(), IssueCountField::Labeled)
+        .unwrap();
+    assert_eq!(
+        by_labeled,
+        vec![("labeled".to_string(), 1), ("unlabeled".to_string(), 2)]
+    );
+}
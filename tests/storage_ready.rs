@@ -1,8 +1,10 @@
 //! Storage unit tests for ready issues functionality.
 //!
-//! Tests: `get_ready_issues` with various filters (assignee, unassigned, types,
-//! priorities, `labels_and`, `labels_or`, `include_deferred`, limit) and sort policies
-//! (Hybrid, Priority, Oldest). Real `SQLite`, no mocks.
+//! Tests: `get_ready_issues` with various filters (assignee, assignees, unassigned,
+//! `assignee_or_unassigned`, types, priorities, `labels_and`, `labels_or`,
+//! `labels_not`, `include_deferred`, `include_pinned`, `include_ephemeral`, limit),
+//! column projection via `get_ready_issue_columns`,
+//! and sort policies (Hybrid, Priority, Oldest). Real `SQLite`, no mocks.
 
 mod common;
 
@@ -85,6 +87,66 @@ fn ready_filter_unassigned_only() {
     assert!(ids.contains(&unassigned2.id));
 }
 
+#[test]
+fn ready_filter_assignee_or_unassigned() {
+    let mut storage = test_db();
+
+    let mine = fixtures::IssueBuilder::new("Assigned to Alice")
+        .with_assignee("alice")
+        .build();
+    let theirs = fixtures::IssueBuilder::new("Assigned to Bob")
+        .with_assignee("bob")
+        .build();
+    let free = fixtures::IssueBuilder::new("Unassigned issue").build();
+
+    storage.create_issue(&mine, "tester").unwrap();
+    storage.create_issue(&theirs, "tester").unwrap();
+    storage.create_issue(&free, "tester").unwrap();
+
+    let filters = ReadyFilters {
+        assignee_or_unassigned: Some("alice".to_string()),
+        ..Default::default()
+    };
+
+    let ids = ready_ids(&storage, &filters, ReadySortPolicy::Oldest);
+    assert_eq!(ids.len(), 2);
+    assert!(ids.contains(&mine.id));
+    assert!(ids.contains(&free.id));
+    assert!(!ids.contains(&theirs.id));
+}
+
+#[test]
+fn ready_filter_by_assignee_set() {
+    let mut storage = test_db();
+
+    let alice = fixtures::IssueBuilder::new("Assigned to Alice")
+        .with_assignee("alice")
+        .build();
+    let bob = fixtures::IssueBuilder::new("Assigned to Bob")
+        .with_assignee("bob")
+        .build();
+    let carol = fixtures::IssueBuilder::new("Assigned to Carol")
+        .with_assignee("carol")
+        .build();
+    let free = fixtures::IssueBuilder::new("Unassigned issue").build();
+
+    for issue in [&alice, &bob, &carol, &free] {
+        storage.create_issue(issue, "tester").unwrap();
+    }
+
+    let filters = ReadyFilters {
+        assignees: vec!["alice".to_string(), "carol".to_string()],
+        ..Default::default()
+    };
+
+    let ids = ready_ids(&storage, &filters, ReadySortPolicy::Oldest);
+    assert_eq!(ids.len(), 2);
+    assert!(ids.contains(&alice.id));
+    assert!(ids.contains(&carol.id));
+    assert!(!ids.contains(&bob.id));
+    assert!(!ids.contains(&free.id));
+}
+
 // ============================================================================
 // TYPE FILTER TESTS
 // ============================================================================
@@ -299,6 +361,72 @@ fn ready_filter_by_labels_or() {
     assert!(!ids.contains(&issue3.id));
 }
 
+#[test]
+fn ready_filter_excludes_labels() {
+    let mut storage = test_db();
+
+    let issue1 = fixtures::issue("Has backend label");
+    let issue2 = fixtures::issue("Needs design");
+    let issue3 = fixtures::issue("No labels");
+
+    storage.create_issue(&issue1, "tester").unwrap();
+    storage.create_issue(&issue2, "tester").unwrap();
+    storage.create_issue(&issue3, "tester").unwrap();
+
+    storage.add_label(&issue1.id, "backend", "tester").unwrap();
+    storage
+        .add_label(&issue2.id, "needs-design", "tester")
+        .unwrap();
+
+    let filters = ReadyFilters {
+        labels_not: vec!["needs-design".to_string()],
+        ..Default::default()
+    };
+
+    let ids = ready_ids(&storage, &filters, ReadySortPolicy::Oldest);
+    assert_eq!(ids.len(), 2);
+    assert!(ids.contains(&issue1.id));
+    assert!(!ids.contains(&issue2.id));
+    assert!(ids.contains(&issue3.id));
+}
+
+#[test]
+fn ready_filter_exclude_labels_wins_over_include() {
+    let mut storage = test_db();
+
+    let issue1 = fixtures::issue("Backend only");
+    let issue2 = fixtures::issue("Backend but needs design");
+    let issue3 = fixtures::issue("Frontend");
+
+    storage.create_issue(&issue1, "tester").unwrap();
+    storage.create_issue(&issue2, "tester").unwrap();
+    storage.create_issue(&issue3, "tester").unwrap();
+
+    storage.add_label(&issue1.id, "backend", "tester").unwrap();
+    storage.add_label(&issue2.id, "backend", "tester").unwrap();
+    storage
+        .add_label(&issue2.id, "needs-design", "tester")
+        .unwrap();
+    storage.add_label(&issue3.id, "frontend", "tester").unwrap();
+
+    // Inclusion via AND and OR, exclusion removes the overlap
+    let filters = ReadyFilters {
+        labels_and: vec!["backend".to_string()],
+        labels_not: vec!["needs-design".to_string()],
+        ..Default::default()
+    };
+    let ids = ready_ids(&storage, &filters, ReadySortPolicy::Oldest);
+    assert_eq!(ids, vec![issue1.id.clone()]);
+
+    let filters = ReadyFilters {
+        labels_or: vec!["backend".to_string(), "frontend".to_string()],
+        labels_not: vec!["needs-design".to_string(), "frontend".to_string()],
+        ..Default::default()
+    };
+    let ids = ready_ids(&storage, &filters, ReadySortPolicy::Oldest);
+    assert_eq!(ids, vec![issue1.id]);
+}
+
 // ============================================================================
 // LIMIT FILTER TESTS
 // ============================================================================
@@ -649,3 +777,78 @@ fn ready_include_deferred_flag() {
     assert!(ids.contains(&open_no_defer.id));
     assert!(ids.contains(&open_with_defer.id));
 }
+
+#[test]
+fn ready_include_pinned_and_ephemeral_flags() {
+    let mut storage = test_db();
+
+    let plain = fixtures::IssueBuilder::new("Plain").build();
+    let mut pinned = fixtures::IssueBuilder::new("Pinned").build();
+    pinned.pinned = true;
+    let mut ephemeral = fixtures::IssueBuilder::new("Ephemeral").build();
+    ephemeral.ephemeral = true;
+
+    storage.create_issue(&plain, "tester").unwrap();
+    storage.create_issue(&pinned, "tester").unwrap();
+    storage.create_issue(&ephemeral, "tester").unwrap();
+
+    let ids = ready_ids(&storage, &ReadyFilters::default(), ReadySortPolicy::Oldest);
+    assert!(ids.contains(&plain.id));
+    assert!(!ids.contains(&pinned.id));
+    assert!(!ids.contains(&ephemeral.id));
+
+    let filters = ReadyFilters {
+        include_pinned: true,
+        ..Default::default()
+    };
+    let ids = ready_ids(&storage, &filters, ReadySortPolicy::Oldest);
+    assert!(ids.contains(&pinned.id));
+    assert!(!ids.contains(&ephemeral.id));
+
+    let filters = ReadyFilters {
+        include_ephemeral: true,
+        ..Default::default()
+    };
+    let ids = ready_ids(&storage, &filters, ReadySortPolicy::Oldest);
+    assert!(!ids.contains(&pinned.id));
+    assert!(ids.contains(&ephemeral.id));
+}
+
+#[test]
+fn ready_issue_columns_projects_requested_fields() {
+    let mut storage = test_db();
+
+    let high = fixtures::IssueBuilder::new("High")
+        .with_priority(Priority::HIGH)
+        .build();
+    let low = fixtures::IssueBuilder::new("Low")
+        .with_priority(Priority::LOW)
+        .build();
+    storage.create_issue(&high, "tester").unwrap();
+    storage.create_issue(&low, "tester").unwrap();
+
+    let rows = storage
+        .get_ready_issue_columns(
+            &ReadyFilters::default(),
+            ReadySortPolicy::Priority,
+            &["priority".to_string()],
+        )
+        .unwrap();
+    let full = ready_ids(
+        &storage,
+        &ReadyFilters::default(),
+        ReadySortPolicy::Priority,
+    );
+
+    assert_eq!(rows.len(), full.len());
+    assert_eq!(rows[0]["id"], high.id.as_str());
+    assert_eq!(rows[0]["priority"], 1);
+    assert!(rows[0].get("title").is_none());
+
+    let err = storage.get_ready_issue_columns(
+        &ReadyFilters::default(),
+        ReadySortPolicy::Priority,
+        &["description".to_string()],
+    );
+    assert!(err.is_err());
+}